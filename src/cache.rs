@@ -0,0 +1,234 @@
+// Persistent parse cache for category files (G.CA.1, G.CA.2, G.CA.3, G.CA.4, G.CA.5)
+
+use crate::filesystem::read_file_utf8;
+use crate::models::{RequirementFull, RequirementSummary};
+use crate::parsing::parse_category_content;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::UNIX_EPOCH;
+
+/// Counter making temporary cache file names unique within the process (G.CA.4)
+static TMP_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Cache format version (G.CA.2). Bump when the cached structures change.
+const CACHE_VERSION: u32 = 1;
+
+/// Chapter with requirement summaries, as returned by reqlix_get_requirements (G.CA.2)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CachedChapter {
+    pub name: String,
+    pub requirements: Vec<RequirementSummary>,
+}
+
+/// Search index entry: full requirement plus lowercase title and text (G.CA.2, G.TOOLREQLIXS.3)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SearchEntry {
+    pub requirement: RequirementFull,
+    pub title_lower: String,
+    pub text_lower: String,
+}
+
+/// Parsed contents of a category file (G.CA.2)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub struct CachedCategory {
+    pub chapters: Vec<CachedChapter>,
+    pub search_index: Vec<SearchEntry>,
+}
+
+impl CachedCategory {
+    /// Chapter names in file order
+    pub fn chapter_names(&self) -> Vec<String> {
+        self.chapters.iter().map(|c| c.name.clone()).collect()
+    }
+
+    /// Requirement summaries of the first chapter with the given name
+    pub fn chapter_requirements(&self, chapter: &str) -> Option<&Vec<RequirementSummary>> {
+        self.chapters
+            .iter()
+            .find(|c| c.name == chapter)
+            .map(|c| &c.requirements)
+    }
+}
+
+/// Fingerprint of a category file (G.CA.2, G.CA.3)
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+struct Fingerprint {
+    mtime_secs: u64,
+    mtime_nanos: u32,
+    size: u64,
+    hash: u64,
+}
+
+/// On-disk cache file layout (G.CA.2)
+#[derive(Debug, Serialize, Deserialize)]
+struct CacheFile {
+    version: u32,
+    fingerprint: Fingerprint,
+    category: CachedCategory,
+}
+
+/// 64-bit FNV-1a hash of file content (G.CA.2)
+#[cfg_attr(test, allow(dead_code))]
+pub fn content_hash(bytes: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in bytes {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
+
+/// Get cache directory inside the requirements directory (G.CA.1)
+#[cfg_attr(test, allow(dead_code))]
+pub fn get_cache_dir(requirements_dir: &Path) -> PathBuf {
+    requirements_dir.join(".reqlix").join("cache")
+}
+
+/// Get cache file path for a category (G.CA.1)
+#[cfg_attr(test, allow(dead_code))]
+pub fn get_cache_path(requirements_dir: &Path, category: &str) -> PathBuf {
+    get_cache_dir(requirements_dir).join(format!("{}.json", category))
+}
+
+/// Read modification time and size of a file (G.CA.3)
+/// Missing modification time is reported as zero and never trusted without the content hash.
+fn file_stat(path: &Path) -> Result<(u64, u32, u64), String> {
+    let metadata = fs::metadata(path)
+        .map_err(|e| format!("Failed to read file {}: {}", path.to_string_lossy(), e))?;
+    let (secs, nanos) = metadata
+        .modified()
+        .ok()
+        .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+        .map(|d| (d.as_secs(), d.subsec_nanos()))
+        .unwrap_or((0, 0));
+    Ok((secs, nanos, metadata.len()))
+}
+
+/// Read cache file if present and readable (G.CA.4)
+fn read_cache_file(cache_path: &Path) -> Option<CacheFile> {
+    let content = fs::read_to_string(cache_path).ok()?;
+    let cache: CacheFile = serde_json::from_str(&content).ok()?;
+    if cache.version != CACHE_VERSION {
+        return None;
+    }
+    Some(cache)
+}
+
+/// Write cache file atomically, ignoring failures (G.CA.1, G.CA.4)
+fn write_cache_file(requirements_dir: &Path, category: &str, cache: &CacheFile) {
+    let cache_dir = get_cache_dir(requirements_dir);
+    if !cache_dir.exists() {
+        if fs::create_dir_all(&cache_dir).is_err() {
+            return;
+        }
+        // G.CA.1: Never commit cache files
+        let _ = fs::write(cache_dir.join(".gitignore"), "*\n");
+    }
+
+    let content = match serde_json::to_string(cache) {
+        Ok(c) => c,
+        Err(_) => return,
+    };
+    let cache_path = get_cache_path(requirements_dir, category);
+    // Unique per write: the HTTP transport serves several sessions from one process
+    let tmp_path = cache_dir.join(format!(
+        "{}.json.tmp{}-{}",
+        category,
+        std::process::id(),
+        TMP_COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+    if fs::write(&tmp_path, content).is_err() {
+        return;
+    }
+    if fs::rename(&tmp_path, &cache_path).is_err() {
+        let _ = fs::remove_file(&tmp_path);
+    }
+}
+
+/// Parse category file content into chapters and search index in one pass (G.CA.2)
+fn parse_category(content: &str, category: &str) -> CachedCategory {
+    let (sections, requirements) = parse_category_content(content, category);
+
+    // A chapter name used by several sections lists the requirements of all of them
+    // (G.REQLIX_GET_REQUIREMENTS.3)
+    let chapters = sections
+        .iter()
+        .map(|(name, _)| CachedChapter {
+            name: name.clone(),
+            requirements: sections
+                .iter()
+                .filter(|(other, _)| other == name)
+                .flat_map(|(_, summaries)| summaries.iter().cloned())
+                .collect(),
+        })
+        .collect();
+
+    let search_index = requirements
+        .into_iter()
+        .map(|requirement| SearchEntry {
+            title_lower: requirement.title.to_lowercase(),
+            text_lower: requirement.text.to_lowercase(),
+            requirement,
+        })
+        .collect();
+
+    CachedCategory {
+        chapters,
+        search_index,
+    }
+}
+
+/// Load parsed category, using the on-disk cache when its fingerprint matches (G.CA.3, G.CA.4, G.CA.5)
+#[cfg_attr(test, allow(dead_code))]
+pub fn load_category(requirements_dir: &Path, category: &str) -> Result<CachedCategory, String> {
+    let category_path = requirements_dir.join(format!("{}.md", category));
+    let (mtime_secs, mtime_nanos, size) = file_stat(&category_path)?;
+    let cache_path = get_cache_path(requirements_dir, category);
+
+    // G.CA.3 step 1: Matching modification time and size are trusted without reading the file,
+    // unless the file changed in the same clock tick as the cache write (or has no modification time)
+    let cache_written = file_stat(&cache_path)
+        .ok()
+        .map(|(secs, nanos, _)| (secs, nanos));
+    let settled = (mtime_secs, mtime_nanos) != (0, 0)
+        && cache_written.is_some_and(|written| (mtime_secs, mtime_nanos) < written);
+    let cached = match read_cache_file(&cache_path) {
+        Some(cache)
+            if settled
+                && cache.fingerprint.mtime_secs == mtime_secs
+                && cache.fingerprint.mtime_nanos == mtime_nanos
+                && cache.fingerprint.size == size =>
+        {
+            return Ok(cache.category)
+        }
+        other => other,
+    };
+
+    // G.CA.3 step 2: Content hash check; unchanged content keeps the parsed data
+    let content = read_file_utf8(&category_path)?;
+    let hash = content_hash(content.as_bytes());
+    let category_data = match cached {
+        Some(cache) if cache.fingerprint.hash == hash => cache.category,
+        _ => parse_category(&content, category),
+    };
+
+    // Store with the current fingerprint (G.CA.3, G.CA.4)
+    write_cache_file(
+        requirements_dir,
+        category,
+        &CacheFile {
+            version: CACHE_VERSION,
+            fingerprint: Fingerprint {
+                mtime_secs,
+                mtime_nanos,
+                size,
+                hash,
+            },
+            category: category_data.clone(),
+        },
+    );
+
+    Ok(category_data)
+}
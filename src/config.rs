@@ -0,0 +1,297 @@
+// Project configuration file reqlix.toml (G.SE.1 - G.SE.6, G.I.4)
+
+use crate::constants::*;
+use crate::filesystem::{get_discovery_dirs, validate_relative_path};
+use regex::Regex;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+/// Project configuration; every section and key is optional (G.SE.1)
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ProjectConfig {
+    /// Directory of reqlix.toml, None without a configuration file
+    #[serde(skip)]
+    pub dir: Option<PathBuf>,
+    pub requirements: RequirementsConfig,
+    pub limits: LimitsConfig,
+    pub naming: NamingConfig,
+    pub index: IndexConfig,
+    pub instructions: InstructionsConfig,
+}
+
+/// Location of the requirements directory (G.SE.3)
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RequirementsConfig {
+    /// Requirements directory relative to the project root, searched first and used for creation
+    pub path: Option<String>,
+    /// Further requirements directories searched before the built-in ones
+    pub search_paths: Vec<String>,
+}
+
+/// Size and batch limits, at most the built-in limits (G.SE.4)
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LimitsConfig {
+    pub max_text_len: usize,
+    pub max_title_len: usize,
+    pub max_batch_size: usize,
+}
+
+impl Default for LimitsConfig {
+    fn default() -> Self {
+        Self {
+            max_text_len: MAX_TEXT_LEN,
+            max_title_len: MAX_TITLE_LEN,
+            max_batch_size: MAX_BATCH_SIZE,
+        }
+    }
+}
+
+/// Category and chapter naming rules: extra characters or patterns replacing the built-in character sets (G.SE.5)
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct NamingConfig {
+    pub category_pattern: Option<String>,
+    pub chapter_pattern: Option<String>,
+    /// Characters allowed in category names besides the built-in set
+    pub extra_category_characters: String,
+    /// Characters allowed in chapter names besides the built-in set
+    pub extra_chapter_characters: String,
+}
+
+/// Index scheme: fixed prefixes of categories (G.SE.6)
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct IndexConfig {
+    pub category_prefixes: BTreeMap<String, String>,
+}
+
+/// Instructions template and placeholder values (G.I.4)
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct InstructionsConfig {
+    /// Content of new AGENTS.md files, relative to the directory of reqlix.toml
+    pub template: Option<String>,
+    /// Value of {project_name}; defaults to the name of the project directory
+    pub project_name: Option<String>,
+    /// Chapters listed by {key_chapters}, as "category/Chapter"
+    pub key_chapters: Vec<String>,
+}
+
+/// Escape characters for use inside a regular expression character class
+fn class_escape(characters: &str) -> String {
+    let mut escaped = String::new();
+    for c in characters.chars() {
+        if matches!(c, '\\' | ']' | '[' | '^' | '-') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+impl NamingConfig {
+    /// Schema pattern of category names: the built-in rule with the extra characters,
+    /// None when naming.category_pattern replaces the rule (G.P.5)
+    pub fn category_schema_pattern(&self) -> Option<String> {
+        if self.category_pattern.is_some() {
+            return None;
+        }
+        let extra = class_escape(&self.extra_category_characters);
+        Some(format!("^[a-z0-9_{0}]*[a-z][a-z0-9_{0}]*$", extra))
+    }
+
+    /// Schema pattern of chapter names: the built-in rule with the extra characters,
+    /// None when naming.chapter_pattern replaces the rule (G.P.5)
+    pub fn chapter_schema_pattern(&self) -> Option<String> {
+        if self.chapter_pattern.is_some() {
+            return None;
+        }
+        let extra = class_escape(&self.extra_chapter_characters);
+        Some(format!(
+            "^([A-Za-z0-9:_{0}-][A-Za-z0-9 :_{0}-]*)?[A-Za-z]([A-Za-z0-9 :_{0}-]*[A-Za-z0-9:_{0}-])?$",
+            extra
+        ))
+    }
+
+    /// Category pattern of the project, if configured (G.SE.5)
+    pub fn category_regex(&self) -> Option<Regex> {
+        self.category_pattern
+            .as_deref()
+            .and_then(|pattern| Regex::new(pattern).ok())
+    }
+
+    /// Chapter pattern of the project, if configured (G.SE.5)
+    pub fn chapter_regex(&self) -> Option<Regex> {
+        self.chapter_pattern
+            .as_deref()
+            .and_then(|pattern| Regex::new(pattern).ok())
+    }
+}
+
+/// Line and column of a byte offset, both starting at 1
+fn line_column(content: &str, offset: usize) -> (usize, usize) {
+    let before = &content[..offset.min(content.len())];
+    let line = before.matches('\n').count() + 1;
+    let column = before.len() - before.rfind('\n').map(|p| p + 1).unwrap_or(0) + 1;
+    (line, column)
+}
+
+/// Check a limit against its built-in maximum (G.SE.4)
+fn validate_limit_value(key: &str, value: usize, max: usize) -> Result<(), String> {
+    if (1..=max).contains(&value) {
+        Ok(())
+    } else {
+        Err(format!("{} must be between 1 and {}", key, max))
+    }
+}
+
+/// Check a configured name pattern (G.SE.5)
+fn validate_pattern(key: &str, value: &Option<String>) -> Result<(), String> {
+    match value {
+        Some(pattern) => Regex::new(pattern)
+            .map(|_| ())
+            .map_err(|e| format!("{} is not a valid regular expression: {}", key, e)),
+        None => Ok(()),
+    }
+}
+
+/// Check configured extra name characters (G.SE.5)
+fn validate_extra_characters(
+    key: &str,
+    value: &str,
+    pattern_key: &str,
+    pattern: &Option<String>,
+    invalid: impl Fn(char) -> bool,
+) -> Result<(), String> {
+    if value.is_empty() {
+        return Ok(());
+    }
+    if pattern.is_some() {
+        return Err(format!("{} cannot be combined with {}", key, pattern_key));
+    }
+    match value.chars().find(|&c| c.is_whitespace() || invalid(c)) {
+        Some(c) => Err(format!("{} contains an invalid character: {:?}", key, c)),
+        None => Ok(()),
+    }
+}
+
+/// Check the configuration values; errors name the offending key (G.SE.2)
+pub fn validate_config(config: &ProjectConfig) -> Result<(), String> {
+    if let Some(path) = &config.requirements.path {
+        validate_relative_path("requirements.path", path)?;
+    }
+    for path in &config.requirements.search_paths {
+        validate_relative_path("requirements.search_paths", path)?;
+    }
+
+    validate_limit_value(
+        "limits.max_text_len",
+        config.limits.max_text_len,
+        MAX_TEXT_LEN,
+    )?;
+    validate_limit_value(
+        "limits.max_title_len",
+        config.limits.max_title_len,
+        MAX_TITLE_LEN,
+    )?;
+    validate_limit_value(
+        "limits.max_batch_size",
+        config.limits.max_batch_size,
+        MAX_BATCH_SIZE,
+    )?;
+
+    validate_pattern("naming.category_pattern", &config.naming.category_pattern)?;
+    validate_pattern("naming.chapter_pattern", &config.naming.chapter_pattern)?;
+    validate_extra_characters(
+        "naming.extra_category_characters",
+        &config.naming.extra_category_characters,
+        "naming.category_pattern",
+        &config.naming.category_pattern,
+        |c| INVALID_FILENAME_CHARS.contains(&c),
+    )?;
+    validate_extra_characters(
+        "naming.extra_chapter_characters",
+        &config.naming.extra_chapter_characters,
+        "naming.chapter_pattern",
+        &config.naming.chapter_pattern,
+        |c| c == '#',
+    )?;
+
+    let mut categories_by_prefix: BTreeMap<&str, &str> = BTreeMap::new();
+    for (category, prefix) in &config.index.category_prefixes {
+        let key = format!("index.category_prefixes.{}", category);
+        crate::validation::validate_category_in(category, config)
+            .map_err(|e| format!("{}: {}", key, e))?;
+        // Same shape as the category part of an index (G.R.4)
+        let valid = prefix.starts_with(|c: char| c.is_ascii_uppercase())
+            && prefix
+                .chars()
+                .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit());
+        if !valid {
+            return Err(format!(
+                "{} must start with an uppercase English letter (A-Z) followed by uppercase letters or digits (0-9)",
+                key
+            ));
+        }
+        if let Some(other) = categories_by_prefix.insert(prefix, category) {
+            return Err(format!(
+                "{} uses the prefix {} of index.category_prefixes.{}",
+                key, prefix, other
+            ));
+        }
+    }
+
+    if let Some(template) = &config.instructions.template {
+        validate_relative_path("instructions.template", template)?;
+    }
+    for entry in &config.instructions.key_chapters {
+        let valid = entry.split_once('/').is_some_and(|(category, chapter)| {
+            crate::validation::validate_category_in(category, config).is_ok()
+                && crate::validation::validate_chapter_in(chapter, config).is_ok()
+        });
+        if !valid {
+            return Err(format!(
+                "instructions.key_chapters entries must have the form category/Chapter: {}",
+                entry
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Parse the content of reqlix.toml (G.SE.1, G.SE.2)
+pub fn parse_config(content: &str) -> Result<ProjectConfig, String> {
+    let config: ProjectConfig = toml::from_str(content).map_err(|e| match e.span() {
+        Some(span) => {
+            let (line, column) = line_column(content, span.start);
+            format!("line {}, column {}: {}", line, column, e.message())
+        }
+        None => e.message().to_string(),
+    })?;
+    validate_config(&config)?;
+    Ok(config)
+}
+
+/// Load the configuration of the project containing project_root (G.SE.1)
+///
+/// reqlix.toml is looked up in the directories of G.C.8; without one the defaults apply.
+pub fn load_project_config(project_root: &str) -> Result<ProjectConfig, String> {
+    let Some(dir) = get_discovery_dirs(project_root).pop() else {
+        return Ok(ProjectConfig::default());
+    };
+    let path = dir.join(CONFIG_FILE);
+    if !path.is_file() {
+        return Ok(ProjectConfig::default());
+    }
+    let content = fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    let mut config =
+        parse_config(&content).map_err(|e| format!("Invalid {}: {}", path.display(), e))?;
+    config.dir = Some(dir);
+    Ok(config)
+}
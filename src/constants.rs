@@ -7,10 +7,71 @@ pub(crate) const MAX_CHAPTER_LEN: usize = 100;
 pub(crate) const MAX_INDEX_LEN: usize = 100;
 pub(crate) const MAX_TEXT_LEN: usize = 10000;
 pub(crate) const MAX_TITLE_LEN: usize = 100;
+// G.P.3: characters allowed in names besides English letters and digits
+pub(crate) const CATEGORY_PUNCTUATION: &str = "_";
+pub(crate) const CHAPTER_PUNCTUATION: &str = " :-_";
+// G.P.3, G.P.5: the built-in name rules as schema patterns
+pub(crate) const CATEGORY_PATTERN: &str = "^[a-z0-9_]*[a-z][a-z0-9_]*$";
+pub(crate) const CHAPTER_PATTERN: &str =
+    "^([A-Za-z0-9:_-][A-Za-z0-9 :_-]*)?[A-Za-z]([A-Za-z0-9 :_-]*[A-Za-z0-9:_-])?$";
+// G.P.3, G.SE.5
+pub(crate) const INVALID_FILENAME_CHARS: &[char] = &['/', '\\', ':', '*', '?', '"', '<', '>', '|'];
 // G.REQLIX_GET_REQUIREMENT.5, G.REQLIX_U.7, G.TOOLREQLIXD.6, G.TOOLREQLIXS.5
 pub(crate) const MAX_BATCH_SIZE: usize = 100;
 // G.TOOLREQLIXS.5, G.P.1
 pub(crate) const MAX_KEYWORD_LEN: usize = 200;
+// G.TOOLREQLIXF.2
+pub(crate) const DEFAULT_SIMILARITY_THRESHOLD: f64 = 0.8;
+// G.TOOLREQLIXSU.2, G.TOOLREQLIXSU.5
+pub(crate) const DEFAULT_SUGGESTION_LIMIT: usize = 5;
+pub(crate) const MAX_SUGGESTION_LIMIT: usize = 100;
+// G.TOOLREQLIXT.5, G.P.1
+pub(crate) const MAX_GLOB_LEN: usize = 200;
+// G.RELA.1
+pub(crate) const RELATION_TYPES: &[&str] = &[
+    "refines",
+    "depends_on",
+    "conflicts_with",
+    "verifies",
+    "derived_from",
+];
+// G.TOOLREQLIXCH.3
+pub(crate) const REPLACEMENT_SIMILARITY_THRESHOLD: f64 = 0.8;
+// G.TOOLREQLIXC.3
+pub(crate) const DEFAULT_TEST_PATTERNS: &[&str] = &[
+    "**/tests/**",
+    "**/test/**",
+    "**/__tests__/**",
+    "**/*_test.*",
+    "**/*_tests.*",
+    "**/test_*.*",
+    "**/*.test.*",
+    "**/*.spec.*",
+];
+// G.TOOLREQLIXC.2, G.TOOLREQLIXC.8
+pub(crate) const COVERAGE_FORMATS: &[&str] = &["json", "markdown", "csv"];
+// G.TOOLREQLIXIMPO.5, G.P.1
+pub(crate) const MAX_FILE_PATH_LEN: usize = 1000;
+// G.TOOLREQLIXIMPO.2, G.TOOLREQLIXIMPO.5
+pub(crate) const TEST_RESULT_FORMATS: &[&str] = &["junit", "libtest"];
+// G.TOOLREQLIXIMPO.3
+pub(crate) const VERIFICATION_FILE: &str = "verification.json";
+// G.TOOLREQLIXE.2, G.TOOLREQLIXE.6
+pub(crate) const MATRIX_FORMATS: &[&str] = &["csv", "markdown", "html"];
+// G.TOOLREQLIXEXPORTG.2, G.TOOLREQLIXEXPORTG.6
+pub(crate) const GRAPH_FORMATS: &[&str] = &["dot", "mermaid"];
+pub(crate) const DEFAULT_GRAPH_RADIUS: usize = 1;
+pub(crate) const MAX_GRAPH_RADIUS: usize = 10;
+// G.MCPP.2, G.P.1
+pub(crate) const MAX_DIFF_LEN: usize = 1_000_000;
+// G.H.1
+pub(crate) const HTTP_PATH: &str = "/mcp";
+// G.C.8
+pub(crate) const REPOSITORY_MARKER: &str = ".git";
+// G.SE.1
+pub(crate) const CONFIG_FILE: &str = "reqlix.toml";
+// G.REQLIX_I.7
+pub(crate) const CHAPTER_NAME_SIMILARITY_THRESHOLD: f64 = 0.8;
 
 // G.REQLIX_GET_I.6
 pub(crate) const PLACEHOLDER_CONTENT: &str = r#"# Instructions
@@ -0,0 +1,253 @@
+// Requirement coverage report and renderings (G.TOOLREQLIXC.3 - G.TOOLREQLIXC.7)
+
+use crate::constants::DEFAULT_TEST_PATTERNS;
+use crate::models::{
+    CategoryCoverage, ChapterCoverage, CoverageReport, CoverageSummary, RequirementLocation,
+};
+use crate::trace::{build_glob_set, trace_requirements, TraceOptions};
+
+/// Scan options and test file patterns for the coverage report (G.TOOLREQLIXC.2, G.TOOLREQLIXC.3)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CoverageOptions {
+    pub trace: TraceOptions,
+    pub test_patterns: Vec<String>,
+}
+
+impl Default for CoverageOptions {
+    fn default() -> Self {
+        Self {
+            trace: TraceOptions::default(),
+            test_patterns: DEFAULT_TEST_PATTERNS
+                .iter()
+                .map(|p| p.to_string())
+                .collect(),
+        }
+    }
+}
+
+/// Percentage rounded to 1 decimal place, 0.0 for empty totals (G.TOOLREQLIXC.4 step 4)
+fn percent(count: usize, total: usize) -> f64 {
+    if total == 0 {
+        return 0.0;
+    }
+    (1000.0 * count as f64 / total as f64).round() / 10.0
+}
+
+impl CoverageSummary {
+    /// Add one requirement to the counts
+    fn add(&mut self, referenced: bool, tested: bool) {
+        self.requirements += 1;
+        self.referenced += usize::from(referenced);
+        self.tested += usize::from(tested);
+        self.referenced_percent = percent(self.referenced, self.requirements);
+        self.tested_percent = percent(self.tested, self.requirements);
+    }
+}
+
+/// Build the coverage report for a project (G.TOOLREQLIXC.4)
+#[cfg_attr(test, allow(dead_code))]
+pub fn coverage_report(
+    project_root: &str,
+    options: &CoverageOptions,
+) -> Result<CoverageReport, String> {
+    let test_files = build_glob_set("test_patterns", &options.test_patterns)?;
+
+    // G.TOOLREQLIXC.4 step 2: Trace
+    let trace = trace_requirements(project_root, &options.trace)?;
+
+    let mut report = CoverageReport {
+        files_scanned: trace.files_scanned,
+        ..Default::default()
+    };
+    for requirement in trace.requirements {
+        // G.TOOLREQLIXC.4 step 3: Classify
+        let referenced = !requirement.locations.is_empty();
+        let tested = requirement
+            .locations
+            .iter()
+            .any(|l| test_files.is_match(&l.file));
+
+        // G.TOOLREQLIXC.4 step 4: Summarize
+        report.total.add(referenced, tested);
+        let category_position = report
+            .categories
+            .iter()
+            .position(|c| c.category == requirement.category)
+            .unwrap_or_else(|| {
+                report.categories.push(CategoryCoverage {
+                    category: requirement.category.clone(),
+                    summary: CoverageSummary::default(),
+                });
+                report.categories.len() - 1
+            });
+        report.categories[category_position]
+            .summary
+            .add(referenced, tested);
+        let chapter_position = report
+            .chapters
+            .iter()
+            .position(|c| c.category == requirement.category && c.chapter == requirement.chapter)
+            .unwrap_or_else(|| {
+                report.chapters.push(ChapterCoverage {
+                    category: requirement.category.clone(),
+                    chapter: requirement.chapter.clone(),
+                    summary: CoverageSummary::default(),
+                });
+                report.chapters.len() - 1
+            });
+        report.chapters[chapter_position]
+            .summary
+            .add(referenced, tested);
+
+        // G.TOOLREQLIXC.4 step 5: List gaps
+        let location = RequirementLocation {
+            index: requirement.index,
+            title: requirement.title,
+            category: requirement.category,
+            chapter: requirement.chapter,
+        };
+        if !referenced {
+            report.unreferenced.push(location);
+        } else if !tested {
+            report.untested.push(location);
+        }
+    }
+
+    Ok(report)
+}
+
+/// Escape a markdown table cell (G.TOOLREQLIXC.6, G.TOOLREQLIXE.5)
+pub(crate) fn markdown_cell(value: &str) -> String {
+    value.replace('|', "\\|")
+}
+
+/// Format "count (percent%)" for markdown tables (G.TOOLREQLIXC.6)
+fn markdown_counts(summary: &CoverageSummary) -> String {
+    format!(
+        "{} | {} ({:.1}%) | {} ({:.1}%)",
+        summary.requirements,
+        summary.referenced,
+        summary.referenced_percent,
+        summary.tested,
+        summary.tested_percent
+    )
+}
+
+/// Markdown list of requirements, or "None." (G.TOOLREQLIXC.6)
+fn markdown_list(out: &mut String, requirements: &[RequirementLocation]) {
+    if requirements.is_empty() {
+        out.push_str("None.\n");
+    }
+    for r in requirements {
+        out.push_str(&format!(
+            "- {}: {} ({} / {})\n",
+            r.index, r.title, r.category, r.chapter
+        ));
+    }
+}
+
+/// Render the coverage report as markdown (G.TOOLREQLIXC.6)
+#[cfg_attr(test, allow(dead_code))]
+pub fn render_coverage_markdown(report: &CoverageReport) -> String {
+    let mut out = String::new();
+    out.push_str("# Requirements Coverage\n\n");
+    out.push_str(&format!("Files scanned: {}\n\n", report.files_scanned));
+
+    out.push_str("## Summary\n\n");
+    out.push_str("| Scope | Requirements | Referenced | Tested |\n");
+    out.push_str("|---|---|---|---|\n");
+    out.push_str(&format!(
+        "| Total | {} |\n\n",
+        markdown_counts(&report.total)
+    ));
+
+    out.push_str("## Categories\n\n");
+    out.push_str("| Category | Requirements | Referenced | Tested |\n");
+    out.push_str("|---|---|---|---|\n");
+    for c in &report.categories {
+        out.push_str(&format!(
+            "| {} | {} |\n",
+            markdown_cell(&c.category),
+            markdown_counts(&c.summary)
+        ));
+    }
+    out.push('\n');
+
+    out.push_str("## Chapters\n\n");
+    out.push_str("| Category | Chapter | Requirements | Referenced | Tested |\n");
+    out.push_str("|---|---|---|---|---|\n");
+    for c in &report.chapters {
+        out.push_str(&format!(
+            "| {} | {} | {} |\n",
+            markdown_cell(&c.category),
+            markdown_cell(&c.chapter),
+            markdown_counts(&c.summary)
+        ));
+    }
+    out.push('\n');
+
+    out.push_str("## Unreferenced requirements\n\n");
+    markdown_list(&mut out, &report.unreferenced);
+    out.push('\n');
+
+    out.push_str("## Referenced in code only\n\n");
+    markdown_list(&mut out, &report.untested);
+
+    out
+}
+
+/// Quote a CSV field when needed (G.TOOLREQLIXC.7)
+pub fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// One CSV line for a scope (G.TOOLREQLIXC.7)
+fn csv_line(scope: &str, category: &str, chapter: &str, summary: &CoverageSummary) -> String {
+    format!(
+        "{},{},{},{},{},{},{:.1},{:.1},,\n",
+        scope,
+        csv_field(category),
+        csv_field(chapter),
+        summary.requirements,
+        summary.referenced,
+        summary.tested,
+        summary.referenced_percent,
+        summary.tested_percent
+    )
+}
+
+/// CSV lines for a list of requirements, without counts (G.TOOLREQLIXC.7)
+fn csv_requirements(out: &mut String, scope: &str, requirements: &[RequirementLocation]) {
+    for r in requirements {
+        out.push_str(&format!(
+            "{},{},{},,,,,,{},{}\n",
+            scope,
+            csv_field(&r.category),
+            csv_field(&r.chapter),
+            csv_field(&r.index),
+            csv_field(&r.title)
+        ));
+    }
+}
+
+/// Render the coverage report as CSV (G.TOOLREQLIXC.7)
+#[cfg_attr(test, allow(dead_code))]
+pub fn render_coverage_csv(report: &CoverageReport) -> String {
+    let mut out = String::from(
+        "scope,category,chapter,requirements,referenced,tested,referenced_percent,tested_percent,index,title\n",
+    );
+    out.push_str(&csv_line("total", "", "", &report.total));
+    for c in &report.categories {
+        out.push_str(&csv_line("category", &c.category, "", &c.summary));
+    }
+    for c in &report.chapters {
+        out.push_str(&csv_line("chapter", &c.category, &c.chapter, &c.summary));
+    }
+    csv_requirements(&mut out, "unreferenced", &report.unreferenced);
+    csv_requirements(&mut out, "untested", &report.untested);
+    out
+}
@@ -0,0 +1,188 @@
+// Dangling requirement references in source code (G.TR.6, G.TOOLREQLIXCH.3)
+
+use crate::config::load_project_config;
+use crate::constants::REPLACEMENT_SIMILARITY_THRESHOLD;
+use crate::filesystem::existing_requirements_dir;
+use crate::helpers::find_category_by_prefix;
+use crate::models::{DanglingReference, DanglingReport, RequirementLocation};
+use crate::parsing::{parse_index, parse_level2_heading};
+use crate::similarity::{name_similarity, normalize_name};
+use crate::trace::{collect_requirements, find_index_references, scan_references, TraceOptions};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+/// Last known title of every index from the git history of the requirements directory (G.TR.6)
+/// Returns an empty map if git is unavailable or the directory is not tracked.
+#[cfg_attr(test, allow(dead_code))]
+pub fn requirement_history(requirements_dir: &Path) -> HashMap<String, String> {
+    let mut history = HashMap::new();
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(requirements_dir)
+        .args([
+            "log",
+            "--format=%x1e",
+            "--patch",
+            "--unified=0",
+            "--no-color",
+            "--no-ext-diff",
+            "--",
+            ".",
+        ])
+        .output();
+    let Ok(output) = output else {
+        return history;
+    };
+    if !output.status.success() {
+        return history;
+    }
+
+    // Newest commit comes first; each commit starts with a record separator line
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    for commit in stdout.split('\x1e') {
+        let mut added: HashMap<String, String> = HashMap::new();
+        let mut removed: HashMap<String, String> = HashMap::new();
+        for line in commit.lines() {
+            let (headings, heading) = if let Some(heading) = line.strip_prefix('+') {
+                (&mut added, heading)
+            } else if let Some(heading) = line.strip_prefix('-') {
+                (&mut removed, heading)
+            } else {
+                continue;
+            };
+            if let Some((index, title)) = parse_level2_heading(heading) {
+                headings.entry(index).or_insert(title);
+            }
+        }
+        // Added headings of a commit are newer than removed ones
+        for (index, title) in added.into_iter().chain(removed) {
+            history.entry(index).or_insert(title);
+        }
+    }
+    history
+}
+
+/// Requirement with the most similar title, if similar enough (G.TOOLREQLIXCH.3 step 6)
+fn most_similar_title<'a>(
+    title: &str,
+    requirements: &'a [RequirementLocation],
+) -> Option<&'a RequirementLocation> {
+    let mut best: Option<(&RequirementLocation, f64)> = None;
+    for requirement in requirements {
+        let score = name_similarity(title, &requirement.title);
+        if score >= REPLACEMENT_SIMILARITY_THRESHOLD && best.is_none_or(|(_, s)| score > s) {
+            best = Some((requirement, score));
+        }
+    }
+    best.map(|(r, _)| r)
+}
+
+/// Requirement whose title occurs in the source line, longest title first (G.TOOLREQLIXCH.3 step 6)
+fn title_in_line<'a>(
+    line: &str,
+    requirements: &'a [RequirementLocation],
+) -> Option<&'a RequirementLocation> {
+    let mut text = line.to_string();
+    for index in find_index_references(line) {
+        text = text.replacen(&index, " ", 1);
+    }
+    let haystack = format!(" {} ", normalize_name(&text));
+
+    let mut best: Option<(&RequirementLocation, usize)> = None;
+    for requirement in requirements {
+        let title = normalize_name(&requirement.title);
+        if title.is_empty() || !haystack.contains(&format!(" {} ", title)) {
+            continue;
+        }
+        if best.is_none_or(|(_, len)| title.len() > len) {
+            best = Some((requirement, title.len()));
+        }
+    }
+    best.map(|(r, _)| r)
+}
+
+/// Find references to indices that are not existing requirements (G.TR.5, G.TOOLREQLIXCH.3)
+#[cfg_attr(test, allow(dead_code))]
+pub fn find_dangling_references(
+    project_root: &str,
+    options: &TraceOptions,
+) -> Result<DanglingReport, String> {
+    let requirements_dir = existing_requirements_dir(project_root)?;
+    let config = load_project_config(project_root)?;
+
+    // G.TOOLREQLIXCH.3 step 2: Collect requirements
+    let requirements = collect_requirements(&requirements_dir)?;
+    let existing: HashSet<&str> = requirements.iter().map(|r| r.index.as_str()).collect();
+
+    // G.TOOLREQLIXCH.3 step 3: Scan references
+    let scan = scan_references(Path::new(project_root), &requirements_dir, options)?;
+
+    let mut report = DanglingReport {
+        files_scanned: scan.files_scanned,
+        references: Vec::new(),
+    };
+    let mut history: Option<HashMap<String, String>> = None;
+    let mut categories: HashMap<String, Option<String>> = HashMap::new();
+    let mut files: HashMap<String, Vec<String>> = HashMap::new();
+    for reference in scan.references {
+        // G.TOOLREQLIXCH.3 step 4: Resolve
+        if existing.contains(reference.index.as_str()) {
+            continue;
+        }
+        let (category_prefix, _, _) = parse_index(&reference.index)?;
+        let category = categories
+            .entry(category_prefix)
+            .or_insert_with_key(|prefix| {
+                find_category_by_prefix(&requirements_dir, prefix, &config.index).ok()
+            })
+            .clone();
+
+        // G.TOOLREQLIXCH.3 step 5: Classify
+        let history = history.get_or_insert_with(|| requirement_history(&requirements_dir));
+        let last_title = history.get(&reference.index).cloned();
+        let same_title = last_title.as_ref().and_then(|title| {
+            let normalized = normalize_name(title);
+            requirements
+                .iter()
+                .find(|r| normalize_name(&r.title) == normalized)
+        });
+        let status = match (&last_title, same_title) {
+            (_, Some(_)) => "moved",
+            (Some(_), None) => "deleted",
+            (None, _) => "missing",
+        };
+
+        // G.TOOLREQLIXCH.3 step 6: Guess replacement
+        let replacement = same_title
+            .or_else(|| {
+                last_title
+                    .as_ref()
+                    .and_then(|title| most_similar_title(title, &requirements))
+            })
+            .or_else(|| {
+                let lines = files.entry(reference.file.clone()).or_insert_with(|| {
+                    fs::read_to_string(Path::new(project_root).join(&reference.file))
+                        .map(|content| content.lines().map(str::to_string).collect())
+                        .unwrap_or_default()
+                });
+                lines
+                    .get(reference.line - 1)
+                    .and_then(|line| title_in_line(line, &requirements))
+            })
+            .cloned();
+
+        report.references.push(DanglingReference {
+            index: reference.index,
+            file: reference.file,
+            line: reference.line,
+            status: status.to_string(),
+            category,
+            last_title,
+            replacement,
+        });
+    }
+
+    Ok(report)
+}
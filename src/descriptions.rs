@@ -36,17 +36,23 @@ Batch request: Returns \"success\": true and \"data\": [{...}, ...]. Each elemen
 pub(crate) const INSERT_REQUIREMENT_DESC: &str = "Inserts a new requirement into the specified category \
 and chapter. The title must be generated by the LLM and provided as a parameter. \
 The title must be a concise name that reflects the essence of the requirement. \
-Category must contain only lowercase English letters (a-z) and underscore (_). \
-Chapter must contain only uppercase and lowercase English letters (A-Z, a-z), spaces, colons (:), and hyphens (-). \
+Optional relations (refines, depends_on, conflicts_with, verifies, derived_from) to existing requirements are stored \
+as metadata lines in the text. \
+Category must contain only lowercase English letters (a-z), digits (0-9) and underscore (_), and at least one letter. \
+Chapter must contain only uppercase and lowercase English letters (A-Z, a-z), digits (0-9), spaces, colons (:), and hyphens (-), and at least one letter. \
 Returns JSON with \"success\": true and \"data\": {\"index\": \"...\", \"title\": \"...\", \"text\": \"...\", \"category\": \"...\", \"chapter\": \"...\"}. \
+If a new chapter is created whose name is close to an existing chapter name, \"data\" also contains \"warnings\": [...]. \
+Set warn_similar_chapter to false to skip this check. \
 On error (title already exists, file system error, validation error), returns JSON with \"success\": false and \"error\": \"error message\".";
 
 // G.REQLIX_U.1
 pub(crate) const UPDATE_REQUIREMENT_DESC: &str = "Updates one or more existing requirements by index with new text and optional new title. \
 If title is provided, it must be unique within the chapter. If not provided, the existing title is kept. \
 Supports batch updates with up to 100 requirements. \
-Category must contain only lowercase English letters (a-z) and underscore (_). \
-Chapter must contain only uppercase and lowercase English letters (A-Z, a-z), spaces, colons (:), and hyphens (-). \
+If relations are provided, they replace the relation lines in the text. \
+When a title changes, links to the old requirement anchor are rewritten if rewrite_links is true. \
+Category must contain only lowercase English letters (a-z), digits (0-9) and underscore (_), and at least one letter. \
+Chapter must contain only uppercase and lowercase English letters (A-Z, a-z), digits (0-9), spaces, colons (:), and hyphens (-), and at least one letter. \
 Single update: Returns JSON with \"success\": true and \"data\": {...}. On error, returns \"success\": false. \
 Batch update: Returns \"success\": true and \"data\": [{...}, ...]. Each element has its own \"success\" and \"data\" or \"error\" field.";
 
@@ -60,6 +66,7 @@ Returns JSON with \"success\": true and \"data\": {\"version\": \"x.y.z\"}.";
 pub(crate) const DELETE_REQUIREMENT_DESC: &str = "Deletes one or more existing requirements by index. \
 The requirements will be permanently removed from the category file. \
 Supports batch deletions with up to 100 indices. \
+Refuses to delete requirements that other requirements still link to, unless force is true. \
 Single delete: Returns JSON with \"success\": true and \"data\": {...}. On error, returns \"success\": false. \
 Batch delete: Returns \"success\": true and \"data\": [{...}, ...]. Each element has its own \"success\" and \"data\" or \"error\" field.";
 
@@ -72,3 +79,111 @@ Search is case-insensitive. \
 Returns JSON with \"success\": true and \"data\": {\"keywords\": [...], \"results\": [...]}. \
 If keywords array is empty, returns success with empty results array. \
 On error, returns JSON with \"success\": false and \"error\": \"error message\".";
+
+// G.TOOLREQLIXF.1
+pub(crate) const FIND_DUPLICATES_DESC: &str = "Finds duplicated requirements across all categories. \
+Reports indices used by more than one requirement heading, titles shared by more than one requirement, \
+and pairs of requirements whose texts are near-duplicates by TF-IDF cosine similarity. \
+Optional threshold (0.0 to 1.0, default 0.8) sets the minimum similarity for near-duplicates. \
+Returns JSON with \"success\": true and \"data\": {\"threshold\": ..., \"duplicate_indices\": [...], \"duplicate_titles\": [...], \"near_duplicates\": [...]}. \
+On error, returns JSON with \"success\": false and \"error\": \"error message\".";
+
+// G.TOOLREQLIXSU.1
+pub(crate) const SUGGEST_LOCATION_DESC: &str =
+    "Suggests where to insert a new requirement before calling reqlix_insert_requirement. \
+Given a draft title and text, ranks existing category/chapter pairs by TF-IDF cosine similarity to the draft \
+and lists existing requirements that look similar to it. \
+Optional limit (1 to 100, default 5) sets the maximum number of entries in each list. \
+Returns JSON with \"success\": true and \"data\": {\"locations\": [...], \"similar_requirements\": [...]}. \
+On error, returns JSON with \"success\": false and \"error\": \"error message\".";
+
+// G.TOOLREQLIXT.1
+pub(crate) const TRACE_DESC: &str = "Traces requirement references in source code. \
+Walks the project tree under project_root (honoring .gitignore and optional include/exclude globs) and finds \
+requirement indices cited in source files (e.g. \"// G.R.3\"). \
+Returns every requirement with the file:line locations that cite it. \
+Returns JSON with \"success\": true and \"data\": {\"files_scanned\": ..., \"requirements\": [...]}. \
+On error, returns JSON with \"success\": false and \"error\": \"error message\".";
+
+// G.TOOLREQLIXC.1
+pub(crate) const COVERAGE_DESC: &str = "Reports how well requirements are covered by code and tests. \
+Scans the project like reqlix_trace and classifies every citing file as a test file or a code file. \
+Lists requirements that are never referenced and requirements referenced in code but not in tests, \
+with coverage percentages overall, per category and per chapter. \
+Optional format \"markdown\" or \"csv\" adds a rendered report. \
+Returns JSON with \"success\": true and \"data\": {\"files_scanned\": ..., \"total\": {...}, \"categories\": [...], \"chapters\": [...], \"unreferenced\": [...], \"untested\": [...]}. \
+On error, returns JSON with \"success\": false and \"error\": \"error message\".";
+
+// G.TOOLREQLIXCH.1
+pub(crate) const CHECK_REFERENCES_DESC: &str = "Finds dangling requirement references in source code. \
+Scans the project like reqlix_trace and reports every cited index that is not an existing requirement: \
+\"missing\" (never existed), \"deleted\" (removed) or \"moved\" (renumbered, same title under a new index). \
+Each reference has file, line and a best-guess replacement requirement, if any. \
+Returns JSON with \"success\": true and \"data\": {\"files_scanned\": ..., \"references\": [...]}. \
+On error, returns JSON with \"success\": false and \"error\": \"error message\".";
+
+// G.TOOLREQLIXGETREF.1
+pub(crate) const GET_REFERENCES_DESC: &str = "Returns the cross-references of a requirement. \
+Links between requirements are index references (e.g. \"G.C.2\") and anchor links (e.g. \"(#gc2-directory-creation)\") \
+in requirement texts. \
+Returns the requirement with its outgoing links and its backlinks (requirements that link to it). \
+Returns JSON with \"success\": true and \"data\": {\"index\": ..., \"title\": ..., \"category\": ..., \"chapter\": ..., \"outgoing\": [...], \"backlinks\": [...]}. \
+On error, returns JSON with \"success\": false and \"error\": \"error message\".";
+
+// G.TOOLREQLIXIM.1
+pub(crate) const IMPACT_DESC: &str = "Lists everything affected by a change to a requirement. \
+Walks typed relations (refines, depends_on, conflicts_with, verifies, derived_from) transitively from the given index \
+and returns every affected requirement with the relation that reached it, plus the source code locations that cite \
+the requirement or any affected requirement. \
+Returns JSON with \"success\": true and \"data\": {\"index\": ..., \"affected\": [...], \"files_scanned\": ..., \"locations\": [...]}. \
+On error, returns JSON with \"success\": false and \"error\": \"error message\".";
+
+// G.TOOLREQLIXCHECKL.1
+pub(crate) const CHECK_LINKS_DESC: &str = "Finds broken markdown links in the requirements directory. \
+Checks every link to a heading anchor (e.g., #gr3-requirement-definition) or markdown file, within and across \
+requirement files, and reports links whose file or anchor does not exist, with a suggested replacement when the \
+requirement was renamed. \
+Returns JSON with \"success\": true and \"data\": {\"files_scanned\": ..., \"links_checked\": ..., \"broken\": [...]}. \
+On error, returns JSON with \"success\": false and \"error\": \"error message\".";
+
+// G.TOOLREQLIXR.1
+pub(crate) const REWRITE_REFERENCES_DESC: &str = "Rewrites requirement index references after requirements are moved or renumbered. \
+Applies old to new index mappings (e.g., G.R.1 -> G.S.1) at the same time to requirement texts and source files, \
+matching whole index tokens only, so G.R.1 never changes G.R.10. Requirement headings are not changed. \
+Use dry_run to get the diff without writing files. \
+Returns JSON with \"success\": true and \"data\": {\"dry_run\": ..., \"files_changed\": ..., \"replacements\": ..., \"changes\": [...], \"diff\": ...}. \
+On error, returns JSON with \"success\": false and \"error\": \"error message\".";
+
+// G.TOOLREQLIXTE.1
+pub(crate) const TEST_MAP_DESC: &str = "Maps Rust test functions to the requirements they cover. \
+Parses #[test] functions and their preceding \"Covers Requirement:\" comments, and reports every test with its \
+requirement indices, tests without any requirement reference, and requirements that no test covers. \
+Returns JSON with \"success\": true and \"data\": {\"files_scanned\": ..., \"tests\": [...], \"unreferenced_tests\": [...], \"untested_requirements\": [...]}. \
+On error, returns JSON with \"success\": false and \"error\": \"error message\".";
+
+// G.TOOLREQLIXIMPO.1
+pub(crate) const IMPORT_TEST_RESULTS_DESC: &str = "Imports test results and computes the verification status of every requirement. \
+Reads JUnit XML or libtest JSON (cargo test -- -Z unstable-options --format json) from a local file, links results to \
+requirements through the \"Covers Requirement\" comments of the tests (see reqlix_test_map), and marks each requirement \
+as \"verified\" (all linked tests passed), \"failing\" (a linked test failed) or \"unverified\". \
+The result is stored with a timestamp in verification.json in the requirements directory. \
+Returns JSON with \"success\": true and \"data\": {\"imported_at\": ..., \"summary\": {...}, \"requirements\": [...], ...}. \
+On error, returns JSON with \"success\": false and \"error\": \"error message\".";
+
+// G.TOOLREQLIXE.1
+pub(crate) const EXPORT_MATRIX_DESC: &str = "Exports the requirements traceability matrix for audits and releases. \
+One row per requirement with index, title, category, chapter, coverage status, code references, test references and \
+the verification result of the last reqlix_import_test_results import. \
+Formats: \"csv\", \"markdown\" or \"html\" (a self-contained static page without external resources). \
+Optional \"output\" writes the matrix to a file. Works offline from the requirements directory and the source tree. \
+Returns JSON with \"success\": true and \"data\": {\"format\": ..., \"rows\": [...], \"rendered\": ..., ...}. \
+On error, returns JSON with \"success\": false and \"error\": \"error message\".";
+
+// G.TOOLREQLIXEXPORTG.1
+pub(crate) const EXPORT_GRAPH_DESC: &str = "Exports the requirement graph as Graphviz DOT or Mermaid flowchart text for architecture reviews. \
+Requirements are grouped by category and chapter; edges are cross-references (dashed) and typed relations (labeled \
+with the relation type). \
+Optional \"category\" limits the graph to one category; optional \"index\" with \"radius\" limits it to the requirements \
+within that many links of one requirement. \
+Returns JSON with \"success\": true and \"data\": {\"format\": ..., \"nodes\": ..., \"edges\": ..., \"rendered\": \"...\"}. \
+On error, returns JSON with \"success\": false and \"error\": \"error message\".";
@@ -0,0 +1,243 @@
+// Requirement graph export to Graphviz DOT and Mermaid (G.TOOLREQLIXEXPORTG.3 - G.TOOLREQLIXEXPORTG.5)
+
+use crate::graph::ReferenceGraph;
+use crate::models::RequirementLocation;
+
+/// Edge of the exported graph: source and target positions, relation type or None for a link
+type DiagramEdge = (usize, usize, Option<&'static str>);
+
+/// Nodes of one category grouped by chapter (G.TOOLREQLIXEXPORTG.5)
+type CategoryGroup<'a> = (&'a str, Vec<(&'a str, Vec<&'a RequirementLocation>)>);
+
+/// Selected part of the reference graph (G.TOOLREQLIXEXPORTG.3 steps 3-4)
+#[derive(Debug, Clone, Default)]
+pub struct Diagram {
+    pub nodes: Vec<RequirementLocation>,
+    edges: Vec<DiagramEdge>,
+}
+
+impl Diagram {
+    /// Select nodes by category and neighborhood, then the edges between them (G.TOOLREQLIXEXPORTG.3)
+    pub fn select(
+        graph: &ReferenceGraph,
+        category: Option<&str>,
+        neighborhood: Option<(&str, usize)>,
+    ) -> Result<Self, String> {
+        let requirements = graph.requirements();
+        let mut selected = vec![true; requirements.len()];
+
+        // G.TOOLREQLIXEXPORTG.3 step 3: Category filter
+        if let Some(category) = category {
+            if !requirements.iter().any(|r| r.category == category) {
+                return Err("Category not found".to_string());
+            }
+            for (keep, requirement) in selected.iter_mut().zip(requirements) {
+                *keep &= requirement.category == category;
+            }
+        }
+
+        // G.TOOLREQLIXEXPORTG.3 step 3: Neighborhood filter, links and relations in either direction
+        if let Some((index, radius)) = neighborhood {
+            let start = graph
+                .position(index)
+                .ok_or_else(|| "Requirement not found".to_string())?;
+            let mut neighbors: Vec<Vec<usize>> = vec![Vec::new(); requirements.len()];
+            for source in 0..requirements.len() {
+                let targets = graph.link_targets(source).iter().copied().chain(
+                    graph
+                        .relation_targets(source)
+                        .iter()
+                        .map(|&(_, target)| target),
+                );
+                for target in targets {
+                    neighbors[source].push(target);
+                    neighbors[target].push(source);
+                }
+            }
+            let mut reached = vec![false; requirements.len()];
+            reached[start] = true;
+            let mut frontier = vec![start];
+            for _ in 0..radius {
+                let mut next = Vec::new();
+                for &position in &frontier {
+                    for &neighbor in &neighbors[position] {
+                        if !reached[neighbor] {
+                            reached[neighbor] = true;
+                            next.push(neighbor);
+                        }
+                    }
+                }
+                frontier = next;
+            }
+            for (keep, reached) in selected.iter_mut().zip(reached) {
+                *keep &= reached;
+            }
+        }
+
+        // Positions of selected requirements among the nodes
+        let mut diagram = Diagram::default();
+        let mut node_positions: Vec<Option<usize>> = vec![None; requirements.len()];
+        for (position, requirement) in requirements.iter().enumerate() {
+            if selected[position] {
+                node_positions[position] = Some(diagram.nodes.len());
+                diagram.nodes.push(requirement.clone());
+            }
+        }
+
+        // G.TOOLREQLIXEXPORTG.3 step 4: Relation edges, then links without a relation
+        for source in 0..requirements.len() {
+            let Some(from) = node_positions[source] else {
+                continue;
+            };
+            let relations = graph.relation_targets(source);
+            for &(relation, target) in relations {
+                if let Some(to) = node_positions[target] {
+                    diagram.edges.push((from, to, Some(relation)));
+                }
+            }
+            for &target in graph.link_targets(source) {
+                if let Some(to) = node_positions[target] {
+                    if !relations.iter().any(|&(_, t)| t == target) {
+                        diagram.edges.push((from, to, None));
+                    }
+                }
+            }
+        }
+        Ok(diagram)
+    }
+
+    /// Number of edges
+    pub fn edge_count(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// Nodes grouped by category and chapter in order of first appearance (G.TOOLREQLIXEXPORTG.5)
+    fn groups(&self) -> Vec<CategoryGroup<'_>> {
+        let mut groups: Vec<CategoryGroup> = Vec::new();
+        for node in &self.nodes {
+            let category = match groups.iter().position(|(c, _)| *c == node.category) {
+                Some(i) => i,
+                None => {
+                    groups.push((&node.category, Vec::new()));
+                    groups.len() - 1
+                }
+            };
+            let chapters = &mut groups[category].1;
+            match chapters.iter().position(|(c, _)| *c == node.chapter) {
+                Some(i) => chapters[i].1.push(node),
+                None => chapters.push((&node.chapter, vec![node])),
+            }
+        }
+        groups
+    }
+}
+
+/// Escape `\` and `"` for a quoted DOT string (G.TOOLREQLIXEXPORTG.5)
+fn dot_escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Quote a DOT string (G.TOOLREQLIXEXPORTG.5)
+fn dot_string(value: &str) -> String {
+    format!("\"{}\"", dot_escape(value))
+}
+
+/// Render the diagram as Graphviz DOT (G.TOOLREQLIXEXPORTG.5)
+#[cfg_attr(test, allow(dead_code))]
+pub fn render_dot(diagram: &Diagram) -> String {
+    let mut out = String::from("digraph requirements {\n  rankdir=LR;\n  node [shape=box];\n");
+    for (c, (category, chapters)) in diagram.groups().into_iter().enumerate() {
+        out.push_str(&format!("  subgraph cluster_c{} {{\n", c + 1));
+        out.push_str(&format!("    label={};\n", dot_string(category)));
+        for (h, (chapter, nodes)) in chapters.into_iter().enumerate() {
+            out.push_str(&format!("    subgraph cluster_c{}_{} {{\n", c + 1, h + 1));
+            out.push_str(&format!("      label={};\n", dot_string(chapter)));
+            for node in nodes {
+                out.push_str(&format!(
+                    "      {} [label=\"{}\\n{}\"];\n",
+                    dot_string(&node.index),
+                    dot_escape(&node.index),
+                    dot_escape(&node.title)
+                ));
+            }
+            out.push_str("    }\n");
+        }
+        out.push_str("  }\n");
+    }
+    for &(source, target, relation) in &diagram.edges {
+        let attributes = match relation {
+            Some(relation) => format!("label={}", dot_string(relation)),
+            None => "style=dashed".to_string(),
+        };
+        out.push_str(&format!(
+            "  {} -> {} [{}];\n",
+            dot_string(&diagram.nodes[source].index),
+            dot_string(&diagram.nodes[target].index),
+            attributes
+        ));
+    }
+    out.push_str("}\n");
+    out
+}
+
+/// Mermaid node id: index with characters other than letters, digits and `_` replaced (G.TOOLREQLIXEXPORTG.5)
+fn mermaid_id(index: &str) -> String {
+    index
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// Quote a Mermaid label (G.TOOLREQLIXEXPORTG.5)
+fn mermaid_label(value: &str) -> String {
+    format!("[\"{}\"]", value.replace('"', "#quot;"))
+}
+
+/// Render the diagram as a Mermaid flowchart (G.TOOLREQLIXEXPORTG.5)
+#[cfg_attr(test, allow(dead_code))]
+pub fn render_mermaid(diagram: &Diagram) -> String {
+    let mut out = String::from("flowchart LR\n");
+    for (c, (category, chapters)) in diagram.groups().into_iter().enumerate() {
+        out.push_str(&format!(
+            "  subgraph c{}{}\n",
+            c + 1,
+            mermaid_label(category)
+        ));
+        for (h, (chapter, nodes)) in chapters.into_iter().enumerate() {
+            out.push_str(&format!(
+                "    subgraph c{}_{}{}\n",
+                c + 1,
+                h + 1,
+                mermaid_label(chapter)
+            ));
+            for node in nodes {
+                out.push_str(&format!(
+                    "      {}{}\n",
+                    mermaid_id(&node.index),
+                    mermaid_label(&format!("{}: {}", node.index, node.title))
+                ));
+            }
+            out.push_str("    end\n");
+        }
+        out.push_str("  end\n");
+    }
+    for &(source, target, relation) in &diagram.edges {
+        let arrow = match relation {
+            Some(relation) => format!("-->|{}|", relation),
+            None => "-.->".to_string(),
+        };
+        out.push_str(&format!(
+            "  {} {} {}\n",
+            mermaid_id(&diagram.nodes[source].index),
+            arrow,
+            mermaid_id(&diagram.nodes[target].index)
+        ));
+    }
+    out
+}
@@ -1,9 +1,62 @@
-// File system helpers (G.REQLIX_GET_I.3, G.REQLIX_GET_I.4, G.C.1, G.C.2, G.R.8, G.R.9, G.R.10)
+// File system helpers (G.REQLIX_GET_I.3, G.REQLIX_GET_I.4, G.C.1, G.C.2, G.C.8, G.SE.3, G.I.4, G.P.6, G.R.8, G.R.9, G.R.10)
 
-use crate::constants::PLACEHOLDER_CONTENT;
+use crate::config::{load_project_config, ProjectConfig};
+use crate::constants::{CONFIG_FILE, PLACEHOLDER_CONTENT, REPOSITORY_MARKER};
 use std::env;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Component, Path, PathBuf};
+
+/// Check that a path is relative and stays inside the project: no root, prefix or `..` components (G.SE.3, G.P.6)
+pub(crate) fn validate_relative_path(key: &str, value: &str) -> Result<(), String> {
+    let path = Path::new(value);
+    let inside = !value.is_empty()
+        && path
+            .components()
+            .all(|component| matches!(component, Component::Normal(_) | Component::CurDir));
+    if inside {
+        Ok(())
+    } else {
+        Err(format!(
+            "{} must be a relative path inside the project: {}",
+            key, value
+        ))
+    }
+}
+
+/// Resolve a file parameter to a canonical path inside the project root (G.P.6)
+///
+/// The nearest existing ancestor of the path is canonicalized, so symbolic links that
+/// leave the project are rejected also for files that do not exist yet.
+#[cfg_attr(test, allow(dead_code))]
+pub(crate) fn resolve_project_path(
+    project_root: &str,
+    key: &str,
+    value: &str,
+) -> Result<PathBuf, String> {
+    validate_relative_path(key, value)?;
+    let root = Path::new(project_root)
+        .canonicalize()
+        .map_err(|e| format!("Failed to resolve project root {}: {}", project_root, e))?;
+    let path = root.join(value);
+
+    let mut existing = path.as_path();
+    while fs::symlink_metadata(existing).is_err() {
+        existing = existing.parent().unwrap_or(&root);
+    }
+    let resolved = existing
+        .canonicalize()
+        .map_err(|e| format!("Failed to resolve {} {}: {}", key, value, e))?;
+    if !resolved.starts_with(&root) {
+        return Err(format!(
+            "{} must be a relative path inside the project: {}",
+            key, value
+        ));
+    }
+    Ok(match path.strip_prefix(existing) {
+        Ok(rest) if !rest.as_os_str().is_empty() => resolved.join(rest),
+        _ => resolved,
+    })
+}
 
 /// Read file as UTF-8 with proper error handling (G.R.8, G.R.9)
 /// Returns content or formatted error message
@@ -87,12 +140,23 @@ pub fn is_file_empty_or_whitespace(content: &str) -> bool {
 /// Get search paths for AGENTS.md (G.REQLIX_GET_I.3)
 #[cfg_attr(test, allow(dead_code))]
 pub fn get_search_paths(project_root: &str) -> Vec<PathBuf> {
+    get_project_search_paths(project_root, &ProjectConfig::default())
+}
+
+/// Get search paths for AGENTS.md including the paths of reqlix.toml (G.REQLIX_GET_I.3, G.SE.3)
+pub fn get_project_search_paths(project_root: &str, config: &ProjectConfig) -> Vec<PathBuf> {
     let root = PathBuf::from(project_root);
     let mut paths = Vec::new();
 
     if let Ok(rel_path) = env::var("REQLIX_REQ_REL_PATH") {
         paths.push(root.join(&rel_path).join("AGENTS.md"));
     }
+    if let Some(rel_path) = &config.requirements.path {
+        paths.push(root.join(rel_path).join("AGENTS.md"));
+    }
+    for rel_path in &config.requirements.search_paths {
+        paths.push(root.join(rel_path).join("AGENTS.md"));
+    }
 
     paths.push(root.join("docs/development/requirements/AGENTS.md"));
     paths.push(root.join("docs/dev/req/AGENTS.md"));
@@ -103,42 +167,118 @@ pub fn get_search_paths(project_root: &str) -> Vec<PathBuf> {
 /// Get path for creating AGENTS.md (G.REQLIX_GET_I.4)
 #[cfg_attr(test, allow(dead_code))]
 pub fn get_create_path(project_root: &str) -> PathBuf {
+    get_project_create_path(project_root, &ProjectConfig::default())
+}
+
+/// Get path for creating AGENTS.md including the path of reqlix.toml (G.REQLIX_GET_I.4, G.SE.3)
+pub fn get_project_create_path(project_root: &str, config: &ProjectConfig) -> PathBuf {
     let root = PathBuf::from(project_root);
 
     if let Ok(rel_path) = env::var("REQLIX_REQ_REL_PATH") {
         root.join(&rel_path).join("AGENTS.md")
+    } else if let Some(rel_path) = &config.requirements.path {
+        root.join(rel_path).join("AGENTS.md")
     } else {
         root.join("docs/development/requirements/AGENTS.md")
     }
 }
 
-/// Find or create requirements file (G.REQLIX_GET_I.3, G.REQLIX_GET_I.4, G.REQLIX_GET_I.5)
-pub fn find_or_create_requirements_file(project_root: &str) -> Result<PathBuf, String> {
-    // Search for existing file
-    for path in get_search_paths(project_root) {
-        if path.exists() {
-            return Ok(path);
+/// Check if a directory is the root of a repository or configured project (G.C.8)
+fn is_project_boundary(dir: &Path) -> bool {
+    dir.join(REPOSITORY_MARKER).exists() || dir.join(CONFIG_FILE).is_file()
+}
+
+/// Directories searched for the requirements file: project_root and its parents up to
+/// the nearest one containing `.git` or reqlix.toml (G.C.8)
+pub fn get_discovery_dirs(project_root: &str) -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+    for dir in Path::new(project_root).ancestors() {
+        if dir.as_os_str().is_empty() {
+            break;
+        }
+        dirs.push(dir.to_path_buf());
+        if is_project_boundary(dir) {
+            break;
         }
     }
+    dirs
+}
+
+/// Find the first requirements file in the directories; reqlix.toml applies to its own directory (G.SE.3)
+fn find_requirements_file_in(dirs: &[PathBuf], config: &ProjectConfig) -> Option<PathBuf> {
+    let default = ProjectConfig::default();
+    dirs.iter().find_map(|dir| {
+        let dir_config = if config.dir.as_ref() == Some(dir) {
+            config
+        } else {
+            &default
+        };
+        get_project_search_paths(&dir.to_string_lossy(), dir_config)
+            .into_iter()
+            .find(|path| path.exists())
+    })
+}
+
+/// Find existing requirements file at or above project_root without creating it (G.C.8)
+pub fn find_requirements_file(project_root: &str) -> Result<Option<PathBuf>, String> {
+    let config = load_project_config(project_root)?;
+    Ok(find_requirements_file_in(
+        &get_discovery_dirs(project_root),
+        &config,
+    ))
+}
+
+/// Find or create requirements file (G.REQLIX_GET_I.3, G.REQLIX_GET_I.4, G.REQLIX_GET_I.5, G.C.8)
+///
+/// With `nested`, only project_root itself is searched and the file is created there;
+/// otherwise it is created in the repository or configured project root above project_root, if any.
+pub fn find_or_create_requirements_file(
+    project_root: &str,
+    nested: bool,
+) -> Result<PathBuf, String> {
+    let config = load_project_config(project_root)?;
+    let dirs = get_discovery_dirs(project_root);
+
+    // Search for existing file
+    let searched = if nested {
+        &dirs[..dirs.len().min(1)]
+    } else {
+        &dirs[..]
+    };
+    if let Some(path) = find_requirements_file_in(searched, &config) {
+        return Ok(path);
+    }
 
     // Create new file with placeholder content
-    let create_path = get_create_path(project_root);
+    let create_root = match dirs.last() {
+        Some(dir) if !nested && is_project_boundary(dir) => dir.clone(),
+        _ => PathBuf::from(project_root),
+    };
+    let create_path = if config.dir.as_ref() == Some(&create_root) {
+        get_project_create_path(&create_root.to_string_lossy(), &config)
+    } else {
+        get_create_path(&create_root.to_string_lossy())
+    };
 
     // Create parent directories (G.C.2)
     if let Some(parent) = create_path.parent() {
         fs::create_dir_all(parent).map_err(|e| format!("Failed to create directories: {}", e))?;
     }
 
-    // Replace {requirements_directory} placeholder (G.REQLIX_GET_I.6)
-    let requirements_dir = create_path
-        .parent()
-        .and_then(|p| {
-            p.strip_prefix(project_root)
-                .ok()
-                .map(|rel| rel.to_string_lossy().to_string())
-        })
-        .unwrap_or_default();
-    let content = PLACEHOLDER_CONTENT.replace("{requirements_directory}", &requirements_dir);
+    // Placeholders are kept and rendered at call time (G.REQLIX_GET_I.6, G.I.1)
+    let content = match (&config.dir, &config.instructions.template) {
+        (Some(dir), Some(template)) if dir == &create_root => {
+            let template_path = dir.join(template);
+            fs::read_to_string(&template_path).map_err(|e| {
+                format!(
+                    "Failed to read instructions template {}: {}",
+                    template_path.display(),
+                    e
+                )
+            })?
+        }
+        _ => PLACEHOLDER_CONTENT.to_string(),
+    };
 
     write_file_utf8(&create_path, &content)
         .map_err(|e| format!("Failed to create requirements file: {}", e))?;
@@ -146,9 +286,16 @@ pub fn find_or_create_requirements_file(project_root: &str) -> Result<PathBuf, S
     Ok(create_path)
 }
 
-/// Get requirements directory (G.C.1)
+/// Requirements directory of an existing project, without creating AGENTS.md (G.M.4, G.S.3)
+pub fn existing_requirements_dir(project_root: &str) -> Result<PathBuf, String> {
+    find_requirements_file(project_root)?
+        .and_then(|path| path.parent().map(Path::to_path_buf))
+        .ok_or_else(|| "Requirements directory not found".to_string())
+}
+
+/// Get requirements directory (G.C.1, G.C.8)
 pub fn get_requirements_dir(project_root: &str) -> Result<PathBuf, String> {
-    let agents_path = find_or_create_requirements_file(project_root)?;
+    let agents_path = find_or_create_requirements_file(project_root, false)?;
     agents_path
         .parent()
         .map(|p| p.to_path_buf())
@@ -0,0 +1,241 @@
+// Cross-reference graph between requirements (G.CR.1, G.CR.2, G.CR.3, G.RELA.2)
+
+use crate::cache::load_category;
+use crate::helpers::list_categories;
+use crate::models::{ImpactedRequirement, RequirementLocation};
+use crate::relations::parse_relations;
+use crate::trace::index_reference_matches;
+use regex::Regex;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+/// GitHub heading slug: lowercase, keep letters, digits, spaces, hyphens and underscores,
+/// then replace spaces with hyphens (G.CR.2)
+#[cfg_attr(test, allow(dead_code))]
+pub fn github_slug(heading: &str) -> String {
+    heading
+        .to_lowercase()
+        .chars()
+        .filter(|c| c.is_alphanumeric() || *c == ' ' || *c == '-' || *c == '_')
+        .map(|c| if c == ' ' { '-' } else { c })
+        .collect()
+}
+
+/// Anchor of a requirement heading `{index}: {title}` (G.CR.2)
+#[cfg_attr(test, allow(dead_code))]
+pub fn requirement_anchor(index: &str, title: &str) -> String {
+    github_slug(&format!("{}: {}", index, title))
+}
+
+/// Markdown inline link to an anchor: `](#anchor)` (G.CR.1)
+fn anchor_link_pattern() -> &'static Regex {
+    static PATTERN: OnceLock<Regex> = OnceLock::new();
+    PATTERN.get_or_init(|| Regex::new(r"\]\(#([^)\s]+)\)").expect("valid anchor link pattern"))
+}
+
+/// Anchors of all inline anchor links in a line with their byte offsets, in order of appearance (G.CR.1)
+fn anchor_link_matches(line: &str) -> Vec<(usize, &str)> {
+    anchor_link_pattern()
+        .captures_iter(line)
+        .filter_map(|c| c.get(1))
+        .map(|m| (m.start(), m.as_str()))
+        .collect()
+}
+
+/// Find all anchors of inline anchor links in a line, in order of appearance (G.CR.1)
+#[cfg_attr(test, allow(dead_code))]
+pub fn find_anchor_links(line: &str) -> Vec<String> {
+    anchor_link_matches(line)
+        .into_iter()
+        .map(|(_, anchor)| anchor.to_string())
+        .collect()
+}
+
+/// Requirements and the links between them (G.CR.3)
+#[derive(Debug, Clone, Default)]
+pub struct ReferenceGraph {
+    requirements: Vec<RequirementLocation>,
+    positions: HashMap<String, usize>,
+    outgoing: Vec<Vec<usize>>,
+    relations: Vec<Vec<(&'static str, usize)>>,
+}
+
+impl ReferenceGraph {
+    /// Build the graph from all category files through the cache (G.CR.3, G.CA.5)
+    pub fn build(requirements_dir: &PathBuf) -> Result<Self, String> {
+        // Collect requirements with their texts, first occurrence of each index wins
+        let mut graph = ReferenceGraph::default();
+        let mut texts: Vec<String> = Vec::new();
+        for category in list_categories(requirements_dir)? {
+            let parsed = load_category(requirements_dir, &category)?;
+            for entry in parsed.search_index {
+                let requirement = entry.requirement;
+                if graph.positions.contains_key(&requirement.index) {
+                    continue;
+                }
+                graph
+                    .positions
+                    .insert(requirement.index.clone(), graph.requirements.len());
+                texts.push(requirement.text);
+                graph.requirements.push(RequirementLocation {
+                    index: requirement.index,
+                    title: requirement.title,
+                    category: requirement.category,
+                    chapter: requirement.chapter,
+                });
+            }
+        }
+
+        // G.CR.2: Anchors of all requirements
+        let anchors: HashMap<String, usize> = graph
+            .requirements
+            .iter()
+            .enumerate()
+            .map(|(i, r)| (requirement_anchor(&r.index, &r.title), i))
+            .collect();
+
+        // G.CR.1: Links outside fenced code blocks, without self links and unknown targets
+        for (source, text) in texts.iter().enumerate() {
+            let mut targets: Vec<usize> = Vec::new();
+            let mut in_code_block = false;
+            for line in text.lines() {
+                if line.trim_start().starts_with("```") {
+                    in_code_block = !in_code_block;
+                    continue;
+                }
+                if in_code_block {
+                    continue;
+                }
+                let by_index = index_reference_matches(line)
+                    .into_iter()
+                    .filter_map(|(offset, index)| graph.positions.get(index).map(|&t| (offset, t)));
+                let by_anchor = anchor_link_matches(line)
+                    .into_iter()
+                    .filter_map(|(offset, anchor)| anchors.get(anchor).map(|&t| (offset, t)));
+                let mut found: Vec<(usize, usize)> = by_index.chain(by_anchor).collect();
+                found.sort_by_key(|&(offset, _)| offset);
+                for (_, target) in found {
+                    if target != source && !targets.contains(&target) {
+                        targets.push(target);
+                    }
+                }
+            }
+            graph.outgoing.push(targets);
+
+            // G.RELA.2: Typed relations, without self relations and unknown targets
+            let mut relations: Vec<(&'static str, usize)> = Vec::new();
+            for (relation, index) in parse_relations(text) {
+                if let Some(&target) = graph.positions.get(&index) {
+                    if target != source && !relations.contains(&(relation, target)) {
+                        relations.push((relation, target));
+                    }
+                }
+            }
+            graph.relations.push(relations);
+        }
+
+        Ok(graph)
+    }
+
+    /// All requirements in requirement order (G.CR.3)
+    pub fn requirements(&self) -> &[RequirementLocation] {
+        &self.requirements
+    }
+
+    /// Position of a requirement in requirement order
+    pub(crate) fn position(&self, index: &str) -> Option<usize> {
+        self.positions.get(index).copied()
+    }
+
+    /// Outgoing link targets of the requirement at a position (G.CR.3)
+    pub(crate) fn link_targets(&self, position: usize) -> &[usize] {
+        &self.outgoing[position]
+    }
+
+    /// Typed relations of the requirement at a position, in stored order (G.RELA.2)
+    pub(crate) fn relation_targets(&self, position: usize) -> &[(&'static str, usize)] {
+        &self.relations[position]
+    }
+
+    /// Requirement with the given index
+    pub fn requirement(&self, index: &str) -> Option<&RequirementLocation> {
+        self.positions.get(index).map(|&i| &self.requirements[i])
+    }
+
+    /// Requirements linked from the given requirement, in order of first link (G.CR.3)
+    pub fn outgoing(&self, index: &str) -> Vec<&RequirementLocation> {
+        self.positions
+            .get(index)
+            .map(|&i| {
+                self.outgoing[i]
+                    .iter()
+                    .map(|&t| &self.requirements[t])
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Requirements that link to the given requirement, in requirement order (G.CR.3)
+    pub fn backlinks(&self, index: &str) -> Vec<&RequirementLocation> {
+        let Some(&target) = self.positions.get(index) else {
+            return Vec::new();
+        };
+        self.outgoing
+            .iter()
+            .enumerate()
+            .filter(|(_, targets)| targets.contains(&target))
+            .map(|(source, _)| &self.requirements[source])
+            .collect()
+    }
+
+    /// Requirements affected by a change to the given requirement, breadth-first (G.TOOLREQLIXIM.3 step 4)
+    pub fn impact(&self, index: &str) -> Vec<ImpactedRequirement> {
+        let Some(&start) = self.positions.get(index) else {
+            return Vec::new();
+        };
+        let mut reached = vec![false; self.requirements.len()];
+        reached[start] = true;
+        let mut frontier = vec![start];
+        let mut affected = Vec::new();
+        let mut depth = 0;
+        while !frontier.is_empty() {
+            depth += 1;
+            let mut next: Vec<(usize, &'static str, usize)> = Vec::new();
+            for (candidate, relations) in self.relations.iter().enumerate() {
+                if reached[candidate] {
+                    continue;
+                }
+                // G.RELA.1: A change to B affects A for every relation A -> B,
+                // and affects B for A conflicts_with B
+                let found = relations
+                    .iter()
+                    .find(|(_, target)| frontier.contains(target))
+                    .map(|&(relation, target)| (relation, target))
+                    .or_else(|| {
+                        frontier
+                            .iter()
+                            .find(|&&source| {
+                                self.relations[source].contains(&("conflicts_with", candidate))
+                            })
+                            .map(|&source| ("conflicts_with", source))
+                    });
+                if let Some((relation, via)) = found {
+                    next.push((candidate, relation, via));
+                }
+            }
+            frontier = Vec::with_capacity(next.len());
+            for (candidate, relation, via) in next {
+                reached[candidate] = true;
+                frontier.push(candidate);
+                affected.push(ImpactedRequirement {
+                    requirement: self.requirements[candidate].clone(),
+                    relation: relation.to_string(),
+                    via: self.requirements[via].index.clone(),
+                    depth,
+                });
+            }
+        }
+        affected
+    }
+}
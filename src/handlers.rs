@@ -1,26 +1,59 @@
 // Tool handlers
 
-use crate::constants::MAX_BATCH_SIZE;
+use crate::cache::load_category;
+use crate::config::{load_project_config, ProjectConfig};
+use crate::constants::{
+    CHAPTER_NAME_SIMILARITY_THRESHOLD, COVERAGE_FORMATS, DEFAULT_GRAPH_RADIUS,
+    DEFAULT_SIMILARITY_THRESHOLD, DEFAULT_SUGGESTION_LIMIT, GRAPH_FORMATS, MATRIX_FORMATS,
+    TEST_RESULT_FORMATS,
+};
+use crate::coverage::{
+    coverage_report, render_coverage_csv, render_coverage_markdown, CoverageOptions,
+};
+use crate::dangling::find_dangling_references;
+use crate::diagram::{render_dot, render_mermaid, Diagram};
 use crate::filesystem::{
-    find_or_create_requirements_file, get_requirements_dir, read_file_utf8, write_file_utf8,
+    existing_requirements_dir, find_or_create_requirements_file, get_requirements_dir,
+    read_file_utf8, resolve_project_path, write_file_utf8,
 };
+use crate::graph::{requirement_anchor, ReferenceGraph};
 use crate::helpers::{
     find_category_by_prefix, get_next_requirement_number, get_or_calculate_category_prefix,
     get_or_calculate_chapter_prefix, list_categories, title_exists_in_chapter,
 };
-use crate::models::{DeletedRequirement, RequirementFull};
+use crate::instructions::render_instructions;
+use crate::links::{find_broken_links, plan_anchor_rewrites};
+use crate::matrix::{
+    render_matrix_csv, render_matrix_html, render_matrix_markdown, traceability_matrix,
+};
+use crate::models::{
+    CategoryList, ChapterList, ChapterRequirements, DeletedRequirement, DuplicateIndex,
+    DuplicateReport, DuplicateTitle, GraphExport, ImpactReport, InsertedRequirement, Instructions,
+    LocationSuggestion, LocationSuggestions, MatrixExport, NearDuplicate, RenderedCoverage,
+    RequirementFull, RequirementLocation, RequirementReferences, SearchResults, SimilarRequirement,
+    ToolResponse, UpdatedRequirement, VersionInfo,
+};
 use crate::params::*;
 use crate::parsing::{
     find_requirement_streaming, parse_index, parse_level1_heading, parse_level2_heading,
-    read_chapters_streaming, read_requirements_streaming,
+    read_chapters_streaming,
 };
+use crate::relations::{apply_relations, validate_relation_targets};
 use crate::response::{json_error, json_success};
+use crate::rewrite::rewrite_references;
+use crate::similarity::{name_similarity, round_score, TfIdfIndex};
+use crate::testmap::map_tests;
+use crate::trace::{scan_references, trace_requirements, TraceOptions};
 use crate::validation::{
-    validate_category, validate_chapter, validate_index, validate_keywords,
-    validate_operation_description, validate_project_root, validate_text, validate_title,
+    validate_category_in, validate_chapter_in, validate_file, validate_format, validate_globs_in,
+    validate_index, validate_keywords_in, validate_limit, validate_mappings_in,
+    validate_operation_description, validate_output, validate_project_root, validate_radius,
+    validate_relations_in, validate_text_in, validate_threshold, validate_title_in,
 };
-use serde_json::json;
+use crate::verification::import_test_results;
+use std::collections::HashMap;
 use std::fs;
+use std::path::Path;
 
 /// Validate common parameters (project_root and operation_description)
 /// Returns error string if validation fails, None otherwise
@@ -40,15 +73,15 @@ pub fn handle_get_instructions(params: GetInstructionsParams) -> String {
         return json_error(&e);
     }
 
-    // Find or create AGENTS.md
-    let agents_path = match find_or_create_requirements_file(&params.project_root) {
-        Ok(p) => p,
+    let config = match load_project_config(&params.project_root) {
+        Ok(c) => c,
         Err(e) => return json_error(&e),
     };
 
-    // Read AGENTS.md content
-    let mut content = match read_file_utf8(&agents_path) {
-        Ok(c) => c,
+    // Find or create AGENTS.md (G.C.8)
+    let nested = params.nested.unwrap_or(false);
+    let agents_path = match find_or_create_requirements_file(&params.project_root, nested) {
+        Ok(p) => p,
         Err(e) => return json_error(&e),
     };
 
@@ -58,27 +91,15 @@ pub fn handle_get_instructions(params: GetInstructionsParams) -> String {
         None => return json_error("Could not determine requirements directory"),
     };
 
-    // Generate Categories chapter (G.REQLIX_GET_I.7)
-    let categories = match list_categories(&requirements_dir) {
+    // Render AGENTS.md with the Categories chapter (G.REQLIX_GET_I.7, G.I.1)
+    let content = match render_instructions(&params.project_root, &requirements_dir, &config, true)
+    {
         Ok(c) => c,
         Err(e) => return json_error(&e),
     };
 
-    let categories_chapter = if categories.is_empty() {
-        "\n# Categories\n\nNo categories defined yet.\n".to_string()
-    } else {
-        let list = categories
-            .iter()
-            .map(|c| format!("- {}", c))
-            .collect::<Vec<_>>()
-            .join("\n");
-        format!("\n# Categories\n\n{}\n", list)
-    };
-
-    content.push_str(&categories_chapter);
-
     // Return JSON response (G.REQLIX_GET_I.8)
-    json_success(json!({ "content": content }))
+    json_success(Instructions { content })
 }
 
 /// reqlix_get_categories (G.REQLIX_GET_CA)
@@ -88,7 +109,7 @@ pub fn handle_get_categories(params: GetCategoriesParams) -> String {
     }
 
     // Get requirements directory
-    let requirements_dir = match get_requirements_dir(&params.project_root) {
+    let requirements_dir = match existing_requirements_dir(&params.project_root) {
         Ok(d) => d,
         Err(e) => return json_error(&e),
     };
@@ -100,7 +121,7 @@ pub fn handle_get_categories(params: GetCategoriesParams) -> String {
     };
 
     // Return JSON response (G.REQLIX_GET_CA.3)
-    json_success(json!({ "categories": categories }))
+    json_success(CategoryList { categories })
 }
 
 /// reqlix_get_chapters (G.REQLIX_GET_CH)
@@ -108,12 +129,16 @@ pub fn handle_get_chapters(params: GetChaptersParams) -> String {
     if let Some(e) = validate_common_params(&params.project_root, &params.operation_description) {
         return json_error(&e);
     }
-    if let Err(e) = validate_category(&params.category) {
+    let config = match load_project_config(&params.project_root) {
+        Ok(c) => c,
+        Err(e) => return json_error(&e),
+    };
+    if let Err(e) = validate_category_in(&params.category, &config) {
         return json_error(&e);
     }
 
     // Get requirements directory
-    let requirements_dir = match get_requirements_dir(&params.project_root) {
+    let requirements_dir = match existing_requirements_dir(&params.project_root) {
         Ok(d) => d,
         Err(e) => return json_error(&e),
     };
@@ -124,17 +149,17 @@ pub fn handle_get_chapters(params: GetChaptersParams) -> String {
         return json_error("Category not found");
     }
 
-    // Read chapters (G.REQLIX_GET_CH.3)
-    let chapters = match read_chapters_streaming(&category_path) {
-        Ok(c) => c,
+    // Read chapters through the cache (G.REQLIX_GET_CH.3, G.CA.5)
+    let parsed = match load_category(&requirements_dir, &params.category) {
+        Ok(p) => p,
         Err(e) => return json_error(&e),
     };
 
     // Return JSON response (G.REQLIX_GET_CH.4)
-    json_success(json!({
-        "category": params.category,
-        "chapters": chapters
-    }))
+    json_success(ChapterList {
+        category: params.category,
+        chapters: parsed.chapter_names(),
+    })
 }
 
 /// reqlix_get_requirements (G.REQLIX_GET_REQUIREMENTS)
@@ -142,15 +167,19 @@ pub fn handle_get_requirements(params: GetRequirementsParams) -> String {
     if let Some(e) = validate_common_params(&params.project_root, &params.operation_description) {
         return json_error(&e);
     }
-    if let Err(e) = validate_category(&params.category) {
+    let config = match load_project_config(&params.project_root) {
+        Ok(c) => c,
+        Err(e) => return json_error(&e),
+    };
+    if let Err(e) = validate_category_in(&params.category, &config) {
         return json_error(&e);
     }
-    if let Err(e) = validate_chapter(&params.chapter) {
+    if let Err(e) = validate_chapter_in(&params.chapter, &config) {
         return json_error(&e);
     }
 
     // Get requirements directory
-    let requirements_dir = match get_requirements_dir(&params.project_root) {
+    let requirements_dir = match existing_requirements_dir(&params.project_root) {
         Ok(d) => d,
         Err(e) => return json_error(&e),
     };
@@ -161,31 +190,32 @@ pub fn handle_get_requirements(params: GetRequirementsParams) -> String {
         return json_error("Category not found");
     }
 
-    // Check if chapter exists
-    let chapters = match read_chapters_streaming(&category_path) {
-        Ok(c) => c,
+    // Read category through the cache (G.CA.5)
+    let parsed = match load_category(&requirements_dir, &params.category) {
+        Ok(p) => p,
         Err(e) => return json_error(&e),
     };
-    if !chapters.contains(&params.chapter) {
-        return json_error("Chapter not found");
-    }
 
-    // Read requirements (G.REQLIX_GET_REQUIREMENTS.3)
-    let requirements = match read_requirements_streaming(&category_path, &params.chapter) {
-        Ok(r) => r,
-        Err(e) => return json_error(&e),
+    // Check if chapter exists and read requirements (G.REQLIX_GET_REQUIREMENTS.3)
+    let requirements = match parsed.chapter_requirements(&params.chapter) {
+        Some(r) => r,
+        None => return json_error("Chapter not found"),
     };
 
     // Return JSON response (G.REQLIX_GET_REQUIREMENTS.4)
-    json_success(json!({
-        "category": params.category,
-        "chapter": params.chapter,
-        "requirements": requirements
-    }))
+    json_success(ChapterRequirements {
+        category: params.category,
+        chapter: params.chapter,
+        requirements: requirements.clone(),
+    })
 }
 
 /// Helper to get a single requirement by index (G.REQLIX_GET_REQUIREMENT.3)
-fn get_single_requirement(project_root: &str, index: &str) -> Result<RequirementFull, String> {
+fn get_single_requirement(
+    project_root: &str,
+    index: &str,
+    config: &ProjectConfig,
+) -> Result<RequirementFull, String> {
     // Validate index
     validate_index(index)?;
 
@@ -193,10 +223,11 @@ fn get_single_requirement(project_root: &str, index: &str) -> Result<Requirement
     let (category_prefix, _chapter_prefix, _number) = parse_index(index)?;
 
     // Get requirements directory
-    let requirements_dir = get_requirements_dir(project_root)?;
+    let requirements_dir = existing_requirements_dir(project_root)?;
 
     // Find category by prefix (G.C.7)
-    let category_name = find_category_by_prefix(&requirements_dir, &category_prefix)?;
+    let category_name =
+        find_category_by_prefix(&requirements_dir, &category_prefix, &config.index)?;
 
     let category_path = requirements_dir.join(format!("{}.md", category_name));
 
@@ -210,37 +241,48 @@ pub fn handle_get_requirement(params: GetRequirementParams) -> String {
     if let Some(e) = validate_common_params(&params.project_root, &params.operation_description) {
         return json_error(&e);
     }
+    let config = match load_project_config(&params.project_root) {
+        Ok(c) => c,
+        Err(e) => return json_error(&e),
+    };
 
     match params.index {
         // Single index (G.REQLIX_GET_REQUIREMENT.3 - single)
-        IndexParam::Single(index) => match get_single_requirement(&params.project_root, &index) {
-            Ok(requirement) => json_success(requirement),
-            Err(e) => json_error(&e),
-        },
+        IndexParam::Single(index) => {
+            match get_single_requirement(&params.project_root, &index, &config) {
+                Ok(requirement) => json_success(requirement),
+                Err(e) => json_error(&e),
+            }
+        }
         // Batch request (G.REQLIX_GET_REQUIREMENT.3 - batch)
         IndexParam::Batch(indices) => {
             // G.P.4: Empty array returns empty result
             if indices.is_empty() {
-                return json_success(json!([]));
+                return json_success(Vec::<ToolResponse<UpdatedRequirement>>::new());
             }
 
             // G.REQLIX_GET_REQUIREMENT.5: Validate batch size
-            if indices.len() > MAX_BATCH_SIZE {
-                return json_error("Batch request exceeds maximum limit of 100 indices");
+            if indices.len() > config.limits.max_batch_size {
+                return json_error(&format!(
+                    "Batch request exceeds maximum limit of {} indices",
+                    config.limits.max_batch_size
+                ));
             }
 
             // Process ALL indices, return success/error for each (G.REQLIX_GET_REQUIREMENT.3, G.REQLIX_GET_REQUIREMENT.4)
             let mut results = Vec::with_capacity(indices.len());
             for index in &indices {
-                match get_single_requirement(&params.project_root, index) {
-                    Ok(requirement) => results.push(json!({
-                        "success": true,
-                        "data": requirement
-                    })),
-                    Err(e) => results.push(json!({
-                        "success": false,
-                        "error": e
-                    })),
+                match get_single_requirement(&params.project_root, index, &config) {
+                    Ok(requirement) => results.push(ToolResponse {
+                        success: true,
+                        data: Some(requirement),
+                        error: None,
+                    }),
+                    Err(e) => results.push(ToolResponse {
+                        success: false,
+                        data: None,
+                        error: Some(e),
+                    }),
                 }
             }
 
@@ -250,6 +292,31 @@ pub fn handle_get_requirement(params: GetRequirementParams) -> String {
     }
 }
 
+/// Warnings for existing chapters whose names are close to a new chapter name (G.REQLIX_I.7)
+fn similar_chapter_warnings(
+    requirements_dir: &std::path::PathBuf,
+    chapter: &str,
+) -> Result<Vec<String>, String> {
+    let mut warnings = Vec::new();
+    for category in list_categories(requirements_dir)? {
+        let parsed = load_category(requirements_dir, &category)?;
+        let mut seen_chapters: Vec<&str> = Vec::new();
+        for existing in &parsed.chapters {
+            if existing.name == chapter || seen_chapters.contains(&existing.name.as_str()) {
+                continue;
+            }
+            seen_chapters.push(&existing.name);
+            if name_similarity(chapter, &existing.name) >= CHAPTER_NAME_SIMILARITY_THRESHOLD {
+                warnings.push(format!(
+                    "Chapter \"{}\" is similar to existing chapter \"{}\" in category \"{}\"",
+                    chapter, existing.name, category
+                ));
+            }
+        }
+    }
+    Ok(warnings)
+}
+
 /// reqlix_insert_requirement (G.REQLIX_I)
 /// Title must be generated by the LLM and provided as parameter. Must be unique within chapter (G.REQLIX_I.3).
 pub fn handle_insert_requirement(params: InsertRequirementParams) -> String {
@@ -257,18 +324,27 @@ pub fn handle_insert_requirement(params: InsertRequirementParams) -> String {
     if let Some(e) = validate_common_params(&params.project_root, &params.operation_description) {
         return json_error(&e);
     }
-    if let Err(e) = validate_category(&params.category) {
+    let config = match load_project_config(&params.project_root) {
+        Ok(c) => c,
+        Err(e) => return json_error(&e),
+    };
+    if let Err(e) = validate_category_in(&params.category, &config) {
         return json_error(&e);
     }
-    if let Err(e) = validate_chapter(&params.chapter) {
+    if let Err(e) = validate_chapter_in(&params.chapter, &config) {
         return json_error(&e);
     }
-    if let Err(e) = validate_text(&params.text) {
+    if let Err(e) = validate_text_in(&params.text, &config) {
         return json_error(&e);
     }
-    if let Err(e) = validate_title(&params.title, true) {
+    if let Err(e) = validate_title_in(&params.title, true, &config) {
         return json_error(&e);
     }
+    if let Some(relations) = &params.relations {
+        if let Err(e) = validate_relations_in(relations, &config) {
+            return json_error(&e);
+        }
+    }
 
     // Get requirements directory
     let requirements_dir = match get_requirements_dir(&params.project_root) {
@@ -276,6 +352,15 @@ pub fn handle_insert_requirement(params: InsertRequirementParams) -> String {
         Err(e) => return json_error(&e),
     };
 
+    // Build the final text and validate its relations (G.RELA.3, G.RELA.4)
+    let text = match &params.relations {
+        Some(relations) => apply_relations(&params.text, relations),
+        None => params.text.clone(),
+    };
+    if let Err(e) = validate_relation_targets(&requirements_dir, &text, None, None) {
+        return json_error(&e);
+    }
+
     let category_path = requirements_dir.join(format!("{}.md", params.category));
 
     // Step 1: Find or create category (G.REQLIX_I.3 step 1, G.R.10)
@@ -292,7 +377,16 @@ pub fn handle_insert_requirement(params: InsertRequirementParams) -> String {
         Err(e) => return json_error(&e),
     };
 
+    let mut warnings = Vec::new();
     if !chapters.contains(&params.chapter) {
+        // Check new chapter name against existing chapters (G.REQLIX_I.7)
+        if params.warn_similar_chapter.unwrap_or(true) {
+            warnings = match similar_chapter_warnings(&requirements_dir, &params.chapter) {
+                Ok(w) => w,
+                Err(e) => return json_error(&e),
+            };
+        }
+
         // Append chapter heading
         let mut content = match read_file_utf8(&category_path) {
             Ok(c) => c,
@@ -322,11 +416,15 @@ pub fn handle_insert_requirement(params: InsertRequirementParams) -> String {
         Err(e) => return json_error(&e),
     };
 
-    let category_prefix =
-        match get_or_calculate_category_prefix(&category_path, &params.category, &all_categories) {
-            Ok(p) => p,
-            Err(e) => return json_error(&e),
-        };
+    let category_prefix = match get_or_calculate_category_prefix(
+        &category_path,
+        &params.category,
+        &all_categories,
+        &config.index,
+    ) {
+        Ok(p) => p,
+        Err(e) => return json_error(&e),
+    };
 
     let chapter_prefix = match get_or_calculate_chapter_prefix(&category_path, &params.chapter) {
         Ok(p) => p,
@@ -368,7 +466,7 @@ pub fn handle_insert_requirement(params: InsertRequirementParams) -> String {
             .map(|p| after_chapter + p)
             .unwrap_or(content.len());
 
-        let requirement_text = format!("\n## {}: {}\n\n{}\n", index, params.title, params.text);
+        let requirement_text = format!("\n## {}: {}\n\n{}\n", index, params.title, text);
         content.insert_str(insert_pos, &requirement_text);
     } else {
         return json_error("Chapter not found after creation");
@@ -379,12 +477,15 @@ pub fn handle_insert_requirement(params: InsertRequirementParams) -> String {
     }
 
     // Step 6: Return result (G.REQLIX_I.3 step 6, G.REQLIX_I.5)
-    json_success(RequirementFull {
-        index,
-        title: params.title,
-        text: params.text,
-        category: params.category,
-        chapter: params.chapter,
+    json_success(InsertedRequirement {
+        requirement: RequirementFull {
+            index,
+            title: params.title,
+            text,
+            category: params.category,
+            chapter: params.chapter,
+        },
+        warnings,
     })
 }
 
@@ -394,12 +495,18 @@ fn update_single_requirement(
     index: &str,
     text: &str,
     title: Option<&str>,
-) -> Result<RequirementFull, String> {
+    relations: Option<&RelationsParam>,
+    rewrite_links: bool,
+    config: &ProjectConfig,
+) -> Result<UpdatedRequirement, String> {
     // Step 1: Validate parameters (G.REQLIX_U.6, G.REQLIX_U.3 step 1)
     validate_index(index)?;
-    validate_text(text)?;
+    validate_text_in(text, config)?;
     if let Some(t) = title {
-        validate_title(t, false)?;
+        validate_title_in(t, false, config)?;
+    }
+    if let Some(r) = relations {
+        validate_relations_in(r, config)?;
     }
 
     // Step 2: Parse index (G.REQLIX_U.3 step 2)
@@ -409,7 +516,8 @@ fn update_single_requirement(
     let requirements_dir = get_requirements_dir(project_root)?;
 
     // Find category by prefix
-    let category_name = find_category_by_prefix(&requirements_dir, &category_prefix)?;
+    let category_name =
+        find_category_by_prefix(&requirements_dir, &category_prefix, &config.index)?;
     let category_path = requirements_dir.join(format!("{}.md", category_name));
 
     // Step 3: Find requirement (G.REQLIX_U.3 step 3)
@@ -421,6 +529,13 @@ fn update_single_requirement(
         .map(|t| t.to_string())
         .unwrap_or(existing.title.clone());
 
+    // Build the final text and validate the relations it adds or changes (G.RELA.3, G.RELA.4)
+    let text = match relations {
+        Some(r) => apply_relations(text, r),
+        None => text.to_string(),
+    };
+    validate_relation_targets(&requirements_dir, &text, Some(index), Some(&existing.text))?;
+
     // Step 5: Validate title uniqueness (G.REQLIX_U.3 step 5)
     if title_provided
         && title_exists_in_chapter(&category_path, &existing.chapter, &new_title, Some(index))?
@@ -433,6 +548,7 @@ fn update_single_requirement(
         .map_err(|e| format!("Failed to read category file: {}", e))?;
 
     // Find and replace the requirement using line-by-line parsing (G.R.5, G.R.3)
+    let mut warnings = Vec::new();
     let new_heading = format!("## {}: {}", index, new_title);
     let lines: Vec<&str> = content.lines().collect();
 
@@ -474,7 +590,7 @@ fn update_single_requirement(
         new_content.push_str(&content[..start]);
         new_content.push_str(&new_heading);
         new_content.push_str("\n\n");
-        new_content.push_str(text);
+        new_content.push_str(&text);
         // G.R.11: Ensure blank line before next heading
         let remaining = &content[end..];
         if remaining.starts_with('#') || remaining.starts_with("\n#") {
@@ -484,19 +600,59 @@ fn update_single_requirement(
         }
         new_content.push_str(remaining);
 
+        // G.CR.5: Plan link rewrites before writing anything, so a failure
+        // leaves the requirement unchanged
+        let old_anchor = requirement_anchor(index, &existing.title);
+        let new_anchor = requirement_anchor(index, &new_title);
+        let mut others = Vec::new();
+        if rewrite_links && old_anchor != new_anchor {
+            let plan = plan_anchor_rewrites(
+                &requirements_dir,
+                &format!("{}.md", category_name),
+                &new_content,
+                &old_anchor,
+                &new_anchor,
+            )?;
+            let (category, rest): (Vec<_>, Vec<_>) = plan
+                .into_iter()
+                .partition(|(path, _)| *path == category_path);
+            others = rest;
+            if let Some((_, content)) = category.into_iter().next() {
+                new_content = content;
+            }
+        }
+
+        // The category file first, so links never point at an anchor that
+        // does not exist yet
         fs::write(&category_path, &new_content)
             .map_err(|e| format!("Failed to write category file: {}", e))?;
+
+        // The requirement is saved; a linking file that cannot be written is
+        // reported and left for reqlix_check_links
+        for (path, content) in others {
+            if let Err(e) = write_file_utf8(&path, &content) {
+                let file = path.strip_prefix(&requirements_dir).unwrap_or(&path);
+                warnings.push(format!(
+                    "Links in {} were not rewritten: {}",
+                    file.to_string_lossy().replace('\\', "/"),
+                    e
+                ));
+            }
+        }
     } else {
         return Err("Could not find requirement to update".to_string());
     }
 
     // Step 7: Return result (G.REQLIX_U.3 step 7)
-    Ok(RequirementFull {
-        index: index.to_string(),
-        title: new_title,
-        text: text.to_string(),
-        category: category_name,
-        chapter: existing.chapter,
+    Ok(UpdatedRequirement {
+        requirement: RequirementFull {
+            index: index.to_string(),
+            title: new_title,
+            text,
+            category: category_name,
+            chapter: existing.chapter,
+        },
+        warnings,
     })
 }
 
@@ -506,6 +662,10 @@ pub fn handle_update_requirement(params: UpdateRequirementParams) -> String {
     if let Some(e) = validate_common_params(&params.project_root, &params.operation_description) {
         return json_error(&e);
     }
+    let config = match load_project_config(&params.project_root) {
+        Ok(c) => c,
+        Err(e) => return json_error(&e),
+    };
 
     // Determine mode: single or batch (G.REQLIX_U.2)
     match (&params.index, &params.items) {
@@ -520,6 +680,9 @@ pub fn handle_update_requirement(params: UpdateRequirementParams) -> String {
                 index,
                 text,
                 params.title.as_deref(),
+                params.relations.as_ref(),
+                params.rewrite_links.unwrap_or(false),
+                &config,
             ) {
                 Ok(result) => json_success(result),
                 Err(e) => json_error(&e),
@@ -529,12 +692,15 @@ pub fn handle_update_requirement(params: UpdateRequirementParams) -> String {
         (None, Some(items)) => {
             // G.P.4: Empty array returns empty result
             if items.is_empty() {
-                return json_success(json!([]));
+                return json_success(Vec::<ToolResponse<UpdatedRequirement>>::new());
             }
 
             // G.REQLIX_U.7: Validate batch size
-            if items.len() > MAX_BATCH_SIZE {
-                return json_error("Batch update exceeds maximum limit of 100 items");
+            if items.len() > config.limits.max_batch_size {
+                return json_error(&format!(
+                    "Batch update exceeds maximum limit of {} items",
+                    config.limits.max_batch_size
+                ));
             }
 
             // Process ALL items, return success/error for each (G.REQLIX_U.3, G.REQLIX_U.4)
@@ -545,15 +711,20 @@ pub fn handle_update_requirement(params: UpdateRequirementParams) -> String {
                     &item.index,
                     &item.text,
                     item.title.as_deref(),
+                    item.relations.as_ref(),
+                    params.rewrite_links.unwrap_or(false),
+                    &config,
                 ) {
-                    Ok(result) => results.push(json!({
-                        "success": true,
-                        "data": result
-                    })),
-                    Err(e) => results.push(json!({
-                        "success": false,
-                        "error": e
-                    })),
+                    Ok(result) => results.push(ToolResponse {
+                        success: true,
+                        data: Some(result),
+                        error: None,
+                    }),
+                    Err(e) => results.push(ToolResponse {
+                        success: false,
+                        data: None,
+                        error: Some(e),
+                    }),
                 }
             }
 
@@ -578,15 +749,18 @@ pub fn handle_get_version(_params: GetVersionParams) -> String {
     let version = env!("CARGO_PKG_VERSION");
 
     // G.TOOLREQLIXGETV.2: Return success response
-    json_success(json!({
-        "version": version
-    }))
+    json_success(VersionInfo {
+        version: version.to_string(),
+    })
 }
 
 /// Helper to delete a single requirement (G.TOOLREQLIXD.3 steps 1-6)
 fn delete_single_requirement(
     project_root: &str,
     index: &str,
+    force: bool,
+    batch: &[String],
+    config: &ProjectConfig,
 ) -> Result<DeletedRequirement, String> {
     // Step 1: Validate index (G.TOOLREQLIXD.5)
     validate_index(index)?;
@@ -596,17 +770,32 @@ fn delete_single_requirement(
 
     // Find category by prefix (G.C.7)
     let req_dir = get_requirements_dir(project_root)?;
-    let category = find_category_by_prefix(&req_dir, &category_prefix)?;
+    let category = find_category_by_prefix(&req_dir, &category_prefix, &config.index)?;
     let category_path = req_dir.join(format!("{}.md", category));
 
     // Step 3: Find requirement (G.TOOLREQLIXD.3 step 3)
     let requirement = find_requirement_streaming(&category_path, &category, index)
         .map_err(|_| "Requirement not found".to_string())?;
 
+    // Step 4: Check links, ignoring requirements deleted in the same batch (G.TOOLREQLIXD.7)
+    let graph = ReferenceGraph::build(&req_dir)?;
+    let linking: Vec<String> = graph
+        .backlinks(index)
+        .into_iter()
+        .map(|r| r.index.clone())
+        .filter(|i| !batch.contains(i))
+        .collect();
+    if !linking.is_empty() && !force {
+        return Err(format!(
+            "Requirement is referenced by {}; set force to true to delete it anyway",
+            linking.join(", ")
+        ));
+    }
+
     // Read file content for modification
     let content = read_file_utf8(&category_path)?;
 
-    // Step 4: Delete requirement (G.TOOLREQLIXD.3 step 4, G.R.5)
+    // Step 5: Delete requirement (G.TOOLREQLIXD.3 step 5, G.R.5)
     let search_heading = format!("## {}: ", index);
     let mut heading_start: Option<usize> = None;
     let mut req_end: Option<usize> = None;
@@ -654,7 +843,7 @@ fn delete_single_requirement(
     }
     new_content.push_str(remaining_trimmed);
 
-    // Step 5: Delete empty chapter (G.TOOLREQLIXD.3 step 5)
+    // Step 6: Delete empty chapter (G.TOOLREQLIXD.3 step 6)
     let chapter_heading = format!("# {}", requirement.chapter);
     let chapter_heading_newline = format!("# {}\n", requirement.chapter);
     let chapter_pos = new_content.find(&chapter_heading_newline).or_else(|| {
@@ -693,12 +882,16 @@ fn delete_single_requirement(
     fs::write(&category_path, &new_content)
         .map_err(|e| format!("Failed to write category file: {}", e))?;
 
-    // Step 6: Return result (G.TOOLREQLIXD.3 step 6)
+    // Step 7: Return result (G.TOOLREQLIXD.3 step 7)
     Ok(DeletedRequirement {
         index: index.to_string(),
         title: requirement.title,
         category,
         chapter: requirement.chapter,
+        warnings: linking
+            .iter()
+            .map(|i| format!("Requirement was referenced by {}", i))
+            .collect(),
     })
 }
 
@@ -708,11 +901,16 @@ pub fn handle_delete_requirement(params: DeleteRequirementParams) -> String {
     if let Some(e) = validate_common_params(&params.project_root, &params.operation_description) {
         return json_error(&e);
     }
+    let config = match load_project_config(&params.project_root) {
+        Ok(c) => c,
+        Err(e) => return json_error(&e),
+    };
+    let force = params.force.unwrap_or(false);
 
     match params.index {
         // Single delete (G.TOOLREQLIXD.3 - single)
         IndexParam::Single(index) => {
-            match delete_single_requirement(&params.project_root, &index) {
+            match delete_single_requirement(&params.project_root, &index, force, &[], &config) {
                 Ok(result) => json_success(result),
                 Err(e) => json_error(&e),
             }
@@ -721,26 +919,37 @@ pub fn handle_delete_requirement(params: DeleteRequirementParams) -> String {
         IndexParam::Batch(indices) => {
             // G.P.4: Empty array returns empty result
             if indices.is_empty() {
-                return json_success(json!([]));
+                return json_success(Vec::<ToolResponse<DeletedRequirement>>::new());
             }
 
             // G.TOOLREQLIXD.6: Validate batch size
-            if indices.len() > MAX_BATCH_SIZE {
-                return json_error("Batch delete exceeds maximum limit of 100 indices");
+            if indices.len() > config.limits.max_batch_size {
+                return json_error(&format!(
+                    "Batch delete exceeds maximum limit of {} indices",
+                    config.limits.max_batch_size
+                ));
             }
 
             // Process ALL indices, return success/error for each (G.TOOLREQLIXD.3, G.TOOLREQLIXD.4)
             let mut results = Vec::with_capacity(indices.len());
             for index in &indices {
-                match delete_single_requirement(&params.project_root, index) {
-                    Ok(result) => results.push(json!({
-                        "success": true,
-                        "data": result
-                    })),
-                    Err(e) => results.push(json!({
-                        "success": false,
-                        "error": e
-                    })),
+                match delete_single_requirement(
+                    &params.project_root,
+                    index,
+                    force,
+                    &indices,
+                    &config,
+                ) {
+                    Ok(result) => results.push(ToolResponse {
+                        success: true,
+                        data: Some(result),
+                        error: None,
+                    }),
+                    Err(e) => results.push(ToolResponse {
+                        success: false,
+                        data: None,
+                        error: Some(e),
+                    }),
                 }
             }
 
@@ -757,23 +966,26 @@ pub fn handle_search_requirements(params: SearchRequirementsParams) -> String {
     if let Some(e) = validate_common_params(&params.project_root, &params.operation_description) {
         return json_error(&e);
     }
+    let config = match load_project_config(&params.project_root) {
+        Ok(c) => c,
+        Err(e) => return json_error(&e),
+    };
     // Step 3: Validate and filter keywords
-    let keywords = match validate_keywords(&params.keywords) {
+    let keywords = match validate_keywords_in(&params.keywords, &config) {
         Ok(k) => k,
         Err(e) => return json_error(&e),
     };
 
     // G.TOOLREQLIXS.5, G.P.4: Empty keywords returns success with empty results
     if keywords.is_empty() {
-        let empty_results: Vec<RequirementFull> = Vec::new();
-        return json_success(json!({
-            "keywords": keywords,
-            "results": empty_results
-        }));
+        return json_success(SearchResults {
+            keywords,
+            results: Vec::new(),
+        });
     }
 
     // Get requirements directory
-    let requirements_dir = match get_requirements_dir(&params.project_root) {
+    let requirements_dir = match existing_requirements_dir(&params.project_root) {
         Ok(d) => d,
         Err(e) => return json_error(&e),
     };
@@ -791,53 +1003,750 @@ pub fn handle_search_requirements(params: SearchRequirementsParams) -> String {
 
     // G.TOOLREQLIXS.3 steps 1-7: Iterate over all categories, chapters, requirements
     for category in &categories {
-        let category_path = requirements_dir.join(format!("{}.md", category));
-
-        // Read chapters (G.TOOLREQLIXS.3 step 2)
-        let chapters = match read_chapters_streaming(&category_path) {
-            Ok(c) => c,
+        // Read chapters and requirements through the cache (G.TOOLREQLIXS.3 steps 2-4, G.CA.5)
+        let parsed = match load_category(&requirements_dir, category) {
+            Ok(p) => p,
             Err(_) => continue, // Skip categories with read errors
         };
 
-        // For each chapter (G.TOOLREQLIXS.3 step 3)
-        for chapter in &chapters {
-            // Read requirements in chapter
-            let requirements = match read_requirements_streaming(&category_path, chapter) {
-                Ok(r) => r,
-                Err(_) => continue, // Skip chapters with read errors
-            };
+        for entry in parsed.search_index {
+            // G.TOOLREQLIXS.3 step 5-6: Case-insensitive substring search in title OR text
+            let matches = keywords_lower
+                .iter()
+                .any(|kw| entry.title_lower.contains(kw) || entry.text_lower.contains(kw));
 
-            // For each requirement (G.TOOLREQLIXS.3 step 4)
-            for req_summary in &requirements {
-                // Get full requirement
-                let requirement = match find_requirement_streaming(
-                    &category_path,
-                    category,
-                    &req_summary.index,
-                ) {
-                    Ok(r) => r,
-                    Err(_) => continue, // Skip requirements with read errors
-                };
+            if matches {
+                results.push(entry.requirement);
+            }
+        }
+    }
+
+    // G.TOOLREQLIXS.3 step 7, G.TOOLREQLIXS.4: Return results
+    // Note: Order is undefined (G.TOOLREQLIXS.3)
+    json_success(SearchResults { keywords, results })
+}
 
-                // G.TOOLREQLIXS.3 step 5-6: Case-insensitive substring search in title OR text
-                let title_lower = requirement.title.to_lowercase();
-                let text_lower = requirement.text.to_lowercase();
+/// Group items by key, keeping groups with more than one item in order of first appearance
+fn duplicate_groups<T: Clone>(items: &[T], key: impl Fn(&T) -> &str) -> Vec<(String, Vec<T>)> {
+    let mut groups: Vec<(String, Vec<T>)> = Vec::new();
+    for item in items {
+        match groups.iter_mut().find(|(k, _)| k == key(item)) {
+            Some((_, group)) => group.push(item.clone()),
+            None => groups.push((key(item).to_string(), vec![item.clone()])),
+        }
+    }
+    groups.retain(|(_, group)| group.len() > 1);
+    groups
+}
 
-                let matches = keywords_lower
-                    .iter()
-                    .any(|kw| title_lower.contains(kw) || text_lower.contains(kw));
+/// reqlix_find_duplicates (G.TOOLREQLIXF)
+/// Reports duplicate indices, duplicate titles and near-duplicate texts (G.TOOLREQLIXF.3)
+pub fn handle_find_duplicates(params: FindDuplicatesParams) -> String {
+    // G.TOOLREQLIXF.5: Validate parameters in order
+    if let Some(e) = validate_common_params(&params.project_root, &params.operation_description) {
+        return json_error(&e);
+    }
+    let threshold = params.threshold.unwrap_or(DEFAULT_SIMILARITY_THRESHOLD);
+    if let Err(e) = validate_threshold(threshold) {
+        return json_error(&e);
+    }
 
-                if matches {
-                    results.push(requirement);
-                }
+    // Get requirements directory
+    let requirements_dir = match existing_requirements_dir(&params.project_root) {
+        Ok(d) => d,
+        Err(e) => return json_error(&e),
+    };
+
+    let categories = match list_categories(&requirements_dir) {
+        Ok(c) => c,
+        Err(e) => return json_error(&e),
+    };
+
+    // G.TOOLREQLIXF.3 step 1: Collect every requirement heading and text (G.CA.5)
+    let mut locations: Vec<RequirementLocation> = Vec::new();
+    let mut texts: Vec<(RequirementLocation, String)> = Vec::new();
+    for category in &categories {
+        let parsed = match load_category(&requirements_dir, category) {
+            Ok(p) => p,
+            Err(e) => return json_error(&e),
+        };
+
+        let mut seen_chapters: Vec<&str> = Vec::new();
+        for chapter in &parsed.chapters {
+            // Repeated chapter headings already list all their requirements in the first entry
+            if seen_chapters.contains(&chapter.name.as_str()) {
+                continue;
             }
+            seen_chapters.push(&chapter.name);
+            for summary in &chapter.requirements {
+                locations.push(RequirementLocation {
+                    index: summary.index.clone(),
+                    title: summary.title.clone(),
+                    category: category.clone(),
+                    chapter: chapter.name.clone(),
+                });
+            }
+        }
+
+        for entry in parsed.search_index {
+            let requirement = entry.requirement;
+            if texts.iter().any(|(l, _)| l.index == requirement.index) {
+                continue;
+            }
+            texts.push((
+                RequirementLocation {
+                    index: requirement.index,
+                    title: requirement.title,
+                    category: requirement.category,
+                    chapter: requirement.chapter,
+                },
+                requirement.text,
+            ));
         }
     }
 
-    // G.TOOLREQLIXS.3 step 7, G.TOOLREQLIXS.4: Return results
-    // Note: Order is undefined (G.TOOLREQLIXS.3)
-    json_success(json!({
-        "keywords": keywords,
-        "results": results
-    }))
+    // G.TOOLREQLIXF.3 step 2: Duplicate indices
+    let duplicate_indices: Vec<DuplicateIndex> = duplicate_groups(&locations, |l| &l.index)
+        .into_iter()
+        .map(|(index, occurrences)| DuplicateIndex { index, occurrences })
+        .collect();
+
+    // G.TOOLREQLIXF.3 step 3: Duplicate titles
+    let duplicate_titles: Vec<DuplicateTitle> = duplicate_groups(&locations, |l| &l.title)
+        .into_iter()
+        .map(|(title, requirements)| DuplicateTitle {
+            title,
+            requirements,
+        })
+        .collect();
+
+    // G.TOOLREQLIXF.3 step 4: Near-duplicate texts by TF-IDF cosine similarity
+    let documents: Vec<String> = texts.iter().map(|(_, text)| text.clone()).collect();
+    let index = TfIdfIndex::build(&documents);
+    let mut near_duplicates: Vec<NearDuplicate> = Vec::new();
+    for a in 0..texts.len() {
+        for b in (a + 1)..texts.len() {
+            let similarity = index.similarity(a, b);
+            if similarity > 0.0 && similarity >= threshold {
+                near_duplicates.push(NearDuplicate {
+                    similarity: round_score(similarity),
+                    first: texts[a].0.clone(),
+                    second: texts[b].0.clone(),
+                });
+            }
+        }
+    }
+    near_duplicates.sort_by(|x, y| y.similarity.total_cmp(&x.similarity));
+
+    // G.TOOLREQLIXF.4: Return report
+    json_success(DuplicateReport {
+        threshold,
+        duplicate_indices,
+        duplicate_titles,
+        near_duplicates,
+    })
+}
+
+/// Indices of documents ranked by similarity to the last document (G.TOOLREQLIXSU.3 step 5)
+/// Entries with similarity 0 are dropped; ties keep document order.
+fn rank_against_last(documents: &[String], limit: usize) -> Vec<(usize, f64)> {
+    let Some(draft) = documents.len().checked_sub(1) else {
+        return Vec::new();
+    };
+    let index = TfIdfIndex::build(documents);
+    let mut ranked: Vec<(usize, f64)> = (0..draft)
+        .map(|i| (i, index.similarity(i, draft)))
+        .filter(|(_, similarity)| *similarity > 0.0)
+        .collect();
+    ranked.sort_by(|x, y| y.1.total_cmp(&x.1));
+    ranked.truncate(limit);
+    ranked
+}
+
+/// reqlix_suggest_location (G.TOOLREQLIXSU)
+/// Ranks chapters and existing requirements by similarity to a draft requirement (G.TOOLREQLIXSU.3)
+pub fn handle_suggest_location(params: SuggestLocationParams) -> String {
+    // G.TOOLREQLIXSU.5: Validate parameters in order
+    if let Some(e) = validate_common_params(&params.project_root, &params.operation_description) {
+        return json_error(&e);
+    }
+    let config = match load_project_config(&params.project_root) {
+        Ok(c) => c,
+        Err(e) => return json_error(&e),
+    };
+    if let Err(e) = validate_title_in(&params.title, true, &config) {
+        return json_error(&e);
+    }
+    if let Err(e) = validate_text_in(&params.text, &config) {
+        return json_error(&e);
+    }
+    let limit = params.limit.unwrap_or(DEFAULT_SUGGESTION_LIMIT);
+    if let Err(e) = validate_limit(limit) {
+        return json_error(&e);
+    }
+
+    // Get requirements directory
+    let requirements_dir = match existing_requirements_dir(&params.project_root) {
+        Ok(d) => d,
+        Err(e) => return json_error(&e),
+    };
+
+    let categories = match list_categories(&requirements_dir) {
+        Ok(c) => c,
+        Err(e) => return json_error(&e),
+    };
+
+    // G.TOOLREQLIXSU.3 steps 1-2: Collect chapters and requirements with their documents (G.CA.5)
+    let mut chapters: Vec<(LocationSuggestion, String)> = Vec::new();
+    let mut requirements: Vec<(SimilarRequirement, String)> = Vec::new();
+    for category in &categories {
+        let parsed = match load_category(&requirements_dir, category) {
+            Ok(p) => p,
+            Err(e) => return json_error(&e),
+        };
+
+        for chapter in &parsed.chapters {
+            if chapters
+                .iter()
+                .any(|(c, _)| c.category == *category && c.chapter == chapter.name)
+            {
+                continue;
+            }
+            chapters.push((
+                LocationSuggestion {
+                    similarity: 0.0,
+                    category: category.clone(),
+                    chapter: chapter.name.clone(),
+                },
+                chapter.name.clone(),
+            ));
+        }
+
+        for entry in parsed.search_index {
+            let requirement = entry.requirement;
+            if requirements
+                .iter()
+                .any(|(r, _)| r.index == requirement.index)
+            {
+                continue;
+            }
+            let document = format!("{}\n{}", requirement.title, requirement.text);
+            if let Some((_, chapter_document)) = chapters
+                .iter_mut()
+                .find(|(c, _)| c.category == *category && c.chapter == requirement.chapter)
+            {
+                chapter_document.push('\n');
+                chapter_document.push_str(&document);
+            }
+            requirements.push((
+                SimilarRequirement {
+                    similarity: 0.0,
+                    index: requirement.index,
+                    title: requirement.title,
+                    category: requirement.category,
+                    chapter: requirement.chapter,
+                },
+                document,
+            ));
+        }
+    }
+    let draft = format!("{}\n{}", params.title, params.text);
+
+    // G.TOOLREQLIXSU.3 step 3: Rank locations
+    let mut documents: Vec<String> = chapters.iter().map(|(_, d)| d.clone()).collect();
+    documents.push(draft.clone());
+    let locations: Vec<LocationSuggestion> = rank_against_last(&documents, limit)
+        .into_iter()
+        .map(|(i, similarity)| LocationSuggestion {
+            similarity: round_score(similarity),
+            ..chapters[i].0.clone()
+        })
+        .collect();
+
+    // G.TOOLREQLIXSU.3 step 4: Rank similar requirements
+    let mut documents: Vec<String> = requirements.iter().map(|(_, d)| d.clone()).collect();
+    documents.push(draft);
+    let similar_requirements: Vec<SimilarRequirement> = rank_against_last(&documents, limit)
+        .into_iter()
+        .map(|(i, similarity)| SimilarRequirement {
+            similarity: round_score(similarity),
+            ..requirements[i].0.clone()
+        })
+        .collect();
+
+    // G.TOOLREQLIXSU.4: Return suggestions
+    json_success(LocationSuggestions {
+        locations,
+        similar_requirements,
+    })
+}
+
+/// reqlix_trace (G.TOOLREQLIXT)
+/// Lists source locations citing each requirement (G.TOOLREQLIXT.3)
+pub fn handle_trace(params: TraceParams) -> String {
+    // G.TOOLREQLIXT.5: Validate parameters in order
+    if let Some(e) = validate_common_params(&params.project_root, &params.operation_description) {
+        return json_error(&e);
+    }
+    let config = match load_project_config(&params.project_root) {
+        Ok(c) => c,
+        Err(e) => return json_error(&e),
+    };
+    let options = TraceOptions {
+        include: params.include.unwrap_or_default(),
+        exclude: params.exclude.unwrap_or_default(),
+    };
+    if let Err(e) = validate_globs_in("include", &options.include, &config) {
+        return json_error(&e);
+    }
+    if let Err(e) = validate_globs_in("exclude", &options.exclude, &config) {
+        return json_error(&e);
+    }
+
+    // G.TOOLREQLIXT.3 steps 2-5
+    match trace_requirements(&params.project_root, &options) {
+        Ok(report) => json_success(report),
+        Err(e) => json_error(&e),
+    }
+}
+
+/// reqlix_coverage (G.TOOLREQLIXC)
+/// Reports unreferenced and untested requirements with coverage percentages (G.TOOLREQLIXC.4)
+pub fn handle_coverage(params: CoverageParams) -> String {
+    // G.TOOLREQLIXC.8: Validate parameters in order
+    if let Some(e) = validate_common_params(&params.project_root, &params.operation_description) {
+        return json_error(&e);
+    }
+    let config = match load_project_config(&params.project_root) {
+        Ok(c) => c,
+        Err(e) => return json_error(&e),
+    };
+    let mut options = CoverageOptions {
+        trace: TraceOptions {
+            include: params.include.unwrap_or_default(),
+            exclude: params.exclude.unwrap_or_default(),
+        },
+        ..Default::default()
+    };
+    if let Some(patterns) = params.test_patterns {
+        options.test_patterns = patterns;
+    }
+    for (name, patterns) in [
+        ("include", &options.trace.include),
+        ("exclude", &options.trace.exclude),
+        ("test_patterns", &options.test_patterns),
+    ] {
+        if let Err(e) = validate_globs_in(name, patterns, &config) {
+            return json_error(&e);
+        }
+    }
+    let format = params.format.unwrap_or_else(|| "json".to_string());
+    if let Err(e) = validate_format(&format, COVERAGE_FORMATS) {
+        return json_error(&e);
+    }
+
+    // G.TOOLREQLIXC.4 steps 2-5
+    let report = match coverage_report(&params.project_root, &options) {
+        Ok(r) => r,
+        Err(e) => return json_error(&e),
+    };
+
+    // G.TOOLREQLIXC.4 step 6: Render
+    let rendered = match format.as_str() {
+        "markdown" => Some(render_coverage_markdown(&report)),
+        "csv" => Some(render_coverage_csv(&report)),
+        _ => None,
+    };
+    json_success(RenderedCoverage { report, rendered })
+}
+
+/// reqlix_check_references (G.TOOLREQLIXCH)
+/// Reports references to missing, deleted or moved requirements (G.TOOLREQLIXCH.3)
+pub fn handle_check_references(params: CheckReferencesParams) -> String {
+    // G.TOOLREQLIXCH.5: Validate parameters in order
+    if let Some(e) = validate_common_params(&params.project_root, &params.operation_description) {
+        return json_error(&e);
+    }
+    let config = match load_project_config(&params.project_root) {
+        Ok(c) => c,
+        Err(e) => return json_error(&e),
+    };
+    let options = TraceOptions {
+        include: params.include.unwrap_or_default(),
+        exclude: params.exclude.unwrap_or_default(),
+    };
+    if let Err(e) = validate_globs_in("include", &options.include, &config) {
+        return json_error(&e);
+    }
+    if let Err(e) = validate_globs_in("exclude", &options.exclude, &config) {
+        return json_error(&e);
+    }
+
+    // G.TOOLREQLIXCH.3 steps 2-7
+    match find_dangling_references(&params.project_root, &options) {
+        Ok(report) => json_success(report),
+        Err(e) => json_error(&e),
+    }
+}
+
+/// reqlix_get_references (G.TOOLREQLIXGETREF)
+/// Returns outgoing links and backlinks of a requirement (G.TOOLREQLIXGETREF.3)
+pub fn handle_get_references(params: GetReferencesParams) -> String {
+    // G.TOOLREQLIXGETREF.5: Validate parameters in order
+    if let Some(e) = validate_common_params(&params.project_root, &params.operation_description) {
+        return json_error(&e);
+    }
+    if let Err(e) = validate_index(&params.index) {
+        return json_error(&e);
+    }
+
+    // G.TOOLREQLIXGETREF.3 step 2: Build graph
+    let requirements_dir = match existing_requirements_dir(&params.project_root) {
+        Ok(d) => d,
+        Err(e) => return json_error(&e),
+    };
+    let graph = match ReferenceGraph::build(&requirements_dir) {
+        Ok(g) => g,
+        Err(e) => return json_error(&e),
+    };
+
+    // G.TOOLREQLIXGETREF.3 step 3: Find requirement
+    let Some(requirement) = graph.requirement(&params.index) else {
+        return json_error("Requirement not found");
+    };
+
+    // G.TOOLREQLIXGETREF.3 step 4: Return result
+    json_success(RequirementReferences {
+        requirement: requirement.clone(),
+        outgoing: graph.outgoing(&params.index).into_iter().cloned().collect(),
+        backlinks: graph
+            .backlinks(&params.index)
+            .into_iter()
+            .cloned()
+            .collect(),
+    })
+}
+
+/// reqlix_impact (G.TOOLREQLIXIM)
+pub fn handle_impact(params: ImpactParams) -> String {
+    // G.TOOLREQLIXIM.5: Validate parameters in order
+    if let Some(e) = validate_common_params(&params.project_root, &params.operation_description) {
+        return json_error(&e);
+    }
+    let config = match load_project_config(&params.project_root) {
+        Ok(c) => c,
+        Err(e) => return json_error(&e),
+    };
+    if let Err(e) = validate_index(&params.index) {
+        return json_error(&e);
+    }
+    let options = TraceOptions {
+        include: params.include.unwrap_or_default(),
+        exclude: params.exclude.unwrap_or_default(),
+    };
+    if let Err(e) = validate_globs_in("include", &options.include, &config) {
+        return json_error(&e);
+    }
+    if let Err(e) = validate_globs_in("exclude", &options.exclude, &config) {
+        return json_error(&e);
+    }
+
+    // G.TOOLREQLIXIM.3 step 2: Build graph
+    let requirements_dir = match existing_requirements_dir(&params.project_root) {
+        Ok(d) => d,
+        Err(e) => return json_error(&e),
+    };
+    let graph = match ReferenceGraph::build(&requirements_dir) {
+        Ok(g) => g,
+        Err(e) => return json_error(&e),
+    };
+
+    // G.TOOLREQLIXIM.3 step 3: Find requirement
+    let Some(requirement) = graph.requirement(&params.index) else {
+        return json_error("Requirement not found");
+    };
+
+    // G.TOOLREQLIXIM.3 step 4: Walk relations
+    let affected = graph.impact(&params.index);
+
+    // G.TOOLREQLIXIM.3 step 5: Scan references
+    let scan = match scan_references(Path::new(&params.project_root), &requirements_dir, &options) {
+        Ok(s) => s,
+        Err(e) => return json_error(&e),
+    };
+    let locations = scan
+        .references
+        .into_iter()
+        .filter(|r| {
+            r.index == requirement.index || affected.iter().any(|a| a.requirement.index == r.index)
+        })
+        .collect();
+
+    // G.TOOLREQLIXIM.3 step 6: Return result
+    json_success(ImpactReport {
+        requirement: requirement.clone(),
+        affected,
+        files_scanned: scan.files_scanned,
+        locations,
+    })
+}
+
+/// reqlix_check_links (G.TOOLREQLIXCHECKL)
+pub fn handle_check_links(params: CheckLinksParams) -> String {
+    // G.TOOLREQLIXCHECKL.5: Validate parameters in order
+    if let Some(e) = validate_common_params(&params.project_root, &params.operation_description) {
+        return json_error(&e);
+    }
+
+    // G.TOOLREQLIXCHECKL.3 steps 2-6
+    let requirements_dir = match existing_requirements_dir(&params.project_root) {
+        Ok(d) => d,
+        Err(e) => return json_error(&e),
+    };
+    match find_broken_links(&requirements_dir) {
+        Ok(report) => json_success(report),
+        Err(e) => json_error(&e),
+    }
+}
+
+/// reqlix_rewrite_references (G.TOOLREQLIXR)
+pub fn handle_rewrite_references(params: RewriteReferencesParams) -> String {
+    // G.TOOLREQLIXR.5: Validate parameters in order
+    if let Some(e) = validate_common_params(&params.project_root, &params.operation_description) {
+        return json_error(&e);
+    }
+    let config = match load_project_config(&params.project_root) {
+        Ok(c) => c,
+        Err(e) => return json_error(&e),
+    };
+    if let Err(e) = validate_mappings_in(&params.mappings, &config) {
+        return json_error(&e);
+    }
+    let options = TraceOptions {
+        include: params.include.unwrap_or_default(),
+        exclude: params.exclude.unwrap_or_default(),
+    };
+    if let Err(e) = validate_globs_in("include", &options.include, &config) {
+        return json_error(&e);
+    }
+    if let Err(e) = validate_globs_in("exclude", &options.exclude, &config) {
+        return json_error(&e);
+    }
+
+    // G.TOOLREQLIXR.3 steps 2-6
+    let mapping: HashMap<String, String> = params
+        .mappings
+        .into_iter()
+        .map(|m| (m.from, m.to))
+        .collect();
+    match rewrite_references(
+        &params.project_root,
+        &mapping,
+        &options,
+        params.dry_run.unwrap_or(false),
+    ) {
+        Ok(report) => json_success(report),
+        Err(e) => json_error(&e),
+    }
+}
+
+/// reqlix_test_map (G.TOOLREQLIXTE)
+pub fn handle_test_map(params: TestMapParams) -> String {
+    // G.TOOLREQLIXTE.5: Validate parameters in order
+    if let Some(e) = validate_common_params(&params.project_root, &params.operation_description) {
+        return json_error(&e);
+    }
+    let config = match load_project_config(&params.project_root) {
+        Ok(c) => c,
+        Err(e) => return json_error(&e),
+    };
+    let options = TraceOptions {
+        include: params.include.unwrap_or_default(),
+        exclude: params.exclude.unwrap_or_default(),
+    };
+    if let Err(e) = validate_globs_in("include", &options.include, &config) {
+        return json_error(&e);
+    }
+    if let Err(e) = validate_globs_in("exclude", &options.exclude, &config) {
+        return json_error(&e);
+    }
+
+    // G.TOOLREQLIXTE.3 steps 2-5
+    match map_tests(&params.project_root, &options) {
+        Ok(map) => json_success(map),
+        Err(e) => json_error(&e),
+    }
+}
+
+/// reqlix_import_test_results (G.TOOLREQLIXIMPO)
+pub fn handle_import_test_results(params: ImportTestResultsParams) -> String {
+    // G.TOOLREQLIXIMPO.5: Validate parameters in order
+    if let Some(e) = validate_common_params(&params.project_root, &params.operation_description) {
+        return json_error(&e);
+    }
+    let config = match load_project_config(&params.project_root) {
+        Ok(c) => c,
+        Err(e) => return json_error(&e),
+    };
+    if let Err(e) = validate_file(&params.file) {
+        return json_error(&e);
+    }
+    if let Some(format) = &params.format {
+        if let Err(e) = validate_format(format, TEST_RESULT_FORMATS) {
+            return json_error(&e);
+        }
+    }
+    let options = TraceOptions {
+        include: params.include.unwrap_or_default(),
+        exclude: params.exclude.unwrap_or_default(),
+    };
+    if let Err(e) = validate_globs_in("include", &options.include, &config) {
+        return json_error(&e);
+    }
+    if let Err(e) = validate_globs_in("exclude", &options.exclude, &config) {
+        return json_error(&e);
+    }
+
+    // G.TOOLREQLIXIMPO.3 steps 2-7
+    match import_test_results(
+        &params.project_root,
+        &params.file,
+        params.format.as_deref(),
+        &options,
+    ) {
+        Ok(report) => json_success(report),
+        Err(e) => json_error(&e),
+    }
+}
+
+/// reqlix_export_matrix (G.TOOLREQLIXE)
+pub fn handle_export_matrix(params: ExportMatrixParams) -> String {
+    // G.TOOLREQLIXE.6: Validate parameters in order
+    if let Some(e) = validate_common_params(&params.project_root, &params.operation_description) {
+        return json_error(&e);
+    }
+    let config = match load_project_config(&params.project_root) {
+        Ok(c) => c,
+        Err(e) => return json_error(&e),
+    };
+    if let Err(e) = validate_format(&params.format, MATRIX_FORMATS) {
+        return json_error(&e);
+    }
+    let mut options = CoverageOptions {
+        trace: TraceOptions {
+            include: params.include.unwrap_or_default(),
+            exclude: params.exclude.unwrap_or_default(),
+        },
+        ..Default::default()
+    };
+    if let Some(patterns) = params.test_patterns {
+        options.test_patterns = patterns;
+    }
+    for (name, patterns) in [
+        ("include", &options.trace.include),
+        ("exclude", &options.trace.exclude),
+        ("test_patterns", &options.test_patterns),
+    ] {
+        if let Err(e) = validate_globs_in(name, patterns, &config) {
+            return json_error(&e);
+        }
+    }
+    if let Some(output) = &params.output {
+        if let Err(e) = validate_output(output) {
+            return json_error(&e);
+        }
+    }
+
+    // G.TOOLREQLIXE.3 steps 2-5
+    let matrix = match traceability_matrix(&params.project_root, &options) {
+        Ok(m) => m,
+        Err(e) => return json_error(&e),
+    };
+
+    // G.TOOLREQLIXE.3 step 6: Render
+    let rendered = match params.format.as_str() {
+        "csv" => render_matrix_csv(&matrix),
+        "html" => render_matrix_html(&matrix),
+        _ => render_matrix_markdown(&matrix),
+    };
+
+    // G.TOOLREQLIXE.3 step 7: Write output
+    if let Some(output) = &params.output {
+        let written = resolve_project_path(&params.project_root, "output", output)
+            .and_then(|path| write_file_utf8(&path, &rendered));
+        if let Err(e) = written {
+            return json_error(&e);
+        }
+    }
+
+    json_success(MatrixExport {
+        format: params.format,
+        verification_imported_at: matrix.verification_imported_at,
+        rows: matrix.rows,
+        rendered,
+        output: params.output,
+    })
+}
+
+/// reqlix_export_graph (G.TOOLREQLIXEXPORTG)
+pub fn handle_export_graph(params: ExportGraphParams) -> String {
+    // G.TOOLREQLIXEXPORTG.6: Validate parameters in order
+    if let Some(e) = validate_common_params(&params.project_root, &params.operation_description) {
+        return json_error(&e);
+    }
+    if let Err(e) = validate_format(&params.format, GRAPH_FORMATS) {
+        return json_error(&e);
+    }
+    if let Some(category) = &params.category {
+        let config = match load_project_config(&params.project_root) {
+            Ok(c) => c,
+            Err(e) => return json_error(&e),
+        };
+        if let Err(e) = validate_category_in(category, &config) {
+            return json_error(&e);
+        }
+    }
+    if let Some(index) = &params.index {
+        if let Err(e) = validate_index(index) {
+            return json_error(&e);
+        }
+    }
+    if let Some(radius) = params.radius {
+        if let Err(e) = validate_radius(radius) {
+            return json_error(&e);
+        }
+        if params.index.is_none() {
+            return json_error("radius requires index");
+        }
+    }
+
+    // G.TOOLREQLIXEXPORTG.3 step 2: Build graph
+    let requirements_dir = match existing_requirements_dir(&params.project_root) {
+        Ok(d) => d,
+        Err(e) => return json_error(&e),
+    };
+    let graph = match ReferenceGraph::build(&requirements_dir) {
+        Ok(g) => g,
+        Err(e) => return json_error(&e),
+    };
+
+    // G.TOOLREQLIXEXPORTG.3 steps 3-4: Select nodes and edges
+    let radius = params.radius.unwrap_or(DEFAULT_GRAPH_RADIUS);
+    let neighborhood = params.index.as_deref().map(|index| (index, radius));
+    let diagram = match Diagram::select(&graph, params.category.as_deref(), neighborhood) {
+        Ok(d) => d,
+        Err(e) => return json_error(&e),
+    };
+
+    // G.TOOLREQLIXEXPORTG.3 steps 5-6: Render and return result
+    let rendered = match params.format.as_str() {
+        "dot" => render_dot(&diagram),
+        _ => render_mermaid(&diagram),
+    };
+    json_success(GraphExport {
+        format: params.format,
+        nodes: diagram.nodes.len(),
+        edges: diagram.edge_count(),
+        rendered,
+    })
 }
@@ -1,5 +1,6 @@
 // Category helpers (G.C.7, G.F.4) and insert/update helpers
 
+use crate::config::IndexConfig;
 use crate::parsing::{
     parse_level1_heading, parse_level2_heading, read_chapters_streaming,
     read_requirements_streaming,
@@ -32,23 +33,28 @@ pub fn list_categories(requirements_dir: &PathBuf) -> Result<Vec<String>, String
     Ok(categories)
 }
 
-/// Calculate unique prefix for a name among a list of names (G.F.4)
-#[cfg_attr(test, allow(dead_code))]
-pub fn calculate_unique_prefix(name: &str, all_names: &[String]) -> String {
-    // Extract only letters (A-Z, a-z) from the name (G.R.4)
-    let letters: Vec<char> = name.chars().filter(|c| c.is_ascii_alphabetic()).collect();
-    if letters.is_empty() {
+/// Characters of a name that make up its prefix (G.R.4)
+/// English letters (uppercased) and digits; digits before the first letter and all
+/// other characters (spaces, colons, hyphens, underscores, dots, ...) are ignored.
+fn prefix_characters(name: &str) -> Vec<char> {
+    name.chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .skip_while(|c| c.is_ascii_digit())
+        .map(|c| c.to_ascii_uppercase())
+        .collect()
+}
+
+/// Shortest prefix of a name that no other name shares (G.F.4, G.R.4)
+fn calculate_prefix(name: &str, all_names: &[String]) -> String {
+    let characters = prefix_characters(name);
+    if characters.is_empty() {
         return String::new();
     }
 
     let mut prefix_len = 1;
 
     loop {
-        let prefix: String = letters
-            .iter()
-            .take(prefix_len)
-            .collect::<String>()
-            .to_uppercase();
+        let prefix: String = characters.iter().take(prefix_len).collect();
 
         // Check if this prefix is unique
         let mut conflicts = 0;
@@ -56,72 +62,45 @@ pub fn calculate_unique_prefix(name: &str, all_names: &[String]) -> String {
             if other == name {
                 continue;
             }
-            let other_letters: Vec<char> =
-                other.chars().filter(|c| c.is_ascii_alphabetic()).collect();
-            if other_letters.is_empty() {
+            let other_characters = prefix_characters(other);
+            if other_characters.is_empty() {
                 continue;
             }
-            let other_prefix: String = other_letters
-                .iter()
-                .take(prefix_len)
-                .collect::<String>()
-                .to_uppercase();
+            let other_prefix: String = other_characters.iter().take(prefix_len).collect();
             if other_prefix == prefix {
                 conflicts += 1;
             }
         }
 
-        if conflicts == 0 || prefix_len >= letters.len() {
+        if conflicts == 0 || prefix_len >= characters.len() {
             return prefix;
         }
         prefix_len += 1;
     }
 }
 
+/// Calculate unique prefix for a name among a list of names (G.F.4)
+#[cfg_attr(test, allow(dead_code))]
+pub fn calculate_unique_prefix(name: &str, all_names: &[String]) -> String {
+    calculate_prefix(name, all_names)
+}
+
 /// Calculate unique prefix for chapter names (G.R.4)
-/// Only considers letters (A-Z, a-z), ignoring spaces, colons, hyphens, etc.
+/// Considers letters (A-Z, a-z) and digits, ignoring spaces, colons, hyphens, etc.
 #[cfg_attr(test, allow(dead_code))]
 pub fn calculate_chapter_prefix(name: &str, all_names: &[String]) -> String {
-    // Extract only letters (A-Z, a-z) from the name (G.R.4)
-    let letters: Vec<char> = name.chars().filter(|c| c.is_ascii_alphabetic()).collect();
-    if letters.is_empty() {
-        return String::new();
-    }
-
-    let mut prefix_len = 1;
-
-    loop {
-        let prefix: String = letters
-            .iter()
-            .take(prefix_len)
-            .collect::<String>()
-            .to_uppercase();
-
-        // Check if this prefix is unique
-        let mut conflicts = 0;
-        for other in all_names {
-            if other == name {
-                continue;
-            }
-            let other_letters: Vec<char> =
-                other.chars().filter(|c| c.is_ascii_alphabetic()).collect();
-            if other_letters.is_empty() {
-                continue;
-            }
-            let other_prefix: String = other_letters
-                .iter()
-                .take(prefix_len)
-                .collect::<String>()
-                .to_uppercase();
-            if other_prefix == prefix {
-                conflicts += 1;
-            }
-        }
+    calculate_prefix(name, all_names)
+}
 
-        if conflicts == 0 || prefix_len >= letters.len() {
-            return prefix;
-        }
-        prefix_len += 1;
+/// Calculated prefix, or an error for names without letters (G.R.4)
+fn require_prefix(kind: &str, name: &str, prefix: String) -> Result<String, String> {
+    if prefix.is_empty() {
+        Err(format!(
+            "Cannot calculate a prefix for {} '{}': the name contains no English letter (A-Z, a-z)",
+            kind, name
+        ))
+    } else {
+        Ok(prefix)
     }
 }
 
@@ -130,9 +109,17 @@ pub fn calculate_chapter_prefix(name: &str, all_names: &[String]) -> String {
 pub fn find_category_by_prefix(
     requirements_dir: &PathBuf,
     search_prefix: &str,
+    index: &IndexConfig,
 ) -> Result<String, String> {
     let categories = list_categories(requirements_dir)?;
 
+    // Fixed prefixes of reqlix.toml (G.SE.6)
+    for (category, prefix) in &index.category_prefixes {
+        if prefix == search_prefix && categories.contains(category) {
+            return Ok(category.clone());
+        }
+    }
+
     for category in &categories {
         let prefix = calculate_unique_prefix(category, &categories);
         if prefix == search_prefix {
@@ -148,6 +135,7 @@ pub fn get_or_calculate_category_prefix(
     category_path: &PathBuf,
     category_name: &str,
     all_categories: &[String],
+    index: &IndexConfig,
 ) -> Result<String, String> {
     // Try to find existing prefix from requirements in the file
     if category_path.exists() {
@@ -180,8 +168,15 @@ pub fn get_or_calculate_category_prefix(
         }
     }
 
-    // Calculate new prefix
-    Ok(calculate_unique_prefix(category_name, all_categories))
+    // Use the fixed prefix of reqlix.toml (G.SE.6), otherwise calculate a new one
+    if let Some(prefix) = index.category_prefixes.get(category_name) {
+        return Ok(prefix.clone());
+    }
+    require_prefix(
+        "category",
+        category_name,
+        calculate_unique_prefix(category_name, all_categories),
+    )
 }
 
 /// Get existing chapter prefix from requirements, or calculate new one
@@ -233,7 +228,11 @@ pub fn get_or_calculate_chapter_prefix(
     }
 
     // Calculate new prefix (G.R.4)
-    Ok(calculate_chapter_prefix(chapter_name, &chapters))
+    require_prefix(
+        "chapter",
+        chapter_name,
+        calculate_chapter_prefix(chapter_name, &chapters),
+    )
 }
 
 /// Get next requirement number in a chapter
@@ -0,0 +1,82 @@
+// Streamable HTTP transport with sessions and bearer token (G.H.1 - G.H.3)
+
+use crate::constants::HTTP_PATH;
+use crate::RequirementsServer;
+use axum::extract::Request;
+use axum::http::{header, StatusCode};
+use axum::middleware::{self, Next};
+use axum::response::{IntoResponse, Response};
+use axum::Router;
+use rmcp::transport::streamable_http_server::session::local::LocalSessionManager;
+use rmcp::transport::streamable_http_server::{StreamableHttpServerConfig, StreamableHttpService};
+use std::future::Future;
+use std::sync::Arc;
+use tokio::net::TcpListener;
+
+/// Reject requests without `Authorization: Bearer <token>` (G.H.3)
+async fn require_bearer_token(token: Arc<str>, request: Request, next: Next) -> Response {
+    let authorized = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .is_some_and(|provided| provided == &*token);
+    if authorized {
+        next.run(request).await
+    } else {
+        (
+            StatusCode::UNAUTHORIZED,
+            [(header::WWW_AUTHENTICATE, "Bearer")],
+            "Unauthorized",
+        )
+            .into_response()
+    }
+}
+
+/// Router serving MCP at `/mcp`, one server state per session (G.H.1, G.H.2)
+fn http_router(
+    config: StreamableHttpServerConfig,
+    project_root: Option<String>,
+    token: Option<String>,
+) -> Router {
+    let service = StreamableHttpService::new(
+        move || Ok(RequirementsServer::new().with_project_root(project_root.clone())),
+        Arc::new(LocalSessionManager::default()),
+        config,
+    );
+    let router = Router::new().nest_service(HTTP_PATH, service);
+    match token {
+        Some(token) => {
+            let token: Arc<str> = token.into();
+            router.layer(middleware::from_fn(move |request, next| {
+                require_bearer_token(token.clone(), request, next)
+            }))
+        }
+        None => router,
+    }
+}
+
+/// Serve MCP over HTTP on `listener` until `shutdown` completes (G.H.1)
+///
+/// Sessions use `project_root` as the default project root (G.PR.1).
+pub async fn serve_http(
+    listener: TcpListener,
+    project_root: Option<String>,
+    token: Option<String>,
+    shutdown: impl Future<Output = ()> + Send + 'static,
+) -> std::io::Result<()> {
+    tracing::info!(
+        "Listening on http://{}{}",
+        listener.local_addr()?,
+        HTTP_PATH
+    );
+    let config = StreamableHttpServerConfig::default();
+    // Open event streams keep connections alive; end them with the server
+    let sessions = config.cancellation_token.clone();
+    axum::serve(listener, http_router(config, project_root, token))
+        .with_graceful_shutdown(async move {
+            shutdown.await;
+            sessions.cancel();
+        })
+        .await
+}
@@ -0,0 +1,198 @@
+// Instructions template rendering (G.REQLIX_GET_I.7, G.I.1 - G.I.3)
+
+use crate::cache::load_category;
+use crate::config::ProjectConfig;
+use crate::filesystem::{get_discovery_dirs, read_file_utf8};
+use crate::graph::github_slug;
+use crate::helpers::list_categories;
+use regex::Regex;
+use std::cell::Cell;
+use std::path::{Component, Path, PathBuf};
+use std::sync::OnceLock;
+
+/// Placeholder `{name}` or `{name:argument}` (G.I.1)
+fn placeholder_pattern() -> &'static Regex {
+    static PATTERN: OnceLock<Regex> = OnceLock::new();
+    PATTERN.get_or_init(|| {
+        Regex::new(r"\{([a-z_]+)(?::([^{}\n]+))?\}").expect("valid placeholder pattern")
+    })
+}
+
+/// Values shared by the template and its fragments (G.I.2)
+struct TemplateContext<'a> {
+    requirements_dir: PathBuf,
+    requirements_directory: String,
+    project_name: String,
+    config: &'a ProjectConfig,
+    /// Set once {categories} was rendered
+    lists_categories: Cell<bool>,
+}
+
+/// Directory of the project containing the requirements directory (G.C.8)
+fn project_dir(project_root: &str, requirements_dir: &Path) -> PathBuf {
+    get_discovery_dirs(project_root)
+        .into_iter()
+        .find(|dir| requirements_dir.starts_with(dir))
+        .unwrap_or_else(|| PathBuf::from(project_root))
+}
+
+/// Markdown list of the categories with their requirement counts (G.I.2)
+fn categories_list(requirements_dir: &PathBuf) -> Result<String, String> {
+    let categories = list_categories(requirements_dir)?;
+    if categories.is_empty() {
+        return Ok("No categories defined yet.".to_string());
+    }
+    let mut lines = Vec::with_capacity(categories.len());
+    for category in &categories {
+        let count = load_category(requirements_dir, category)?
+            .search_index
+            .len();
+        let noun = if count == 1 {
+            "requirement"
+        } else {
+            "requirements"
+        };
+        lines.push(format!("- {} ({} {})", category, count, noun));
+    }
+    Ok(lines.join("\n"))
+}
+
+/// Markdown list of links to the key chapters of reqlix.toml; missing chapters are marked, not fatal (G.I.2, G.I.4)
+fn key_chapters_list(context: &TemplateContext) -> Result<String, String> {
+    let categories = list_categories(&context.requirements_dir)?;
+    let mut lines = Vec::new();
+    for entry in &context.config.instructions.key_chapters {
+        let found = match entry.split_once('/') {
+            Some((category, chapter)) if categories.iter().any(|c| c == category) => {
+                load_category(&context.requirements_dir, category)?
+                    .chapter_names()
+                    .iter()
+                    .any(|c| c == chapter)
+                    .then_some((category, chapter))
+            }
+            _ => None,
+        };
+        lines.push(match found {
+            Some((category, chapter)) => format!(
+                "- [{}]({}.md#{}) ({})",
+                chapter,
+                category,
+                github_slug(chapter),
+                category
+            ),
+            None => format!("- {} (not found)", entry),
+        });
+    }
+    Ok(lines.join("\n"))
+}
+
+/// Rendered content of a fragment of the requirements directory (G.I.3)
+fn include_fragment(
+    context: &TemplateContext,
+    path: &str,
+    stack: &mut Vec<PathBuf>,
+) -> Result<String, String> {
+    let inside = Path::new(path)
+        .components()
+        .all(|component| matches!(component, Component::Normal(_) | Component::CurDir));
+    if !inside {
+        return Err(format!(
+            "Instruction fragment must be a relative path inside the requirements directory: {}",
+            path
+        ));
+    }
+    let fragment_path = context.requirements_dir.join(path);
+    if !fragment_path.is_file() {
+        return Err(format!("Instruction fragment not found: {}", path));
+    }
+    if stack.contains(&fragment_path) {
+        return Err(format!("Instruction fragment includes itself: {}", path));
+    }
+
+    stack.push(fragment_path.clone());
+    let content = read_file_utf8(&fragment_path)?;
+    let rendered = render(context, &content, stack)?;
+    stack.pop();
+    Ok(rendered.strip_suffix('\n').unwrap_or(&rendered).to_string())
+}
+
+/// Replace the known placeholders of a template; other text in braces is kept (G.I.1)
+fn render(
+    context: &TemplateContext,
+    template: &str,
+    stack: &mut Vec<PathBuf>,
+) -> Result<String, String> {
+    let mut rendered = String::with_capacity(template.len());
+    let mut last = 0;
+    for captures in placeholder_pattern().captures_iter(template) {
+        let whole = captures.get(0).expect("match");
+        let argument = captures.get(2).map(|m| m.as_str());
+        let value = match (&captures[1], argument) {
+            ("requirements_directory", None) => context.requirements_directory.clone(),
+            ("project_name", None) => context.project_name.clone(),
+            ("categories", None) => {
+                context.lists_categories.set(true);
+                categories_list(&context.requirements_dir)?
+            }
+            ("key_chapters", None) => key_chapters_list(context)?,
+            ("include", Some(path)) => include_fragment(context, path, stack)?,
+            _ => continue,
+        };
+        rendered.push_str(&template[last..whole.start()]);
+        rendered.push_str(&value);
+        last = whole.end();
+    }
+    rendered.push_str(&template[last..]);
+    Ok(rendered)
+}
+
+/// Render AGENTS.md of a requirements directory (G.REQLIX_GET_I.7, G.I.1)
+///
+/// With `categories_chapter`, the generated "# Categories" chapter is appended unless the
+/// template or one of its fragments lists the categories itself.
+pub fn render_instructions(
+    project_root: &str,
+    requirements_dir: &Path,
+    config: &ProjectConfig,
+    categories_chapter: bool,
+) -> Result<String, String> {
+    let requirements_dir = requirements_dir.to_path_buf();
+    let project_dir = project_dir(project_root, &requirements_dir);
+    let requirements_directory = requirements_dir
+        .strip_prefix(&project_dir)
+        .map(|rel| rel.to_string_lossy().replace('\\', "/"))
+        .unwrap_or_default();
+    let project_name = match &config.instructions.project_name {
+        Some(name) => name.clone(),
+        None => project_dir
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default(),
+    };
+    let context = TemplateContext {
+        requirements_dir,
+        requirements_directory,
+        project_name,
+        config,
+        lists_categories: Cell::new(false),
+    };
+
+    let template = read_file_utf8(&context.requirements_dir.join("AGENTS.md"))?;
+    let mut content = render(&context, &template, &mut Vec::new())?;
+
+    // Generated Categories chapter (G.REQLIX_GET_I.7)
+    if categories_chapter && !context.lists_categories.get() {
+        let categories = list_categories(&context.requirements_dir)?;
+        if categories.is_empty() {
+            content.push_str("\n# Categories\n\nNo categories defined yet.\n");
+        } else {
+            let list = categories
+                .iter()
+                .map(|c| format!("- {}", c))
+                .collect::<Vec<_>>()
+                .join("\n");
+            content.push_str(&format!("\n# Categories\n\n{}\n", list));
+        }
+    }
+    Ok(content)
+}
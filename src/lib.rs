@@ -1,23 +1,89 @@
 // Main library module - re-exports and RequirementsServer struct
 
+mod cache;
+mod config;
 mod constants;
+mod coverage;
+mod dangling;
 mod descriptions;
+mod diagram;
 mod filesystem;
+mod graph;
 mod handlers;
 mod helpers;
+mod http;
+mod instructions;
+mod links;
+mod matrix;
 mod models;
 mod params;
 mod parsing;
+mod prompts;
+mod relations;
+mod resources;
 mod response;
+mod rewrite;
+mod roots;
 mod server;
+mod similarity;
+mod subscriptions;
+mod testmap;
+mod trace;
 mod validation;
+mod verification;
 
 // Re-export public types for external use
-pub use models::{DeletedRequirement, RequirementFull, RequirementSummary};
+pub use cache::{CachedCategory, CachedChapter, SearchEntry};
+pub use config::{
+    load_project_config, parse_config, validate_config, IndexConfig, InstructionsConfig,
+    LimitsConfig, NamingConfig, ProjectConfig, RequirementsConfig,
+};
+pub use coverage::{
+    coverage_report, render_coverage_csv, render_coverage_markdown, CoverageOptions,
+};
+pub use dangling::{find_dangling_references, requirement_history};
+pub use diagram::{render_dot, render_mermaid, Diagram};
+pub use graph::{github_slug, requirement_anchor, ReferenceGraph};
+pub use http::serve_http;
+pub use instructions::render_instructions;
+pub use links::{find_broken_links, heading_anchors, parse_link_destination};
+pub use matrix::{
+    html_escape, render_matrix_csv, render_matrix_html, render_matrix_markdown, traceability_matrix,
+};
+pub use models::{
+    BrokenLink, CategoryCoverage, CategoryList, ChapterCoverage, ChapterList, ChapterRequirements,
+    CodeLocation, CoverageReport, CoverageSummary, DanglingReference, DanglingReport,
+    DeletedRequirement, DuplicateIndex, DuplicateReport, DuplicateTitle, GraphExport, ImpactReport,
+    ImpactedRequirement, IndexReference, InsertedRequirement, Instructions, LinkReport,
+    LocationSuggestion, LocationSuggestions, MatrixExport, MatrixRow, NearDuplicate,
+    PromptArgumentInfo, PromptContent, PromptInfo, ReferenceChange, ReferenceScan,
+    RenderedCoverage, RequirementFull, RequirementLocation, RequirementSummary,
+    RequirementVerification, ResourceChanges, ResourceContent, ResourceInfo, RewriteReport,
+    SearchResults, SimilarRequirement, SingleOrBatch, TestFunction, TestMap, TestOutcome,
+    ToolResponse, TraceReport, TraceabilityMatrix, TracedRequirement, UpdatedRequirement,
+    VerificationReport, VerificationSummary, VerifiedTest, VersionInfo,
+};
 pub use params::*;
+pub use prompts::{
+    get_prompt, list_prompts, validate_prompt_arguments, validate_prompt_arguments_in,
+};
+pub use relations::{apply_relations, parse_relation_line, parse_relations};
+pub use resources::{encode_uri_segment, list_resources, read_resource, ResourceUri};
+pub use rewrite::{rewrite_index_line, rewrite_references};
+pub use roots::{resolve_project_root, root_uri_path};
+pub use subscriptions::{
+    resource_changes, resource_snapshot, RequirementsWatcher, ResourceSnapshot,
+};
+pub use testmap::{map_tests, parse_test_functions};
+pub use trace::{scan_references, trace_requirements, TraceOptions};
+pub use verification::{import_test_results, parse_junit, parse_libtest};
 
 // Re-export public functions for tests (module-level)
 #[cfg(test)]
+pub use cache::*;
+#[cfg(test)]
+pub use coverage::*;
+#[cfg(test)]
 pub use filesystem::*;
 #[cfg(test)]
 pub use handlers::*;
@@ -26,6 +92,10 @@ pub use helpers::*;
 #[cfg(test)]
 pub use parsing::*;
 #[cfg(test)]
+pub use similarity::*;
+#[cfg(test)]
+pub use trace::*;
+#[cfg(test)]
 pub use validation::*;
 
 // =============================================================================
@@ -33,11 +103,24 @@ pub use validation::*;
 // =============================================================================
 
 #[derive(Debug, Clone, Default)]
-pub struct RequirementsServer;
+pub struct RequirementsServer {
+    // G.M.6: Resource subscriptions of the session
+    subscriptions: std::sync::Arc<std::sync::Mutex<subscriptions::Subscriptions>>,
+    // G.PR.1: Project root of requests without project_root
+    default_project_root: Option<String>,
+    // G.PR.2: Directories of the client roots, None until requested
+    roots: std::sync::Arc<std::sync::Mutex<Option<Vec<String>>>>,
+}
 
 impl RequirementsServer {
     pub fn new() -> Self {
-        Self
+        Self::default()
+    }
+
+    /// Use a default project root for requests without project_root (G.PR.1)
+    pub fn with_project_root(mut self, project_root: Option<String>) -> Self {
+        self.default_project_root = project_root;
+        self
     }
 
     // Compatibility methods for tests - delegate to module functions
@@ -73,6 +156,38 @@ impl RequirementsServer {
         validation::validate_keywords(keywords)
     }
 
+    pub fn validate_threshold(value: f64) -> Result<(), String> {
+        validation::validate_threshold(value)
+    }
+
+    pub fn validate_limit(value: usize) -> Result<(), String> {
+        validation::validate_limit(value)
+    }
+
+    pub fn validate_globs(name: &str, patterns: &[String]) -> Result<(), String> {
+        validation::validate_globs(name, patterns)
+    }
+
+    pub fn validate_format(value: &str, allowed: &[&str]) -> Result<(), String> {
+        validation::validate_format(value, allowed)
+    }
+
+    pub fn find_index_references(line: &str) -> Vec<String> {
+        trace::find_index_references(line)
+    }
+
+    pub fn csv_field(value: &str) -> String {
+        coverage::csv_field(value)
+    }
+
+    pub fn tokenize(text: &str) -> Vec<String> {
+        similarity::tokenize(text)
+    }
+
+    pub fn name_similarity(a: &str, b: &str) -> f64 {
+        similarity::name_similarity(a, b)
+    }
+
     pub fn read_file_utf8(path: &std::path::PathBuf) -> Result<String, String> {
         filesystem::read_file_utf8(path)
     }
@@ -93,6 +208,10 @@ impl RequirementsServer {
         filesystem::get_create_path(project_root)
     }
 
+    pub fn get_discovery_dirs(project_root: &str) -> Vec<std::path::PathBuf> {
+        filesystem::get_discovery_dirs(project_root)
+    }
+
     pub fn list_categories(requirements_dir: &std::path::PathBuf) -> Result<Vec<String>, String> {
         helpers::list_categories(requirements_dir)
     }
@@ -109,7 +228,7 @@ impl RequirementsServer {
         requirements_dir: &std::path::PathBuf,
         search_prefix: &str,
     ) -> Result<String, String> {
-        helpers::find_category_by_prefix(requirements_dir, search_prefix)
+        helpers::find_category_by_prefix(requirements_dir, search_prefix, &IndexConfig::default())
     }
 
     pub fn parse_level1_heading(line: &str) -> Option<String> {
@@ -145,6 +264,24 @@ impl RequirementsServer {
         parsing::parse_index(index)
     }
 
+    pub fn content_hash(bytes: &[u8]) -> u64 {
+        cache::content_hash(bytes)
+    }
+
+    pub fn get_cache_path(
+        requirements_dir: &std::path::Path,
+        category: &str,
+    ) -> std::path::PathBuf {
+        cache::get_cache_path(requirements_dir, category)
+    }
+
+    pub fn load_category(
+        requirements_dir: &std::path::Path,
+        category: &str,
+    ) -> Result<CachedCategory, String> {
+        cache::load_category(requirements_dir, category)
+    }
+
     pub fn handle_get_instructions(params: params::GetInstructionsParams) -> String {
         handlers::handle_get_instructions(params)
     }
@@ -184,6 +321,90 @@ impl RequirementsServer {
     pub fn handle_search_requirements(params: params::SearchRequirementsParams) -> String {
         handlers::handle_search_requirements(params)
     }
+
+    pub fn handle_find_duplicates(params: params::FindDuplicatesParams) -> String {
+        handlers::handle_find_duplicates(params)
+    }
+
+    pub fn handle_suggest_location(params: params::SuggestLocationParams) -> String {
+        handlers::handle_suggest_location(params)
+    }
+
+    pub fn handle_trace(params: params::TraceParams) -> String {
+        handlers::handle_trace(params)
+    }
+
+    pub fn handle_coverage(params: params::CoverageParams) -> String {
+        handlers::handle_coverage(params)
+    }
+
+    pub fn handle_check_references(params: params::CheckReferencesParams) -> String {
+        handlers::handle_check_references(params)
+    }
+
+    pub fn handle_get_references(params: params::GetReferencesParams) -> String {
+        handlers::handle_get_references(params)
+    }
+
+    pub fn find_anchor_links(line: &str) -> Vec<String> {
+        graph::find_anchor_links(line)
+    }
+
+    pub fn validate_relations(relations: &params::RelationsParam) -> Result<(), String> {
+        validation::validate_relations(relations)
+    }
+
+    pub fn handle_impact(params: params::ImpactParams) -> String {
+        handlers::handle_impact(params)
+    }
+
+    pub fn handle_check_links(params: params::CheckLinksParams) -> String {
+        handlers::handle_check_links(params)
+    }
+
+    pub fn validate_mappings(mappings: &[params::IndexMapping]) -> Result<(), String> {
+        validation::validate_mappings(mappings)
+    }
+
+    pub fn validate_file(value: &str) -> Result<(), String> {
+        validation::validate_file(value)
+    }
+
+    pub fn handle_rewrite_references(params: params::RewriteReferencesParams) -> String {
+        handlers::handle_rewrite_references(params)
+    }
+
+    pub fn handle_test_map(params: params::TestMapParams) -> String {
+        handlers::handle_test_map(params)
+    }
+
+    pub fn handle_import_test_results(params: params::ImportTestResultsParams) -> String {
+        handlers::handle_import_test_results(params)
+    }
+
+    pub fn handle_export_matrix(params: params::ExportMatrixParams) -> String {
+        handlers::handle_export_matrix(params)
+    }
+
+    pub fn handle_export_graph(params: params::ExportGraphParams) -> String {
+        handlers::handle_export_graph(params)
+    }
+
+    pub fn validate_radius(value: usize) -> Result<(), String> {
+        validation::validate_radius(value)
+    }
+
+    pub fn validate_output(value: &str) -> Result<(), String> {
+        validation::validate_output(value)
+    }
+
+    pub fn validate_diff(value: &str) -> Result<(), String> {
+        validation::validate_diff(value)
+    }
+
+    pub fn validate_feature(value: &str) -> Result<(), String> {
+        validation::validate_feature(value)
+    }
 }
 
 // ServerHandler implementation is in server.rs module
@@ -0,0 +1,265 @@
+// Markdown anchor links in the requirements directory (G.CR.4, G.CR.5, G.TOOLREQLIXCHECKL.3)
+
+use crate::filesystem::read_file_utf8;
+use crate::graph::github_slug;
+use crate::models::{BrokenLink, LinkReport};
+use crate::parsing::parse_level2_heading;
+use regex::Regex;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+/// Markdown inline link destination: `](destination)` (G.CR.4)
+fn link_destination_pattern() -> &'static Regex {
+    static PATTERN: OnceLock<Regex> = OnceLock::new();
+    PATTERN.get_or_init(|| Regex::new(r"\]\(([^)\s]+)\)").expect("valid link destination pattern"))
+}
+
+/// Split a destination into file path and anchor, if it is an anchor or markdown file link (G.CR.4)
+#[cfg_attr(test, allow(dead_code))]
+pub fn parse_link_destination(destination: &str) -> Option<(&str, Option<&str>)> {
+    let (path, anchor) = match destination.split_once('#') {
+        Some((path, anchor)) => (path, Some(anchor)),
+        None => (destination, None),
+    };
+    if path.contains(':') {
+        return None;
+    }
+    if path.is_empty() {
+        return anchor.map(|a| (path, Some(a)));
+    }
+    if !path.ends_with(".md") {
+        return None;
+    }
+    Some((path, anchor))
+}
+
+/// Text of an ATX heading (`#` to `######` followed by a space)
+fn heading_text(line: &str) -> Option<&str> {
+    let hashes = line.chars().take_while(|c| *c == '#').count();
+    if !(1..=6).contains(&hashes) {
+        return None;
+    }
+    line[hashes..].strip_prefix(' ').map(str::trim)
+}
+
+/// Anchors of all headings of a markdown file in file order, with GitHub duplicate suffixes (G.CR.4)
+#[cfg_attr(test, allow(dead_code))]
+pub fn heading_anchors(content: &str) -> Vec<String> {
+    let mut anchors = Vec::new();
+    let mut seen: HashMap<String, usize> = HashMap::new();
+    let mut in_code_block = false;
+    for line in content.lines() {
+        if line.trim_start().starts_with("```") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+        if in_code_block {
+            continue;
+        }
+        let Some(text) = heading_text(line) else {
+            continue;
+        };
+        let slug = github_slug(text);
+        let count = seen.entry(slug.clone()).or_insert(0);
+        anchors.push(if *count == 0 {
+            slug
+        } else {
+            format!("{}-{}", slug, count)
+        });
+        *count += 1;
+    }
+    anchors
+}
+
+/// Anchors of requirement headings by index slug, first heading wins (G.TOOLREQLIXCHECKL.3 step 5)
+fn requirement_anchors(content: &str) -> HashMap<String, String> {
+    let mut anchors = HashMap::new();
+    let mut in_code_block = false;
+    for line in content.lines() {
+        if line.trim_start().starts_with("```") {
+            in_code_block = !in_code_block;
+        }
+        if in_code_block {
+            continue;
+        }
+        if let Some((index, title)) = parse_level2_heading(line) {
+            anchors
+                .entry(github_slug(&index))
+                .or_insert_with(|| github_slug(&format!("{}: {}", index, title)));
+        }
+    }
+    anchors
+}
+
+/// Markdown files directly in the requirements directory, sorted by name (G.TOOLREQLIXCHECKL.3 step 2)
+fn markdown_files(requirements_dir: &Path) -> Result<Vec<String>, String> {
+    let entries = fs::read_dir(requirements_dir)
+        .map_err(|e| format!("Failed to read requirements directory: {}", e))?;
+    let mut files: Vec<String> = entries
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().is_file())
+        .filter_map(|e| e.file_name().to_str().map(str::to_string))
+        .filter(|name| name.ends_with(".md"))
+        .collect();
+    files.sort();
+    Ok(files)
+}
+
+/// Resolve the file a destination path refers to; an empty path is the linking file (G.TOOLREQLIXCHECKL.3 step 4)
+fn resolve_link(requirements_dir: &Path, file: &str, path: &str) -> PathBuf {
+    if path.is_empty() {
+        requirements_dir.join(file)
+    } else {
+        requirements_dir.join(path)
+    }
+}
+
+/// Resolved file identity for comparisons: canonical path when the file exists
+fn file_key(path: &Path) -> PathBuf {
+    fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+/// Lines of a markdown file with the destinations found outside code blocks
+fn link_lines(content: &str) -> Vec<(usize, Vec<String>)> {
+    let mut lines = Vec::new();
+    let mut in_code_block = false;
+    for (number, line) in content.lines().enumerate() {
+        if line.trim_start().starts_with("```") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+        if in_code_block {
+            continue;
+        }
+        let destinations: Vec<String> = link_destination_pattern()
+            .captures_iter(line)
+            .filter_map(|c| c.get(1))
+            .map(|m| m.as_str().to_string())
+            .filter(|d| parse_link_destination(d).is_some())
+            .collect();
+        if !destinations.is_empty() {
+            lines.push((number + 1, destinations));
+        }
+    }
+    lines
+}
+
+/// Anchors and requirement anchors of a linked file, or None if it does not exist
+type LinkTarget = Option<(Vec<String>, HashMap<String, String>)>;
+
+/// Find broken anchor and file links in the requirements directory (G.TOOLREQLIXCHECKL.3)
+#[cfg_attr(test, allow(dead_code))]
+pub fn find_broken_links(requirements_dir: &Path) -> Result<LinkReport, String> {
+    let files = markdown_files(requirements_dir)?;
+    let mut targets: HashMap<PathBuf, LinkTarget> = HashMap::new();
+    let mut report = LinkReport {
+        files_scanned: files.len(),
+        ..Default::default()
+    };
+
+    for file in &files {
+        let content = read_file_utf8(&requirements_dir.join(file))?;
+        for (line, destinations) in link_lines(&content) {
+            for destination in destinations {
+                report.links_checked += 1;
+                let Some((path, anchor)) = parse_link_destination(&destination) else {
+                    continue;
+                };
+
+                // G.TOOLREQLIXCHECKL.3 step 4: Check links
+                let resolved = resolve_link(requirements_dir, file, path);
+                let target = targets.entry(file_key(&resolved)).or_insert_with(|| {
+                    if !resolved.is_file() {
+                        return None;
+                    }
+                    read_file_utf8(&resolved)
+                        .ok()
+                        .map(|c| (heading_anchors(&c), requirement_anchors(&c)))
+                });
+                let (reason, suggestion) = match (&*target, anchor) {
+                    (None, _) => ("missing_file", None),
+                    (Some((anchors, by_index)), Some(anchor))
+                        if !anchors.iter().any(|a| a == anchor) =>
+                    {
+                        // G.TOOLREQLIXCHECKL.3 step 5: Suggest replacement
+                        let suggestion = anchor
+                            .split_once('-')
+                            .and_then(|(index_slug, _)| by_index.get(index_slug))
+                            .map(|a| format!("{}#{}", path, a));
+                        ("missing_anchor", suggestion)
+                    }
+                    _ => continue,
+                };
+                report.broken.push(BrokenLink {
+                    file: file.clone(),
+                    line,
+                    destination,
+                    reason: reason.to_string(),
+                    suggestion,
+                });
+            }
+        }
+    }
+
+    Ok(report)
+}
+
+/// Plan link rewrites to an anchor of a category file after a title change (G.CR.5)
+///
+/// Returns the new content of every file to write, without writing anything.
+/// The category file is planned from `category_content` (its updated content)
+/// and is always included, so callers can write it from the plan.
+pub(crate) fn plan_anchor_rewrites(
+    requirements_dir: &Path,
+    category_file: &str,
+    category_content: &str,
+    old_anchor: &str,
+    new_anchor: &str,
+) -> Result<Vec<(PathBuf, String)>, String> {
+    let category_key = file_key(&requirements_dir.join(category_file));
+    let mut plan = Vec::new();
+
+    for file in markdown_files(requirements_dir)? {
+        let path = requirements_dir.join(&file);
+        let is_category = file_key(&path) == category_key;
+        let content = if is_category {
+            category_content.to_string()
+        } else {
+            read_file_utf8(&path)?
+        };
+        let mut changed = false;
+        let mut in_code_block = false;
+        let mut lines: Vec<String> = Vec::new();
+        for line in content.split('\n') {
+            if line.trim_start().starts_with("```") {
+                in_code_block = !in_code_block;
+            }
+            if in_code_block || !line.contains(old_anchor) {
+                lines.push(line.to_string());
+                continue;
+            }
+            let new_line = link_destination_pattern().replace_all(line, |c: &regex::Captures| {
+                let destination = &c[1];
+                match parse_link_destination(destination) {
+                    Some((target, Some(anchor)))
+                        if anchor == old_anchor
+                            && file_key(&resolve_link(requirements_dir, &file, target))
+                                == category_key =>
+                    {
+                        format!("]({}#{})", target, new_anchor)
+                    }
+                    _ => c[0].to_string(),
+                }
+            });
+            changed |= new_line != line;
+            lines.push(new_line.into_owned());
+        }
+        if changed || is_category {
+            plan.push((path, lines.join("\n")));
+        }
+    }
+
+    Ok(plan)
+}
@@ -0,0 +1,212 @@
+// Requirements traceability matrix and renderings (G.TOOLREQLIXE.3 - G.TOOLREQLIXE.5)
+
+use crate::constants::VERIFICATION_FILE;
+use crate::coverage::{csv_field, markdown_cell, CoverageOptions};
+use crate::filesystem::{get_requirements_dir, read_file_utf8};
+use crate::models::{MatrixRow, TraceabilityMatrix, VerificationReport};
+use crate::trace::{build_glob_set, trace_requirements};
+use std::collections::HashMap;
+
+/// Build the traceability matrix for a project (G.TOOLREQLIXE.3 steps 2-5)
+#[cfg_attr(test, allow(dead_code))]
+pub fn traceability_matrix(
+    project_root: &str,
+    options: &CoverageOptions,
+) -> Result<TraceabilityMatrix, String> {
+    let test_files = build_glob_set("test_patterns", &options.test_patterns)?;
+
+    // G.TOOLREQLIXE.3 step 2: Trace
+    let trace = trace_requirements(project_root, &options.trace)?;
+
+    // G.TOOLREQLIXE.3 step 5: Read the last verification import
+    let verification_path = get_requirements_dir(project_root)?.join(VERIFICATION_FILE);
+    let verification = if verification_path.is_file() {
+        let content = read_file_utf8(&verification_path)?;
+        let report: VerificationReport = serde_json::from_str(&content)
+            .map_err(|e| format!("Invalid {}: {}", VERIFICATION_FILE, e))?;
+        Some(report)
+    } else {
+        None
+    };
+    let statuses: HashMap<&str, &str> = verification
+        .iter()
+        .flat_map(|v| &v.requirements)
+        .map(|r| (r.requirement.index.as_str(), r.status.as_str()))
+        .collect();
+
+    let mut matrix = TraceabilityMatrix {
+        verification_imported_at: verification.as_ref().map(|v| v.imported_at.clone()),
+        rows: Vec::new(),
+    };
+    for requirement in trace.requirements {
+        // G.TOOLREQLIXE.3 step 3: Split references
+        let mut code_references = Vec::new();
+        let mut test_references = Vec::new();
+        for location in &requirement.locations {
+            let reference = format!("{}:{}", location.file, location.line);
+            if test_files.is_match(&location.file) {
+                test_references.push(reference);
+            } else {
+                code_references.push(reference);
+            }
+        }
+
+        // G.TOOLREQLIXE.3 step 4: Status
+        let status = if !test_references.is_empty() {
+            "tested"
+        } else if !code_references.is_empty() {
+            "code_only"
+        } else {
+            "unreferenced"
+        };
+
+        let verification = statuses
+            .get(requirement.index.as_str())
+            .copied()
+            .unwrap_or("unknown")
+            .to_string();
+        matrix.rows.push(MatrixRow {
+            index: requirement.index,
+            title: requirement.title,
+            category: requirement.category,
+            chapter: requirement.chapter,
+            status: status.to_string(),
+            code_references,
+            test_references,
+            verification,
+        });
+    }
+
+    Ok(matrix)
+}
+
+/// Verification line of the markdown and HTML renderings (G.TOOLREQLIXE.5)
+fn verification_line(matrix: &TraceabilityMatrix) -> String {
+    match &matrix.verification_imported_at {
+        Some(imported_at) => format!("Verification imported at: {}", imported_at),
+        None => "Verification: not imported".to_string(),
+    }
+}
+
+/// Render the matrix as CSV (G.TOOLREQLIXE.5)
+#[cfg_attr(test, allow(dead_code))]
+pub fn render_matrix_csv(matrix: &TraceabilityMatrix) -> String {
+    let mut out = String::from(
+        "index,title,category,chapter,status,code_references,test_references,verification\n",
+    );
+    for row in &matrix.rows {
+        let fields = [
+            row.index.clone(),
+            row.title.clone(),
+            row.category.clone(),
+            row.chapter.clone(),
+            row.status.clone(),
+            row.code_references.join("; "),
+            row.test_references.join("; "),
+            row.verification.clone(),
+        ];
+        let fields: Vec<String> = fields.iter().map(|f| csv_field(f)).collect();
+        out.push_str(&fields.join(","));
+        out.push('\n');
+    }
+    out
+}
+
+/// Join references for a markdown or HTML cell, "-" if there are none (G.TOOLREQLIXE.5)
+fn references_cell(references: &[String], escape: fn(&str) -> String) -> String {
+    if references.is_empty() {
+        return "-".to_string();
+    }
+    references
+        .iter()
+        .map(|r| escape(r))
+        .collect::<Vec<_>>()
+        .join("<br>")
+}
+
+/// Render the matrix as a markdown table (G.TOOLREQLIXE.5)
+#[cfg_attr(test, allow(dead_code))]
+pub fn render_matrix_markdown(matrix: &TraceabilityMatrix) -> String {
+    let mut out = String::from("# Requirements Traceability Matrix\n\n");
+    out.push_str(&format!("{}\n\n", verification_line(matrix)));
+    out.push_str(
+        "| Index | Title | Category | Chapter | Status | Code references | Test references | Verification |\n",
+    );
+    out.push_str("|---|---|---|---|---|---|---|---|\n");
+    for row in &matrix.rows {
+        out.push_str(&format!(
+            "| {} | {} | {} | {} | {} | {} | {} | {} |\n",
+            markdown_cell(&row.index),
+            markdown_cell(&row.title),
+            markdown_cell(&row.category),
+            markdown_cell(&row.chapter),
+            row.status,
+            references_cell(&row.code_references, markdown_cell),
+            references_cell(&row.test_references, markdown_cell),
+            markdown_cell(&row.verification)
+        ));
+    }
+    out
+}
+
+/// Escape HTML text (G.TOOLREQLIXE.5)
+pub fn html_escape(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// Inline styles of the HTML rendering (G.TOOLREQLIXE.5)
+const MATRIX_HTML_STYLE: &str = "body { font-family: sans-serif; margin: 2em; }
+table { border-collapse: collapse; width: 100%; }
+th, td { border: 1px solid #ccc; padding: 4px 8px; text-align: left; vertical-align: top; }
+th { background: #f0f0f0; }
+tr.verification-verified td { background: #e6f4ea; }
+tr.verification-failing td { background: #fce8e6; }
+tr.verification-unverified td { background: #fef7e0; }";
+
+/// Render the matrix as a self-contained HTML page (G.TOOLREQLIXE.5)
+#[cfg_attr(test, allow(dead_code))]
+pub fn render_matrix_html(matrix: &TraceabilityMatrix) -> String {
+    let mut out = String::from("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n");
+    out.push_str("<meta charset=\"utf-8\">\n");
+    out.push_str("<title>Requirements Traceability Matrix</title>\n");
+    out.push_str(&format!("<style>\n{}\n</style>\n", MATRIX_HTML_STYLE));
+    out.push_str("</head>\n<body>\n");
+    out.push_str("<h1>Requirements Traceability Matrix</h1>\n");
+    out.push_str(&format!(
+        "<p>{}</p>\n",
+        html_escape(&verification_line(matrix))
+    ));
+    out.push_str(
+        "<table>\n<thead>\n<tr><th>Index</th><th>Title</th><th>Category</th><th>Chapter</th>",
+    );
+    out.push_str("<th>Status</th><th>Code references</th><th>Test references</th><th>Verification</th></tr>\n");
+    out.push_str("</thead>\n<tbody>\n");
+    for row in &matrix.rows {
+        out.push_str(&format!(
+            "<tr class=\"verification-{}\"><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td>\
+<td>{}</td><td>{}</td><td>{}</td></tr>\n",
+            html_escape(&row.verification),
+            html_escape(&row.index),
+            html_escape(&row.title),
+            html_escape(&row.category),
+            html_escape(&row.chapter),
+            row.status,
+            references_cell(&row.code_references, html_escape),
+            references_cell(&row.test_references, html_escape),
+            html_escape(&row.verification)
+        ));
+    }
+    out.push_str("</tbody>\n</table>\n</body>\n</html>\n");
+    out
+}
@@ -1,9 +1,10 @@
 // Data structures for requirements
 
-use serde::Serialize;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 
 /// A requirement with index and title (for listing)
-#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
 #[cfg_attr(test, allow(dead_code))]
 pub struct RequirementSummary {
     pub index: String,
@@ -11,16 +12,19 @@ pub struct RequirementSummary {
 }
 
 /// Deleted requirement info (for delete response) (G.TOOLREQLIXD.4)
-#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, JsonSchema, PartialEq, Eq)]
 pub struct DeletedRequirement {
     pub index: String,
     pub title: String,
     pub category: String,
     pub chapter: String,
+    /// Requirements that still linked to the deleted one (G.TOOLREQLIXD.7)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
 }
 
 /// A full requirement with all data
-#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
 #[cfg_attr(test, allow(dead_code))]
 pub struct RequirementFull {
     pub index: String,
@@ -29,3 +33,466 @@ pub struct RequirementFull {
     pub category: String,
     pub chapter: String,
 }
+
+/// Requirement location without text (for duplicate reports) (G.TOOLREQLIXF.4)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct RequirementLocation {
+    pub index: String,
+    pub title: String,
+    pub category: String,
+    pub chapter: String,
+}
+
+/// Index used by more than one requirement heading (G.TOOLREQLIXF.3, G.TOOLREQLIXF.4)
+#[derive(Debug, Clone, Serialize, JsonSchema, PartialEq, Eq)]
+pub struct DuplicateIndex {
+    pub index: String,
+    pub occurrences: Vec<RequirementLocation>,
+}
+
+/// Title used by more than one requirement (G.TOOLREQLIXF.3, G.TOOLREQLIXF.4)
+#[derive(Debug, Clone, Serialize, JsonSchema, PartialEq, Eq)]
+pub struct DuplicateTitle {
+    pub title: String,
+    pub requirements: Vec<RequirementLocation>,
+}
+
+/// Pair of requirements with similar text (G.TOOLREQLIXF.3, G.TOOLREQLIXF.4)
+#[derive(Debug, Clone, Serialize, JsonSchema, PartialEq)]
+pub struct NearDuplicate {
+    pub similarity: f64,
+    pub first: RequirementLocation,
+    pub second: RequirementLocation,
+}
+
+/// Category/chapter pair ranked for a draft requirement (G.TOOLREQLIXSU.3, G.TOOLREQLIXSU.4)
+#[derive(Debug, Clone, Serialize, JsonSchema, PartialEq)]
+pub struct LocationSuggestion {
+    pub similarity: f64,
+    pub category: String,
+    pub chapter: String,
+}
+
+/// Existing requirement ranked for a draft requirement (G.TOOLREQLIXSU.3, G.TOOLREQLIXSU.4)
+#[derive(Debug, Clone, Serialize, JsonSchema, PartialEq)]
+pub struct SimilarRequirement {
+    pub similarity: f64,
+    pub index: String,
+    pub title: String,
+    pub category: String,
+    pub chapter: String,
+}
+
+/// Inserted requirement with optional warnings (G.REQLIX_I.5, G.REQLIX_I.7)
+#[derive(Debug, Clone, Serialize, JsonSchema, PartialEq, Eq)]
+pub struct InsertedRequirement {
+    #[serde(flatten)]
+    pub requirement: RequirementFull,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+}
+
+/// Updated requirement with optional warnings (G.REQLIX_U.4, G.CR.5)
+#[derive(Debug, Clone, Serialize, JsonSchema, PartialEq, Eq)]
+pub struct UpdatedRequirement {
+    #[serde(flatten)]
+    pub requirement: RequirementFull,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+}
+
+/// Index reference found in a source file (G.TR.1, G.TR.4)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct IndexReference {
+    pub index: String,
+    pub file: String,
+    pub line: usize,
+}
+
+/// Result of a source scan: number of files read and references in scan order (G.TR.4, G.TR.5)
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct ReferenceScan {
+    pub files_scanned: usize,
+    pub references: Vec<IndexReference>,
+}
+
+/// File and line citing a requirement (G.TR.4, G.TOOLREQLIXT.4)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct CodeLocation {
+    pub file: String,
+    pub line: usize,
+}
+
+/// Requirement with the source locations that cite it (G.TOOLREQLIXT.4)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct TracedRequirement {
+    pub index: String,
+    pub title: String,
+    pub category: String,
+    pub chapter: String,
+    pub locations: Vec<CodeLocation>,
+}
+
+/// Traceability report (G.TR.5, G.TOOLREQLIXT.4)
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct TraceReport {
+    pub files_scanned: usize,
+    pub requirements: Vec<TracedRequirement>,
+}
+
+/// Requirement counts and percentages for one scope (G.TOOLREQLIXC.4, G.TOOLREQLIXC.5)
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct CoverageSummary {
+    pub requirements: usize,
+    pub referenced: usize,
+    pub tested: usize,
+    pub referenced_percent: f64,
+    pub tested_percent: f64,
+}
+
+/// Coverage of one category (G.TOOLREQLIXC.5)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct CategoryCoverage {
+    pub category: String,
+    #[serde(flatten)]
+    pub summary: CoverageSummary,
+}
+
+/// Coverage of one chapter (G.TOOLREQLIXC.5)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct ChapterCoverage {
+    pub category: String,
+    pub chapter: String,
+    #[serde(flatten)]
+    pub summary: CoverageSummary,
+}
+
+/// Coverage report (G.TOOLREQLIXC.4, G.TOOLREQLIXC.5)
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct CoverageReport {
+    pub files_scanned: usize,
+    pub total: CoverageSummary,
+    pub categories: Vec<CategoryCoverage>,
+    pub chapters: Vec<ChapterCoverage>,
+    pub unreferenced: Vec<RequirementLocation>,
+    pub untested: Vec<RequirementLocation>,
+}
+
+/// Reference to an index that is not an existing requirement (G.TOOLREQLIXCH.3, G.TOOLREQLIXCH.4)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct DanglingReference {
+    pub index: String,
+    pub file: String,
+    pub line: usize,
+    pub status: String,
+    pub category: Option<String>,
+    pub last_title: Option<String>,
+    pub replacement: Option<RequirementLocation>,
+}
+
+/// Dangling references found in the project (G.TOOLREQLIXCH.4)
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct DanglingReport {
+    pub files_scanned: usize,
+    pub references: Vec<DanglingReference>,
+}
+
+/// Requirement with its outgoing links and backlinks (G.CR.3, G.TOOLREQLIXGETREF.4)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct RequirementReferences {
+    #[serde(flatten)]
+    pub requirement: RequirementLocation,
+    pub outgoing: Vec<RequirementLocation>,
+    pub backlinks: Vec<RequirementLocation>,
+}
+
+/// Requirement affected by a change, with the relation that reached it (G.TOOLREQLIXIM.3, G.TOOLREQLIXIM.4)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct ImpactedRequirement {
+    #[serde(flatten)]
+    pub requirement: RequirementLocation,
+    pub relation: String,
+    pub via: String,
+    pub depth: usize,
+}
+
+/// Impact of a change to a requirement (G.TOOLREQLIXIM.4)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct ImpactReport {
+    #[serde(flatten)]
+    pub requirement: RequirementLocation,
+    pub affected: Vec<ImpactedRequirement>,
+    pub files_scanned: usize,
+    pub locations: Vec<IndexReference>,
+}
+
+/// Broken markdown link in the requirements directory (G.TOOLREQLIXCHECKL.4)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct BrokenLink {
+    pub file: String,
+    pub line: usize,
+    pub destination: String,
+    pub reason: String,
+    pub suggestion: Option<String>,
+}
+
+/// Result of checking markdown links in the requirements directory (G.TOOLREQLIXCHECKL.4)
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct LinkReport {
+    pub files_scanned: usize,
+    pub links_checked: usize,
+    pub broken: Vec<BrokenLink>,
+}
+
+/// One rewritten line (G.TOOLREQLIXR.4)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct ReferenceChange {
+    pub file: String,
+    pub line: usize,
+    pub before: String,
+    pub after: String,
+}
+
+/// Result of rewriting index references (G.TOOLREQLIXR.4)
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct RewriteReport {
+    pub dry_run: bool,
+    pub files_changed: usize,
+    pub replacements: usize,
+    pub changes: Vec<ReferenceChange>,
+    pub diff: String,
+}
+
+/// Test function with the requirements it covers (G.TOOLREQLIXTE.4)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct TestFunction {
+    pub name: String,
+    pub file: String,
+    pub line: usize,
+    pub requirements: Vec<String>,
+}
+
+/// Test-to-requirement map with untested requirements and unreferenced tests (G.TOOLREQLIXTE.4)
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct TestMap {
+    pub files_scanned: usize,
+    pub tests: Vec<TestFunction>,
+    pub unreferenced_tests: Vec<TestFunction>,
+    pub untested_requirements: Vec<RequirementLocation>,
+}
+
+/// Result of one test in imported test results (G.TOOLREQLIXIMPO.3)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct TestOutcome {
+    pub name: String,
+    pub result: String,
+}
+
+/// Test linked to a requirement with its imported result (G.TOOLREQLIXIMPO.4)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct VerifiedTest {
+    pub name: String,
+    pub file: String,
+    pub line: usize,
+    pub result: String,
+}
+
+/// Verification status of a requirement (G.TOOLREQLIXIMPO.4)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct RequirementVerification {
+    #[serde(flatten)]
+    pub requirement: RequirementLocation,
+    pub status: String,
+    pub tests: Vec<VerifiedTest>,
+}
+
+/// Number of requirements per verification status (G.TOOLREQLIXIMPO.4)
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct VerificationSummary {
+    pub verified: usize,
+    pub failing: usize,
+    pub unverified: usize,
+}
+
+/// Imported test results with the verification status of every requirement (G.TOOLREQLIXIMPO.4)
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct VerificationReport {
+    pub imported_at: String,
+    pub file: String,
+    pub format: String,
+    pub tests_found: usize,
+    pub tests_matched: usize,
+    pub summary: VerificationSummary,
+    pub requirements: Vec<RequirementVerification>,
+}
+
+/// One requirement of the traceability matrix (G.TOOLREQLIXE.3, G.TOOLREQLIXE.4)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct MatrixRow {
+    pub index: String,
+    pub title: String,
+    pub category: String,
+    pub chapter: String,
+    pub status: String,
+    pub code_references: Vec<String>,
+    pub test_references: Vec<String>,
+    pub verification: String,
+}
+
+/// Requirements traceability matrix (G.TOOLREQLIXE.4)
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct TraceabilityMatrix {
+    pub verification_imported_at: Option<String>,
+    pub rows: Vec<MatrixRow>,
+}
+
+/// Resource listed by resources/list (G.M.3)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct ResourceInfo {
+    pub uri: String,
+    pub name: String,
+    pub title: String,
+    pub description: String,
+    pub mime_type: String,
+}
+
+/// Content returned by resources/read (G.M.2)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct ResourceContent {
+    pub uri: String,
+    pub mime_type: String,
+    pub text: String,
+}
+
+/// Resources changed between two states of the requirements directory (G.M.7)
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct ResourceChanges {
+    pub updated: Vec<String>,
+    pub list_changed: bool,
+}
+
+/// Argument of a prompt (G.MCPP.1)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct PromptArgumentInfo {
+    pub name: String,
+    pub description: String,
+    pub required: bool,
+}
+
+/// Entry of prompts/list (G.MCPP.1)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct PromptInfo {
+    pub name: String,
+    pub title: String,
+    pub description: String,
+    pub arguments: Vec<PromptArgumentInfo>,
+}
+
+/// Result of prompts/get: description and the text of the user message (G.MCPP.3)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct PromptContent {
+    pub description: String,
+    pub text: String,
+}
+
+/// Response of every tool: `data` on success, `error` on failure (G.C.5, G.C.6, G.S.2)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct ToolResponse<T> {
+    pub success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Data of a tool that accepts one item or a batch (G.REQLIX_GET_REQUIREMENT.4, G.REQLIX_U.4, G.TOOLREQLIXD.4)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+#[serde(untagged)]
+pub enum SingleOrBatch<T> {
+    Single(T),
+    Batch(Vec<ToolResponse<T>>),
+}
+
+/// Data of reqlix_get_instructions (G.REQLIX_GET_I.8)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct Instructions {
+    pub content: String,
+}
+
+/// Data of reqlix_get_categories (G.REQLIX_GET_CA.3)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct CategoryList {
+    pub categories: Vec<String>,
+}
+
+/// Data of reqlix_get_chapters (G.REQLIX_GET_CH.4)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct ChapterList {
+    pub category: String,
+    pub chapters: Vec<String>,
+}
+
+/// Data of reqlix_get_requirements (G.REQLIX_GET_REQUIREMENTS.4)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct ChapterRequirements {
+    pub category: String,
+    pub chapter: String,
+    pub requirements: Vec<RequirementSummary>,
+}
+
+/// Data of reqlix_get_version (G.TOOLREQLIXGETV.2)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct VersionInfo {
+    pub version: String,
+}
+
+/// Data of reqlix_search_requirements (G.TOOLREQLIXS.4)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct SearchResults {
+    pub keywords: Vec<String>,
+    pub results: Vec<RequirementFull>,
+}
+
+/// Data of reqlix_find_duplicates (G.TOOLREQLIXF.4)
+#[derive(Debug, Clone, Serialize, JsonSchema, PartialEq)]
+pub struct DuplicateReport {
+    pub threshold: f64,
+    pub duplicate_indices: Vec<DuplicateIndex>,
+    pub duplicate_titles: Vec<DuplicateTitle>,
+    pub near_duplicates: Vec<NearDuplicate>,
+}
+
+/// Data of reqlix_suggest_location (G.TOOLREQLIXSU.4)
+#[derive(Debug, Clone, Serialize, JsonSchema, PartialEq)]
+pub struct LocationSuggestions {
+    pub locations: Vec<LocationSuggestion>,
+    pub similar_requirements: Vec<SimilarRequirement>,
+}
+
+/// Data of reqlix_coverage: the report and its rendering (G.TOOLREQLIXC.5)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct RenderedCoverage {
+    #[serde(flatten)]
+    pub report: CoverageReport,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rendered: Option<String>,
+}
+
+/// Data of reqlix_export_matrix (G.TOOLREQLIXE.4)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct MatrixExport {
+    pub format: String,
+    pub verification_imported_at: Option<String>,
+    pub rows: Vec<MatrixRow>,
+    pub rendered: String,
+    pub output: Option<String>,
+}
+
+/// Data of reqlix_export_graph (G.TOOLREQLIXEXPORTG.4)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct GraphExport {
+    pub format: String,
+    pub nodes: usize,
+    pub edges: usize,
+    pub rendered: String,
+}
@@ -1,47 +1,65 @@
 // Parameter structures (G.REQLIX_GET_I.2, G.REQLIX_GET_CA.2, G.REQLIX_GET_CH.2, G.REQLIX_GET_REQUIREMENTS.2, G.REQLIX_GET_REQUIREMENT.2, G.REQLIX_I.2, G.REQLIX_U.2)
 
+use crate::constants::*;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Parameters for reqlix_get_instructions (G.REQLIX_GET_I.2)
 #[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct GetInstructionsParams {
-    /// Path to the project root directory.
+    /// Path to the project root directory. Optional when the server has a default project root or the client provides a single root.
+    #[serde(default)]
+    #[schemars(length(min = 1, max = MAX_PROJECT_ROOT_LEN))]
     pub project_root: String,
     /// Brief description of the operation that LLM intends to perform.
+    #[schemars(length(min = 1, max = MAX_OPERATION_DESC_LEN))]
     pub operation_description: String,
+    /// Create the requirements tree in project_root even if it lies inside a git repository, instead of the repository root. Default: false.
+    pub nested: Option<bool>,
 }
 
 /// Parameters for reqlix_get_categories (G.REQLIX_GET_CA.2)
 #[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct GetCategoriesParams {
-    /// Path to the project root directory.
+    /// Path to the project root directory. Optional when the server has a default project root or the client provides a single root.
+    #[serde(default)]
+    #[schemars(length(min = 1, max = MAX_PROJECT_ROOT_LEN))]
     pub project_root: String,
     /// Brief description of the operation that LLM intends to perform.
+    #[schemars(length(min = 1, max = MAX_OPERATION_DESC_LEN))]
     pub operation_description: String,
 }
 
 /// Parameters for reqlix_get_chapters (G.REQLIX_GET_CH.2)
 #[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct GetChaptersParams {
-    /// Path to the project root directory.
+    /// Path to the project root directory. Optional when the server has a default project root or the client provides a single root.
+    #[serde(default)]
+    #[schemars(length(min = 1, max = MAX_PROJECT_ROOT_LEN))]
     pub project_root: String,
     /// Brief description of the operation that LLM intends to perform.
+    #[schemars(length(min = 1, max = MAX_OPERATION_DESC_LEN))]
     pub operation_description: String,
     /// Category key (e.g., "general", "testing").
+    #[schemars(length(min = 1, max = MAX_CATEGORY_LEN), pattern(CATEGORY_PATTERN))]
     pub category: String,
 }
 
 /// Parameters for reqlix_get_requirements (G.REQLIX_GET_REQUIREMENTS.2)
 #[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct GetRequirementsParams {
-    /// Path to the project root directory.
+    /// Path to the project root directory. Optional when the server has a default project root or the client provides a single root.
+    #[serde(default)]
+    #[schemars(length(min = 1, max = MAX_PROJECT_ROOT_LEN))]
     pub project_root: String,
     /// Brief description of the operation that LLM intends to perform.
+    #[schemars(length(min = 1, max = MAX_OPERATION_DESC_LEN))]
     pub operation_description: String,
     /// Category key (e.g., "general", "testing").
+    #[schemars(length(min = 1, max = MAX_CATEGORY_LEN), pattern(CATEGORY_PATTERN))]
     pub category: String,
     /// Chapter name (e.g., "General Requirements", "Unit Tests").
+    #[schemars(length(min = 1, max = MAX_CHAPTER_LEN), pattern(CHAPTER_PATTERN))]
     pub chapter: String,
 }
 
@@ -50,69 +68,143 @@ pub struct GetRequirementsParams {
 #[serde(untagged)]
 pub enum IndexParam {
     /// Single index (e.g., "G.G.1")
-    Single(String),
+    Single(#[schemars(length(min = 1, max = MAX_INDEX_LEN))] String),
     /// Array of indices for batch operations (max 100)
-    Batch(Vec<String>),
+    Batch(
+        #[schemars(
+            length(max = MAX_BATCH_SIZE),
+            inner(length(min = 1, max = MAX_INDEX_LEN))
+        )]
+        Vec<String>,
+    ),
 }
 
 /// Parameters for reqlix_get_requirement (G.REQLIX_GET_REQUIREMENT.2)
 #[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct GetRequirementParams {
-    /// Path to the project root directory.
+    /// Path to the project root directory. Optional when the server has a default project root or the client provides a single root.
+    #[serde(default)]
+    #[schemars(length(min = 1, max = MAX_PROJECT_ROOT_LEN))]
     pub project_root: String,
     /// Brief description of the operation that LLM intends to perform.
+    #[schemars(length(min = 1, max = MAX_OPERATION_DESC_LEN))]
     pub operation_description: String,
     /// Requirement index or array of indices (max 100). Example: "G.G.1" or ["G.G.1", "G.G.2", "T.U.1"].
+    /// The array limit is the built-in maximum; reqlix.toml may set a lower one.
     pub index: IndexParam,
 }
 
+/// Typed relations to other requirements (G.RELA.1, G.RELA.4)
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct RelationsParam {
+    /// Indices of requirements this requirement refines.
+    #[schemars(
+        length(max = MAX_BATCH_SIZE),
+        inner(length(min = 1, max = MAX_INDEX_LEN))
+    )]
+    pub refines: Option<Vec<String>>,
+    /// Indices of requirements this requirement depends on.
+    #[schemars(
+        length(max = MAX_BATCH_SIZE),
+        inner(length(min = 1, max = MAX_INDEX_LEN))
+    )]
+    pub depends_on: Option<Vec<String>>,
+    /// Indices of requirements this requirement conflicts with.
+    #[schemars(
+        length(max = MAX_BATCH_SIZE),
+        inner(length(min = 1, max = MAX_INDEX_LEN))
+    )]
+    pub conflicts_with: Option<Vec<String>>,
+    /// Indices of requirements this requirement verifies.
+    #[schemars(
+        length(max = MAX_BATCH_SIZE),
+        inner(length(min = 1, max = MAX_INDEX_LEN))
+    )]
+    pub verifies: Option<Vec<String>>,
+    /// Indices of requirements this requirement is derived from.
+    #[schemars(
+        length(max = MAX_BATCH_SIZE),
+        inner(length(min = 1, max = MAX_INDEX_LEN))
+    )]
+    pub derived_from: Option<Vec<String>>,
+}
+
 /// Parameters for reqlix_insert_requirement (G.REQLIX_I.2)
 #[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct InsertRequirementParams {
-    /// Path to the project root directory.
+    /// Path to the project root directory. Optional when the server has a default project root or the client provides a single root.
+    #[serde(default)]
+    #[schemars(length(min = 1, max = MAX_PROJECT_ROOT_LEN))]
     pub project_root: String,
     /// Brief description of the operation that LLM intends to perform.
+    #[schemars(length(min = 1, max = MAX_OPERATION_DESC_LEN))]
     pub operation_description: String,
     /// Category key (e.g., "general", "testing").
+    #[schemars(length(min = 1, max = MAX_CATEGORY_LEN), pattern(CATEGORY_PATTERN))]
     pub category: String,
     /// Chapter name (e.g., "General Requirements", "Unit Tests").
+    #[schemars(length(min = 1, max = MAX_CHAPTER_LEN), pattern(CHAPTER_PATTERN))]
     pub chapter: String,
-    /// Requirement text (body content).
+    /// Requirement text (body content). The schema limit is the built-in maximum; reqlix.toml may set a lower one.
+    #[schemars(length(min = 1, max = MAX_TEXT_LEN))]
     pub text: String,
     /// Requirement title (required). A concise name that reflects the essence of the requirement.
-    /// Must be generated by the LLM and be unique within the chapter.
+    /// Must be generated by the LLM and be unique within the chapter. The schema limit is the built-in maximum; reqlix.toml may set a lower one.
+    #[schemars(length(min = 1, max = MAX_TITLE_LEN))]
     pub title: String,
+    /// Warn when a newly created chapter name is close to an existing chapter name. Default: true.
+    pub warn_similar_chapter: Option<bool>,
+    /// Typed relations to existing requirements, stored as metadata lines in the text (optional).
+    pub relations: Option<RelationsParam>,
 }
 
 /// Single update item for batch operations (G.REQLIX_U.2)
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct UpdateItem {
     /// Requirement index (e.g., "G.G.1", "T.U.2").
+    #[schemars(length(min = 1, max = MAX_INDEX_LEN))]
     pub index: String,
-    /// New requirement text (body content).
+    /// New requirement text (body content). The schema limit is the built-in maximum; reqlix.toml may set a lower one.
+    #[schemars(length(min = 1, max = MAX_TEXT_LEN))]
     pub text: String,
-    /// New requirement title (optional). If provided, must be unique within the chapter.
+    /// New requirement title (optional). If provided, must be unique within the chapter. The schema limit is the built-in maximum; reqlix.toml may set a lower one.
+    #[schemars(length(max = MAX_TITLE_LEN))]
     pub title: Option<String>,
+    /// Typed relations (optional). If provided, replace the relation lines in the text.
+    pub relations: Option<RelationsParam>,
 }
 
 /// Parameters for reqlix_update_requirement (G.REQLIX_U.2)
 /// Supports single update (index+text+title) or batch update (items array).
 #[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct UpdateRequirementParams {
-    /// Path to the project root directory.
+    /// Path to the project root directory. Optional when the server has a default project root or the client provides a single root.
+    #[serde(default)]
+    #[schemars(length(min = 1, max = MAX_PROJECT_ROOT_LEN))]
     pub project_root: String,
     /// Brief description of the operation that LLM intends to perform.
+    #[schemars(length(min = 1, max = MAX_OPERATION_DESC_LEN))]
     pub operation_description: String,
     /// Requirement index for single update (e.g., "G.G.1", "T.U.2").
     /// Use either index+text+title for single update OR items for batch update.
+    #[schemars(length(min = 1, max = MAX_INDEX_LEN))]
     pub index: Option<String>,
-    /// New requirement text for single update (body content).
+    /// New requirement text for single update (body content). The schema limit is the built-in maximum; reqlix.toml may set a lower one.
+    #[schemars(length(min = 1, max = MAX_TEXT_LEN))]
     pub text: Option<String>,
-    /// New requirement title for single update (optional).
+    /// New requirement title for single update (optional). The schema limit is the built-in maximum; reqlix.toml may set a lower one.
+    #[schemars(length(max = MAX_TITLE_LEN))]
     pub title: Option<String>,
+    /// Typed relations for single update (optional). If provided, replace the relation lines in the text.
+    pub relations: Option<RelationsParam>,
     /// Array of update objects for batch update (max 100).
     /// Use either index+text+title for single update OR items for batch update.
+    /// The schema limit is the built-in maximum; reqlix.toml may set a lower one.
+    #[schemars(length(max = MAX_BATCH_SIZE))]
     pub items: Option<Vec<UpdateItem>>,
+    /// Rewrite links to the old anchor when a title changes (optional). Default: false.
+    pub rewrite_links: Option<bool>,
 }
 
 /// Parameters for reqlix_get_version (G.TOOLREQLIXGETV.3 - no parameters)
@@ -122,12 +214,18 @@ pub struct GetVersionParams {}
 /// Parameters for reqlix_delete_requirement (G.TOOLREQLIXD.2)
 #[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct DeleteRequirementParams {
-    /// Path to the project root directory.
+    /// Path to the project root directory. Optional when the server has a default project root or the client provides a single root.
+    #[serde(default)]
+    #[schemars(length(min = 1, max = MAX_PROJECT_ROOT_LEN))]
     pub project_root: String,
     /// Brief description of the operation that LLM intends to perform.
+    #[schemars(length(min = 1, max = MAX_OPERATION_DESC_LEN))]
     pub operation_description: String,
     /// Requirement index or array of indices to delete (max 100). Example: "G.G.1" or ["G.G.1", "G.G.2"].
+    /// The array limit is the built-in maximum; reqlix.toml may set a lower one.
     pub index: IndexParam,
+    /// Delete even if other requirements still link to the requirement. Default: false.
+    pub force: Option<bool>,
 }
 
 /// Keywords parameter that can be a single string or array of strings (G.TOOLREQLIXS.2)
@@ -135,19 +233,293 @@ pub struct DeleteRequirementParams {
 #[serde(untagged)]
 pub enum KeywordsParam {
     /// Single keyword (e.g., "auth")
-    Single(String),
+    Single(#[schemars(length(max = MAX_KEYWORD_LEN))] String),
     /// Array of keywords for search (max 100)
-    Batch(Vec<String>),
+    Batch(
+        #[schemars(length(max = MAX_BATCH_SIZE), inner(length(max = MAX_KEYWORD_LEN)))] Vec<String>,
+    ),
 }
 
 /// Parameters for reqlix_search_requirements (G.TOOLREQLIXS.2)
 #[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct SearchRequirementsParams {
-    /// Path to the project root directory.
+    /// Path to the project root directory. Optional when the server has a default project root or the client provides a single root.
+    #[serde(default)]
+    #[schemars(length(min = 1, max = MAX_PROJECT_ROOT_LEN))]
     pub project_root: String,
     /// Brief description of the operation that LLM intends to perform.
+    #[schemars(length(min = 1, max = MAX_OPERATION_DESC_LEN))]
     pub operation_description: String,
     /// Single keyword (max 200 characters) or array of keywords (0 to 100 elements, each max 200 characters).
     /// Example: "auth" or ["auth", "user", "login"].
     pub keywords: KeywordsParam,
 }
+
+/// Parameters for reqlix_find_duplicates (G.TOOLREQLIXF.2)
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct FindDuplicatesParams {
+    /// Path to the project root directory. Optional when the server has a default project root or the client provides a single root.
+    #[serde(default)]
+    #[schemars(length(min = 1, max = MAX_PROJECT_ROOT_LEN))]
+    pub project_root: String,
+    /// Brief description of the operation that LLM intends to perform.
+    #[schemars(length(min = 1, max = MAX_OPERATION_DESC_LEN))]
+    pub operation_description: String,
+    /// Minimum TF-IDF cosine similarity (0.0 to 1.0) for two requirement texts to be reported as
+    /// near-duplicates. Default: 0.8.
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub threshold: Option<f64>,
+}
+
+/// Parameters for reqlix_suggest_location (G.TOOLREQLIXSU.2)
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct SuggestLocationParams {
+    /// Path to the project root directory. Optional when the server has a default project root or the client provides a single root.
+    #[serde(default)]
+    #[schemars(length(min = 1, max = MAX_PROJECT_ROOT_LEN))]
+    pub project_root: String,
+    /// Brief description of the operation that LLM intends to perform.
+    #[schemars(length(min = 1, max = MAX_OPERATION_DESC_LEN))]
+    pub operation_description: String,
+    /// Draft requirement title. The schema limit is the built-in maximum; reqlix.toml may set a lower one.
+    #[schemars(length(min = 1, max = MAX_TITLE_LEN))]
+    pub title: String,
+    /// Draft requirement text (body content). The schema limit is the built-in maximum; reqlix.toml may set a lower one.
+    #[schemars(length(min = 1, max = MAX_TEXT_LEN))]
+    pub text: String,
+    /// Maximum number of entries in each result list (1 to 100). Default: 5.
+    #[schemars(range(min = 1, max = MAX_SUGGESTION_LIMIT))]
+    pub limit: Option<usize>,
+}
+
+/// Parameters for reqlix_trace (G.TOOLREQLIXT.2)
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct TraceParams {
+    /// Path to the project root directory. Optional when the server has a default project root or the client provides a single root.
+    #[serde(default)]
+    #[schemars(length(min = 1, max = MAX_PROJECT_ROOT_LEN))]
+    pub project_root: String,
+    /// Brief description of the operation that LLM intends to perform.
+    #[schemars(length(min = 1, max = MAX_OPERATION_DESC_LEN))]
+    pub operation_description: String,
+    /// Glob patterns of files to scan, relative to project_root (e.g., ["src/**/*.rs"]). Default: all files.
+    #[schemars(length(max = MAX_BATCH_SIZE), inner(length(max = MAX_GLOB_LEN)))]
+    pub include: Option<Vec<String>>,
+    /// Glob patterns of files and directories to skip, relative to project_root (e.g., ["target"]).
+    #[schemars(length(max = MAX_BATCH_SIZE), inner(length(max = MAX_GLOB_LEN)))]
+    pub exclude: Option<Vec<String>>,
+}
+
+/// Parameters for reqlix_coverage (G.TOOLREQLIXC.2)
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct CoverageParams {
+    /// Path to the project root directory. Optional when the server has a default project root or the client provides a single root.
+    #[serde(default)]
+    #[schemars(length(min = 1, max = MAX_PROJECT_ROOT_LEN))]
+    pub project_root: String,
+    /// Brief description of the operation that LLM intends to perform.
+    #[schemars(length(min = 1, max = MAX_OPERATION_DESC_LEN))]
+    pub operation_description: String,
+    /// Glob patterns of files to scan, relative to project_root (e.g., ["src/**/*.rs"]). Default: all files.
+    #[schemars(length(max = MAX_BATCH_SIZE), inner(length(max = MAX_GLOB_LEN)))]
+    pub include: Option<Vec<String>>,
+    /// Glob patterns of files and directories to skip, relative to project_root (e.g., ["target"]).
+    #[schemars(length(max = MAX_BATCH_SIZE), inner(length(max = MAX_GLOB_LEN)))]
+    pub exclude: Option<Vec<String>>,
+    /// Glob patterns of test files (e.g., ["tests/**"]). Default: common test file and directory names.
+    #[schemars(length(max = MAX_BATCH_SIZE), inner(length(max = MAX_GLOB_LEN)))]
+    pub test_patterns: Option<Vec<String>>,
+    /// Output format: "json" (default), "markdown" or "csv". Markdown and CSV add a "rendered" field.
+    pub format: Option<String>,
+}
+
+/// Parameters for reqlix_check_references (G.TOOLREQLIXCH.2)
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct CheckReferencesParams {
+    /// Path to the project root directory. Optional when the server has a default project root or the client provides a single root.
+    #[serde(default)]
+    #[schemars(length(min = 1, max = MAX_PROJECT_ROOT_LEN))]
+    pub project_root: String,
+    /// Brief description of the operation that LLM intends to perform.
+    #[schemars(length(min = 1, max = MAX_OPERATION_DESC_LEN))]
+    pub operation_description: String,
+    /// Glob patterns of files to scan, relative to project_root (e.g., ["src/**/*.rs"]). Default: all files.
+    #[schemars(length(max = MAX_BATCH_SIZE), inner(length(max = MAX_GLOB_LEN)))]
+    pub include: Option<Vec<String>>,
+    /// Glob patterns of files and directories to skip, relative to project_root (e.g., ["target"]).
+    #[schemars(length(max = MAX_BATCH_SIZE), inner(length(max = MAX_GLOB_LEN)))]
+    pub exclude: Option<Vec<String>>,
+}
+
+/// Parameters for reqlix_get_references (G.TOOLREQLIXGETREF.2)
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct GetReferencesParams {
+    /// Path to the project root directory. Optional when the server has a default project root or the client provides a single root.
+    #[serde(default)]
+    #[schemars(length(min = 1, max = MAX_PROJECT_ROOT_LEN))]
+    pub project_root: String,
+    /// Brief description of the operation that LLM intends to perform.
+    #[schemars(length(min = 1, max = MAX_OPERATION_DESC_LEN))]
+    pub operation_description: String,
+    /// Requirement index (e.g., "G.R.9").
+    #[schemars(length(min = 1, max = MAX_INDEX_LEN))]
+    pub index: String,
+}
+
+/// Parameters for reqlix_impact (G.TOOLREQLIXIM.2)
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct ImpactParams {
+    /// Path to the project root directory. Optional when the server has a default project root or the client provides a single root.
+    #[serde(default)]
+    #[schemars(length(min = 1, max = MAX_PROJECT_ROOT_LEN))]
+    pub project_root: String,
+    /// Brief description of the operation that LLM intends to perform.
+    #[schemars(length(min = 1, max = MAX_OPERATION_DESC_LEN))]
+    pub operation_description: String,
+    /// Index of the requirement to change (e.g., "G.C.2").
+    #[schemars(length(min = 1, max = MAX_INDEX_LEN))]
+    pub index: String,
+    /// Glob patterns of files to scan, relative to project_root (e.g., ["src/**/*.rs"]). Default: all files.
+    #[schemars(length(max = MAX_BATCH_SIZE), inner(length(max = MAX_GLOB_LEN)))]
+    pub include: Option<Vec<String>>,
+    /// Glob patterns of files and directories to skip, relative to project_root (e.g., ["target"]).
+    #[schemars(length(max = MAX_BATCH_SIZE), inner(length(max = MAX_GLOB_LEN)))]
+    pub exclude: Option<Vec<String>>,
+}
+
+/// Parameters for reqlix_check_links (G.TOOLREQLIXCHECKL.2)
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct CheckLinksParams {
+    /// Path to the project root directory. Optional when the server has a default project root or the client provides a single root.
+    #[serde(default)]
+    #[schemars(length(min = 1, max = MAX_PROJECT_ROOT_LEN))]
+    pub project_root: String,
+    /// Brief description of the operation that LLM intends to perform.
+    #[schemars(length(min = 1, max = MAX_OPERATION_DESC_LEN))]
+    pub operation_description: String,
+}
+
+/// Old and new index of a moved requirement (G.TOOLREQLIXR.2)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct IndexMapping {
+    /// Old index (e.g., "G.R.1").
+    #[schemars(length(min = 1, max = MAX_INDEX_LEN))]
+    pub from: String,
+    /// New index (e.g., "G.S.1").
+    #[schemars(length(min = 1, max = MAX_INDEX_LEN))]
+    pub to: String,
+}
+
+/// Parameters for reqlix_rewrite_references (G.TOOLREQLIXR.2)
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct RewriteReferencesParams {
+    /// Path to the project root directory. Optional when the server has a default project root or the client provides a single root.
+    #[serde(default)]
+    #[schemars(length(min = 1, max = MAX_PROJECT_ROOT_LEN))]
+    pub project_root: String,
+    /// Brief description of the operation that LLM intends to perform.
+    #[schemars(length(min = 1, max = MAX_OPERATION_DESC_LEN))]
+    pub operation_description: String,
+    /// Old to new index mappings (max 100), applied at the same time.
+    #[schemars(length(max = MAX_BATCH_SIZE))]
+    pub mappings: Vec<IndexMapping>,
+    /// If true, return the changes without writing files (optional). Default: false.
+    pub dry_run: Option<bool>,
+    /// Glob patterns of source files to rewrite, relative to project_root (e.g., ["src/**/*.rs"]). Default: all files.
+    #[schemars(length(max = MAX_BATCH_SIZE), inner(length(max = MAX_GLOB_LEN)))]
+    pub include: Option<Vec<String>>,
+    /// Glob patterns of files and directories to skip, relative to project_root (e.g., ["target"]).
+    #[schemars(length(max = MAX_BATCH_SIZE), inner(length(max = MAX_GLOB_LEN)))]
+    pub exclude: Option<Vec<String>>,
+}
+
+/// Parameters for reqlix_test_map (G.TOOLREQLIXTE.2)
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct TestMapParams {
+    /// Path to the project root directory. Optional when the server has a default project root or the client provides a single root.
+    #[serde(default)]
+    #[schemars(length(min = 1, max = MAX_PROJECT_ROOT_LEN))]
+    pub project_root: String,
+    /// Brief description of the operation that LLM intends to perform.
+    #[schemars(length(min = 1, max = MAX_OPERATION_DESC_LEN))]
+    pub operation_description: String,
+    /// Glob patterns of files to scan, relative to project_root (e.g., ["tests/**"]). Default: all files.
+    #[schemars(length(max = MAX_BATCH_SIZE), inner(length(max = MAX_GLOB_LEN)))]
+    pub include: Option<Vec<String>>,
+    /// Glob patterns of files and directories to skip, relative to project_root (e.g., ["target"]).
+    #[schemars(length(max = MAX_BATCH_SIZE), inner(length(max = MAX_GLOB_LEN)))]
+    pub exclude: Option<Vec<String>>,
+}
+
+/// Parameters for reqlix_import_test_results (G.TOOLREQLIXIMPO.2)
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct ImportTestResultsParams {
+    /// Path to the project root directory. Optional when the server has a default project root or the client provides a single root.
+    #[serde(default)]
+    #[schemars(length(min = 1, max = MAX_PROJECT_ROOT_LEN))]
+    pub project_root: String,
+    /// Brief description of the operation that LLM intends to perform.
+    #[schemars(length(min = 1, max = MAX_OPERATION_DESC_LEN))]
+    pub operation_description: String,
+    /// Path of the test results file, relative to project_root (e.g., "target/junit.xml").
+    #[schemars(length(min = 1, max = MAX_FILE_PATH_LEN))]
+    pub file: String,
+    /// Results format: "junit" or "libtest" (optional). Default: detected from the file content.
+    pub format: Option<String>,
+    /// Glob patterns of files to scan for tests, relative to project_root (e.g., ["tests/**"]). Default: all files.
+    #[schemars(length(max = MAX_BATCH_SIZE), inner(length(max = MAX_GLOB_LEN)))]
+    pub include: Option<Vec<String>>,
+    /// Glob patterns of files and directories to skip, relative to project_root (e.g., ["target"]).
+    #[schemars(length(max = MAX_BATCH_SIZE), inner(length(max = MAX_GLOB_LEN)))]
+    pub exclude: Option<Vec<String>>,
+}
+
+/// Parameters for reqlix_export_matrix (G.TOOLREQLIXE.2)
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct ExportMatrixParams {
+    /// Path to the project root directory. Optional when the server has a default project root or the client provides a single root.
+    #[serde(default)]
+    #[schemars(length(min = 1, max = MAX_PROJECT_ROOT_LEN))]
+    pub project_root: String,
+    /// Brief description of the operation that LLM intends to perform.
+    #[schemars(length(min = 1, max = MAX_OPERATION_DESC_LEN))]
+    pub operation_description: String,
+    /// Matrix format: "csv", "markdown" or "html" (self-contained static page).
+    pub format: String,
+    /// Glob patterns of files to scan, relative to project_root (e.g., ["src/**/*.rs"]). Default: all files.
+    #[schemars(length(max = MAX_BATCH_SIZE), inner(length(max = MAX_GLOB_LEN)))]
+    pub include: Option<Vec<String>>,
+    /// Glob patterns of files and directories to skip, relative to project_root (e.g., ["target"]).
+    #[schemars(length(max = MAX_BATCH_SIZE), inner(length(max = MAX_GLOB_LEN)))]
+    pub exclude: Option<Vec<String>>,
+    /// Glob patterns of test files (e.g., ["tests/**"]). Default: common test file and directory names.
+    #[schemars(length(max = MAX_BATCH_SIZE), inner(length(max = MAX_GLOB_LEN)))]
+    pub test_patterns: Option<Vec<String>>,
+    /// File to write the rendered matrix to, relative to project_root (e.g., "target/matrix.html").
+    #[schemars(length(min = 1, max = MAX_FILE_PATH_LEN))]
+    pub output: Option<String>,
+}
+
+/// Parameters for reqlix_export_graph (G.TOOLREQLIXEXPORTG.2)
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct ExportGraphParams {
+    /// Path to the project root directory. Optional when the server has a default project root or the client provides a single root.
+    #[serde(default)]
+    #[schemars(length(min = 1, max = MAX_PROJECT_ROOT_LEN))]
+    pub project_root: String,
+    /// Brief description of the operation that LLM intends to perform.
+    #[schemars(length(min = 1, max = MAX_OPERATION_DESC_LEN))]
+    pub operation_description: String,
+    /// Graph format: "dot" (Graphviz) or "mermaid" (flowchart).
+    pub format: String,
+    /// Only requirements of this category (e.g., "general").
+    #[schemars(length(min = 1, max = MAX_CATEGORY_LEN), pattern(CATEGORY_PATTERN))]
+    pub category: Option<String>,
+    /// Only requirements within radius links of this requirement (e.g., "G.C.1").
+    #[schemars(length(min = 1, max = MAX_INDEX_LEN))]
+    pub index: Option<String>,
+    /// Neighborhood radius around index, between 0 and 10. Default: 1. Only allowed with index.
+    #[schemars(range(max = MAX_GRAPH_RADIUS))]
+    pub radius: Option<usize>,
+}
@@ -3,6 +3,7 @@
 use crate::filesystem::{is_file_empty_or_whitespace, read_file_utf8};
 use crate::models::{RequirementFull, RequirementSummary};
 use pulldown_cmark::{Event, HeadingLevel, Parser, Tag};
+use std::collections::HashMap;
 use std::path::PathBuf;
 
 /// Parse markdown level-1 heading according to G.R.2
@@ -34,27 +35,23 @@ pub fn parse_level1_heading(line: &str) -> Option<String> {
     // Check if we have a level-1 heading
     if events.len() >= 2 {
         match (&events[0], &events[1]) {
-            (Event::Start(Tag::Heading(level, _, _)), Event::Text(text)) => {
-                if level == &HeadingLevel::H1 {
-                    // Check if there are more events (would indicate level-2 or higher)
-                    if events.len() == 3 {
-                        if let Event::End(Tag::Heading(end_level, _, _)) = &events[2] {
-                            if end_level == &HeadingLevel::H1 {
-                                return Some(text.to_string());
-                            }
-                        }
+            // Check if there are more events (would indicate level-2 or higher)
+            (Event::Start(Tag::Heading(level, _, _)), Event::Text(text))
+                if level == &HeadingLevel::H1 && events.len() == 3 =>
+            {
+                if let Event::End(Tag::Heading(end_level, _, _)) = &events[2] {
+                    if end_level == &HeadingLevel::H1 {
+                        return Some(text.to_string());
                     }
                 }
             }
+            // Handle empty heading: Start + End without Text
             (
                 Event::Start(Tag::Heading(level, _, _)),
                 Event::End(Tag::Heading(end_level, _, _)),
-            ) => {
-                // Handle empty heading: Start + End without Text
-                if level == &HeadingLevel::H1 && end_level == &HeadingLevel::H1 {
-                    // Empty heading content
-                    return Some(String::new());
-                }
+            ) if level == &HeadingLevel::H1 && end_level == &HeadingLevel::H1 => {
+                // Empty heading content
+                return Some(String::new());
             }
             _ => {}
         }
@@ -371,6 +368,117 @@ pub fn find_requirement_streaming(
     Err("Requirement not found".to_string())
 }
 
+/// Parse a whole category file in one pass (G.R.2, G.R.3, G.R.5, G.CA.2)
+/// Returns the chapters in file order with their requirement summaries, and every requirement with
+/// its full content. Chapter and requirement boundaries follow read_chapters_streaming,
+/// read_requirements_streaming and find_requirement_streaming.
+#[allow(clippy::type_complexity)]
+pub fn parse_category_content(
+    content: &str,
+    category_name: &str,
+) -> (Vec<(String, Vec<RequirementSummary>)>, Vec<RequirementFull>) {
+    // Handle empty files (G.R.10)
+    if is_file_empty_or_whitespace(content) {
+        return (Vec::new(), Vec::new());
+    }
+
+    // Requirement bodies by index, first occurrence wins (G.R.5)
+    let mut bodies: HashMap<String, String> = HashMap::new();
+    let lines: Vec<&str> = content.lines().collect();
+    let mut current: Option<(String, usize)> = None;
+    let mut in_code_block = false;
+    let mut close = |current: &mut Option<(String, usize)>, end: usize| {
+        if let Some((index, start)) = current.take() {
+            let text = lines[start + 1..end].join("\n").trim().to_string();
+            bodies.entry(index).or_insert(text);
+        }
+    };
+    for (idx, line) in lines.iter().enumerate() {
+        // Track code blocks
+        if line.trim().starts_with("```") {
+            in_code_block = !in_code_block;
+        }
+        if in_code_block {
+            continue;
+        }
+        // Level-1 heading ends a requirement, level-2 heading ends it and starts the next one
+        if parse_level1_heading(line).is_some() {
+            close(&mut current, idx);
+        } else if let Some((index, _)) = parse_level2_heading(line) {
+            if current.as_ref().is_some_and(|(c, _)| *c == index) {
+                continue;
+            }
+            close(&mut current, idx);
+            current = Some((index, idx));
+        }
+    }
+    close(&mut current, lines.len());
+
+    // Chapters and requirement headings (G.R.2, G.R.3)
+    let mut chapters: Vec<(String, Vec<RequirementSummary>)> = Vec::new();
+    let mut requirements = Vec::new();
+    let mut in_code_block = false;
+    let mut current_heading_text = String::new();
+    let mut current_heading_level: Option<HeadingLevel> = None;
+
+    for event in Parser::new(content) {
+        match event {
+            Event::Start(Tag::CodeBlock(_)) => {
+                in_code_block = true;
+            }
+            Event::End(Tag::CodeBlock(_)) => {
+                in_code_block = false;
+            }
+            Event::Start(Tag::Heading(level, _, _)) if !in_code_block => {
+                current_heading_level = Some(level);
+                current_heading_text.clear();
+            }
+            Event::Text(text)
+                if !in_code_block
+                    && matches!(
+                        current_heading_level,
+                        Some(HeadingLevel::H1) | Some(HeadingLevel::H2)
+                    ) =>
+            {
+                current_heading_text.push_str(&text);
+            }
+            Event::End(Tag::Heading(HeadingLevel::H1, _, _)) if !in_code_block => {
+                chapters.push((current_heading_text.trim().to_string(), Vec::new()));
+                current_heading_text.clear();
+                current_heading_level = None;
+            }
+            Event::End(Tag::Heading(HeadingLevel::H2, _, _)) if !in_code_block => {
+                // Parse format: {index}: {title}
+                let heading_content = current_heading_text.trim();
+                if let (Some(colon_pos), Some((chapter, summaries))) =
+                    (heading_content.find(':'), chapters.last_mut())
+                {
+                    let index = heading_content[..colon_pos].trim().to_string();
+                    let title = heading_content[colon_pos + 1..].trim().to_string();
+                    if !index.is_empty() && !title.is_empty() {
+                        // Skip requirements whose body cannot be located (G.TOOLREQLIXS.3)
+                        if let Some(text) = bodies.get(&index) {
+                            requirements.push(RequirementFull {
+                                index: index.clone(),
+                                title: title.clone(),
+                                text: text.clone(),
+                                category: category_name.to_string(),
+                                chapter: chapter.clone(),
+                            });
+                        }
+                        summaries.push(RequirementSummary { index, title });
+                    }
+                }
+                current_heading_text.clear();
+                current_heading_level = None;
+            }
+            _ => {}
+        }
+    }
+
+    (chapters, requirements)
+}
+
 /// Parse index into parts (G.REQLIX_GET_REQUIREMENT.3)
 #[cfg_attr(test, allow(dead_code))]
 pub fn parse_index(index: &str) -> Result<(String, String, String), String> {
@@ -0,0 +1,322 @@
+// MCP prompts for common requirement workflows (G.MCPP.1 - G.MCPP.4)
+
+use crate::cache::load_category;
+use crate::config::{load_project_config, ProjectConfig};
+use crate::filesystem::{existing_requirements_dir, read_file_utf8, resolve_project_path};
+use crate::helpers::list_categories;
+use crate::models::{PromptArgumentInfo, PromptContent, PromptInfo};
+use crate::resources::{read_resource, requirement_markdown, ResourceUri};
+use crate::trace::find_index_references;
+use crate::validation::{
+    validate_category_in, validate_diff, validate_feature, validate_file, validate_index,
+};
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+/// Prompt definition: name, title, description and arguments as (name, description, required)
+type PromptDefinition = (
+    &'static str,
+    &'static str,
+    &'static str,
+    &'static [(&'static str, &'static str, bool)],
+);
+
+/// Category argument shared by several prompts (G.MCPP.1)
+const CATEGORY_ARGUMENT: (&str, &str, bool) = (
+    "category",
+    "Category whose requirements are added in full (e.g., general)",
+    false,
+);
+
+/// All prompts in listing order (G.MCPP.1)
+const PROMPTS: &[PromptDefinition] = &[
+    (
+        "review_diff",
+        "Review diff against requirements",
+        "Review a diff for violations of the project requirements",
+        &[("diff", "Unified diff to review", true), CATEGORY_ARGUMENT],
+    ),
+    (
+        "draft_requirements",
+        "Draft requirements for a feature",
+        "Draft new requirements for a feature and place them in the existing structure",
+        &[
+            ("feature", "Description of the feature", true),
+            CATEGORY_ARGUMENT,
+        ],
+    ),
+    (
+        "check_discrepancies",
+        "Check code against requirements",
+        "Find discrepancies between a source file and the requirements",
+        &[
+            ("file", "Source file, relative to the project root", true),
+            CATEGORY_ARGUMENT,
+        ],
+    ),
+    (
+        "write_tests",
+        "Write tests for a requirement",
+        "Write tests that cover one requirement",
+        &[
+            ("index", "Requirement index (e.g., G.R.3)", true),
+            (
+                "file",
+                "Test file to extend, relative to the project root",
+                false,
+            ),
+        ],
+    ),
+];
+
+/// Instructions shared by all prompts (G.MCPP.3)
+const PROMPT_INSTRUCTIONS: &str = "Follow the instructions in AGENTS.md (resource \
+reqlix://instructions). Make requirement changes only through the reqlix tools.";
+
+/// List all prompts (G.MCPP.1)
+#[cfg_attr(test, allow(dead_code))]
+pub fn list_prompts() -> Vec<PromptInfo> {
+    PROMPTS
+        .iter()
+        .map(|&(name, title, description, arguments)| PromptInfo {
+            name: name.to_string(),
+            title: title.to_string(),
+            description: description.to_string(),
+            arguments: arguments
+                .iter()
+                .map(|&(name, description, required)| PromptArgumentInfo {
+                    name: name.to_string(),
+                    description: description.to_string(),
+                    required,
+                })
+                .collect(),
+        })
+        .collect()
+}
+
+/// Validate the arguments of a prompt; unknown arguments are ignored (G.MCPP.2, G.MCPP.4)
+#[cfg_attr(test, allow(dead_code))]
+pub fn validate_prompt_arguments(
+    name: &str,
+    arguments: &HashMap<String, String>,
+) -> Result<(), String> {
+    validate_prompt_arguments_in(name, arguments, &ProjectConfig::default())
+}
+
+/// Validate the arguments of a prompt with the naming rules of the project (G.MCPP.4, G.SE.5)
+pub fn validate_prompt_arguments_in(
+    name: &str,
+    arguments: &HashMap<String, String>,
+    config: &ProjectConfig,
+) -> Result<(), String> {
+    let Some(&(_, _, _, definitions)) = PROMPTS.iter().find(|p| p.0 == name) else {
+        return Err(format!("Unknown prompt: {}", name));
+    };
+    for &(argument, _, required) in definitions {
+        let value = match arguments.get(argument) {
+            Some(value) => value.as_str(),
+            None if required => return Err(format!("{} is required", argument)),
+            None => continue,
+        };
+        match argument {
+            "diff" => validate_diff(value)?,
+            "feature" => validate_feature(value)?,
+            "category" => validate_category_in(value, config)?,
+            "index" => validate_index(value)?,
+            _ => validate_file(value)?,
+        }
+    }
+    Ok(())
+}
+
+/// Fenced code block, with a fence longer than any backtick run in the content (G.MCPP.3)
+fn fenced(content: &str, language: &str) -> String {
+    let longest = content.split(|c| c != '`').map(str::len).max().unwrap_or(0);
+    let fence = "`".repeat(longest.max(2) + 1);
+    let content = content.strip_suffix('\n').unwrap_or(content);
+    format!("{}{}\n{}\n{}", fence, language, content, fence)
+}
+
+/// Language of a fenced file: its extension (G.MCPP.3)
+fn file_language(file: &str) -> &str {
+    Path::new(file)
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or_default()
+}
+
+/// Section with a heading and requirement blocks, or `empty` if there are none (G.MCPP.3)
+fn requirements_section(heading: &str, blocks: &[String], empty: &str) -> String {
+    if blocks.is_empty() {
+        format!("# {}\n\n{}\n", heading, empty)
+    } else {
+        format!("# {}\n\n{}", heading, blocks.join("\n"))
+    }
+}
+
+/// Requirements referenced in `text` that exist, each once (G.MCPP.3)
+fn referenced_requirements(
+    project_root: &str,
+    text: &str,
+    written: &mut HashSet<String>,
+) -> Result<Vec<String>, String> {
+    let mut blocks = Vec::new();
+    for index in text.lines().flat_map(find_index_references) {
+        if !written.insert(index.clone()) {
+            continue;
+        }
+        match read_resource(project_root, &ResourceUri::Requirement(index).uri()) {
+            Ok(content) => blocks.push(content.text),
+            Err(e) if e == "Requirement not found" => {}
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(blocks)
+}
+
+/// Requirements of a category not written yet (G.MCPP.3)
+fn category_requirements(
+    requirements_dir: &Path,
+    category: &str,
+    written: &mut HashSet<String>,
+) -> Result<Vec<String>, String> {
+    if !list_categories(&requirements_dir.to_path_buf())?
+        .iter()
+        .any(|c| c == category)
+    {
+        return Err("Category not found".to_string());
+    }
+    Ok(load_category(requirements_dir, category)?
+        .search_index
+        .iter()
+        .map(|entry| &entry.requirement)
+        .filter(|r| written.insert(r.index.clone()))
+        .map(requirement_markdown)
+        .collect())
+}
+
+/// Optional category section of a prompt (G.MCPP.3)
+fn category_section(
+    requirements_dir: &Path,
+    category: Option<&String>,
+    written: &mut HashSet<String>,
+) -> Result<String, String> {
+    let Some(category) = category else {
+        return Ok(String::new());
+    };
+    let blocks = category_requirements(requirements_dir, category, written)?;
+    Ok(format!(
+        "\n{}",
+        requirements_section(
+            &format!("Requirements of category {}", category),
+            &blocks,
+            "The category has no further requirements."
+        )
+    ))
+}
+
+/// Build the message of a prompt from its arguments (G.MCPP.3)
+#[cfg_attr(test, allow(dead_code))]
+pub fn get_prompt(
+    project_root: &str,
+    name: &str,
+    arguments: &HashMap<String, String>,
+) -> Result<PromptContent, String> {
+    validate_prompt_arguments_in(name, arguments, &load_project_config(project_root)?)?;
+    let requirements_dir = existing_requirements_dir(project_root)?;
+    let argument = |name: &str| arguments.get(name);
+    let mut written = HashSet::new();
+
+    let text = match name {
+        "review_diff" => {
+            let diff = &arguments["diff"];
+            let referenced = referenced_requirements(project_root, diff, &mut written)?;
+            format!(
+                "Review the diff below against the requirements of this project. Report every change \
+                 that violates or contradicts a requirement, behavior the requirements do not cover, \
+                 and requirements that should change along with the code.\n\n{}\n\n# Diff\n\n{}\n\n{}{}",
+                PROMPT_INSTRUCTIONS,
+                fenced(diff, "diff"),
+                requirements_section(
+                    "Referenced requirements",
+                    &referenced,
+                    "The diff references no requirements."
+                ),
+                category_section(&requirements_dir, argument("category"), &mut written)?
+            )
+        }
+        "draft_requirements" => {
+            let mut structure = Vec::new();
+            for category in list_categories(&requirements_dir)? {
+                let chapters = load_category(&requirements_dir, &category)?.chapter_names();
+                structure.push(format!("- {}: {}", category, chapters.join(", ")));
+            }
+            let structure = if structure.is_empty() {
+                "There are no categories yet.".to_string()
+            } else {
+                structure.join("\n")
+            };
+            format!(
+                "Draft requirements for the feature below. Write atomic requirements in English, place \
+                 them in existing categories and chapters where they fit, and check new requirements \
+                 for duplicates with reqlix_find_duplicates.\n\n{}\n\n# Feature\n\n{}\n\n\
+                 # Categories and chapters\n\n{}\n{}",
+                PROMPT_INSTRUCTIONS,
+                arguments["feature"],
+                structure,
+                category_section(&requirements_dir, argument("category"), &mut written)?
+            )
+        }
+        "check_discrepancies" => {
+            let file = &arguments["file"];
+            let content = read_file_utf8(&resolve_project_path(project_root, "file", file)?)?;
+            let referenced = referenced_requirements(project_root, &content, &mut written)?;
+            format!(
+                "Check {} for discrepancies with the requirements of this project. Report code that \
+                 contradicts a requirement, requirements the code does not implement, and references \
+                 that cite the wrong requirement. For each discrepancy, say whether the code or the \
+                 requirement should change.\n\n{}\n\n# File {}\n\n{}\n\n{}{}",
+                file,
+                PROMPT_INSTRUCTIONS,
+                file,
+                fenced(&content, file_language(file)),
+                requirements_section(
+                    "Referenced requirements",
+                    &referenced,
+                    "The file references no requirements."
+                ),
+                category_section(&requirements_dir, argument("category"), &mut written)?
+            )
+        }
+        _ => {
+            let index = &arguments["index"];
+            let requirement =
+                read_resource(project_root, &ResourceUri::Requirement(index.clone()).uri())?;
+            let test_file = match argument("file") {
+                Some(file) => {
+                    let content =
+                        read_file_utf8(&resolve_project_path(project_root, "file", file)?)?;
+                    format!(
+                        "\n# Test file {}\n\n{}\n",
+                        file,
+                        fenced(&content, file_language(file))
+                    )
+                }
+                None => String::new(),
+            };
+            format!(
+                "Write tests for requirement {}. Cover the behavior the requirement specifies, including \
+                 edge cases and errors. Mark each test with a `Covers Requirement: {}` comment.\n\n{}\n\n\
+                 # Requirement\n\n{}{}",
+                index, index, PROMPT_INSTRUCTIONS, requirement.text, test_file
+            )
+        }
+    };
+
+    let description = PROMPTS
+        .iter()
+        .find(|p| p.0 == name)
+        .map(|p| p.2.to_string())
+        .unwrap_or_default();
+    Ok(PromptContent { description, text })
+}
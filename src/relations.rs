@@ -0,0 +1,132 @@
+// Typed requirement relations stored as metadata lines (G.RELA.1, G.RELA.2, G.RELA.3, G.RELA.4)
+
+use crate::constants::RELATION_TYPES;
+use crate::params::RelationsParam;
+use crate::trace::collect_requirements;
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+impl RelationsParam {
+    /// Relation types with their targets, in the order of G.RELA.1
+    pub fn entries(&self) -> Vec<(&'static str, &[String])> {
+        [
+            ("refines", &self.refines),
+            ("depends_on", &self.depends_on),
+            ("conflicts_with", &self.conflicts_with),
+            ("verifies", &self.verifies),
+            ("derived_from", &self.derived_from),
+        ]
+        .into_iter()
+        .map(|(relation, targets)| (relation, targets.as_deref().unwrap_or(&[])))
+        .collect()
+    }
+}
+
+/// Parse a relation line `- {type}: {index}, {index}` (G.RELA.2)
+#[cfg_attr(test, allow(dead_code))]
+pub fn parse_relation_line(line: &str) -> Option<(&'static str, Vec<String>)> {
+    let rest = line.trim_end().strip_prefix("- ")?;
+    let (relation, targets) = rest.split_once(':')?;
+    let relation = RELATION_TYPES.iter().find(|t| **t == relation)?;
+    if !targets.is_empty() && !targets.starts_with(' ') {
+        return None;
+    }
+    let targets = targets
+        .split(',')
+        .map(str::trim)
+        .filter(|t| !t.is_empty())
+        .map(str::to_string)
+        .collect();
+    Some((relation, targets))
+}
+
+/// All relations in a requirement text, skipping fenced code blocks (G.RELA.2)
+#[cfg_attr(test, allow(dead_code))]
+pub fn parse_relations(text: &str) -> Vec<(&'static str, String)> {
+    let mut relations = Vec::new();
+    let mut in_code_block = false;
+    for line in text.lines() {
+        if line.trim_start().starts_with("```") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+        if in_code_block {
+            continue;
+        }
+        if let Some((relation, targets)) = parse_relation_line(line) {
+            relations.extend(targets.into_iter().map(|t| (relation, t)));
+        }
+    }
+    relations
+}
+
+/// Replace relation lines in the text with the given relations (G.RELA.4)
+#[cfg_attr(test, allow(dead_code))]
+pub fn apply_relations(text: &str, relations: &RelationsParam) -> String {
+    let mut kept: Vec<&str> = Vec::new();
+    let mut in_code_block = false;
+    for line in text.lines() {
+        if line.trim_start().starts_with("```") {
+            in_code_block = !in_code_block;
+        }
+        if !in_code_block && parse_relation_line(line).is_some() {
+            continue;
+        }
+        kept.push(line);
+    }
+    let mut result = kept.join("\n").trim_end().to_string();
+
+    let mut lines = Vec::new();
+    for (relation, targets) in relations.entries() {
+        let mut unique: Vec<&str> = Vec::new();
+        for target in targets {
+            if !unique.contains(&target.as_str()) {
+                unique.push(target);
+            }
+        }
+        if !unique.is_empty() {
+            lines.push(format!("- {}: {}", relation, unique.join(", ")));
+        }
+    }
+    if !lines.is_empty() {
+        if !result.is_empty() {
+            result.push_str("\n\n");
+        }
+        result.push_str(&lines.join("\n"));
+    }
+    result
+}
+
+/// Check that every relation target exists and is not the requirement itself (G.RELA.3)
+/// Relations already present in `previous_text` are kept as they are and not checked again.
+pub(crate) fn validate_relation_targets(
+    requirements_dir: &PathBuf,
+    text: &str,
+    index: Option<&str>,
+    previous_text: Option<&str>,
+) -> Result<(), String> {
+    let previous = previous_text.map(parse_relations).unwrap_or_default();
+    let relations: Vec<(&str, String)> = parse_relations(text)
+        .into_iter()
+        .filter(|relation| !previous.contains(relation))
+        .collect();
+    if relations.is_empty() {
+        return Ok(());
+    }
+    let existing: HashSet<String> = collect_requirements(requirements_dir)?
+        .into_iter()
+        .map(|r| r.index)
+        .collect();
+    for (_, target) in relations {
+        if index == Some(target.as_str()) {
+            return Err(format!(
+                "Requirement cannot be related to itself: {}",
+                target
+            ));
+        }
+        if !existing.contains(&target) {
+            return Err(format!("Related requirement not found: {}", target));
+        }
+    }
+    Ok(())
+}
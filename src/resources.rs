@@ -0,0 +1,240 @@
+// MCP resources with reqlix:// URIs (G.M.1 - G.M.5)
+
+use crate::cache::load_category;
+use crate::config::load_project_config;
+use crate::filesystem::{existing_requirements_dir, find_requirements_file, read_file_utf8};
+use crate::helpers::list_categories;
+use crate::instructions::render_instructions;
+use crate::models::{RequirementFull, ResourceContent, ResourceInfo};
+use std::collections::HashSet;
+use std::path::Path;
+
+/// URI scheme of all resources (G.M.1)
+const RESOURCE_SCHEME: &str = "reqlix://";
+
+/// MIME type of all resource contents (G.M.2)
+const RESOURCE_MIME_TYPE: &str = "text/markdown";
+
+/// Resource addressed by a reqlix:// URI (G.M.1)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResourceUri {
+    Instructions,
+    Category(String),
+    Chapter(String, String),
+    Requirement(String),
+}
+
+/// Percent-encode a URI path segment, keeping unreserved characters (G.M.1)
+pub fn encode_uri_segment(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            out.push(byte as char);
+        } else {
+            out.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    out
+}
+
+/// Decode a percent-encoded URI path segment (G.M.1)
+pub(crate) fn decode_uri_segment(value: &str) -> Option<String> {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            let hex = value.get(i + 1..i + 3)?;
+            decoded.push(u8::from_str_radix(hex, 16).ok()?);
+            i += 3;
+        } else {
+            decoded.push(bytes[i]);
+            i += 1;
+        }
+    }
+    String::from_utf8(decoded).ok().filter(|s| !s.is_empty())
+}
+
+impl ResourceUri {
+    /// Parse a reqlix:// URI (G.M.1)
+    pub fn parse(uri: &str) -> Result<Self, String> {
+        let invalid = || format!("Invalid resource URI: {}", uri);
+        let path = uri.strip_prefix(RESOURCE_SCHEME).ok_or_else(invalid)?;
+        let segments: Vec<&str> = path.split('/').collect();
+        let parsed = match segments.as_slice() {
+            ["instructions"] => Some(ResourceUri::Instructions),
+            ["category", category] => decode_uri_segment(category).map(ResourceUri::Category),
+            ["chapter", category, chapter] => decode_uri_segment(category)
+                .zip(decode_uri_segment(chapter))
+                .map(|(category, chapter)| ResourceUri::Chapter(category, chapter)),
+            [index] if index.contains('.') => {
+                decode_uri_segment(index).map(ResourceUri::Requirement)
+            }
+            _ => None,
+        };
+        parsed.ok_or_else(invalid)
+    }
+
+    /// Canonical URI of the resource (G.M.1)
+    pub fn uri(&self) -> String {
+        match self {
+            ResourceUri::Instructions => format!("{}instructions", RESOURCE_SCHEME),
+            ResourceUri::Category(category) => {
+                format!(
+                    "{}category/{}",
+                    RESOURCE_SCHEME,
+                    encode_uri_segment(category)
+                )
+            }
+            ResourceUri::Chapter(category, chapter) => format!(
+                "{}chapter/{}/{}",
+                RESOURCE_SCHEME,
+                encode_uri_segment(category),
+                encode_uri_segment(chapter)
+            ),
+            ResourceUri::Requirement(index) => {
+                format!("{}{}", RESOURCE_SCHEME, encode_uri_segment(index))
+            }
+        }
+    }
+}
+
+/// Markdown of one requirement as stored in the category file (G.M.2)
+pub(crate) fn requirement_markdown(requirement: &RequirementFull) -> String {
+    format!(
+        "## {}: {}\n\n{}\n",
+        requirement.index,
+        requirement.title,
+        requirement.text.trim_end()
+    )
+}
+
+/// Resource entry with its URI (G.M.3)
+fn resource_info(
+    uri: ResourceUri,
+    name: String,
+    title: String,
+    description: String,
+) -> ResourceInfo {
+    ResourceInfo {
+        uri: uri.uri(),
+        name,
+        title,
+        description,
+        mime_type: RESOURCE_MIME_TYPE.to_string(),
+    }
+}
+
+/// List the instructions, categories, chapters and requirements of a project (G.M.3)
+#[cfg_attr(test, allow(dead_code))]
+pub fn list_resources(project_root: &str) -> Result<Vec<ResourceInfo>, String> {
+    let Some(requirements_dir) =
+        find_requirements_file(project_root)?.and_then(|path| path.parent().map(Path::to_path_buf))
+    else {
+        return Ok(Vec::new());
+    };
+
+    let mut resources = vec![resource_info(
+        ResourceUri::Instructions,
+        "instructions".to_string(),
+        "Instructions (AGENTS.md)".to_string(),
+        "Mandatory instructions for working with the requirements".to_string(),
+    )];
+    let mut chapters = Vec::new();
+    let mut requirements = Vec::new();
+    for category in list_categories(&requirements_dir)? {
+        resources.push(resource_info(
+            ResourceUri::Category(category.clone()),
+            format!("category/{}", category),
+            format!("Category: {}", category),
+            format!("All requirements of category {}", category),
+        ));
+        let parsed = load_category(&requirements_dir, &category)?;
+        for chapter in parsed.chapter_names() {
+            chapters.push(resource_info(
+                ResourceUri::Chapter(category.clone(), chapter.clone()),
+                format!("chapter/{}/{}", category, chapter),
+                format!("Chapter: {} ({})", chapter, category),
+                format!(
+                    "All requirements of chapter {} in category {}",
+                    chapter, category
+                ),
+            ));
+        }
+        for entry in parsed.search_index {
+            let r = entry.requirement;
+            requirements.push(resource_info(
+                ResourceUri::Requirement(r.index.clone()),
+                r.index.clone(),
+                format!("{}: {}", r.index, r.title),
+                format!("Requirement in {} / {}", r.category, r.chapter),
+            ));
+        }
+    }
+    resources.extend(chapters);
+    resources.extend(requirements);
+    // Duplicate chapter headings and indices share a URI
+    let mut seen = HashSet::new();
+    resources.retain(|resource| seen.insert(resource.uri.clone()));
+    Ok(resources)
+}
+
+/// Read the content of a resource (G.M.2)
+#[cfg_attr(test, allow(dead_code))]
+pub fn read_resource(project_root: &str, uri: &str) -> Result<ResourceContent, String> {
+    let resource = ResourceUri::parse(uri)?;
+    let requirements_dir = existing_requirements_dir(project_root)?;
+    let categories = list_categories(&requirements_dir)?;
+    let text = match &resource {
+        ResourceUri::Instructions => render_instructions(
+            project_root,
+            &requirements_dir,
+            &load_project_config(project_root)?,
+            false,
+        )?,
+        ResourceUri::Category(category) => {
+            if !categories.contains(category) {
+                return Err("Category not found".to_string());
+            }
+            read_file_utf8(&requirements_dir.join(format!("{}.md", category)))?
+        }
+        ResourceUri::Chapter(category, chapter) => {
+            if !categories.contains(category) {
+                return Err("Category not found".to_string());
+            }
+            let parsed = load_category(&requirements_dir, category)?;
+            if !parsed.chapter_names().contains(chapter) {
+                return Err("Chapter not found".to_string());
+            }
+            let blocks: Vec<String> = parsed
+                .search_index
+                .iter()
+                .map(|e| &e.requirement)
+                .filter(|r| &r.chapter == chapter)
+                .map(requirement_markdown)
+                .collect();
+            format!("# {}\n\n{}", chapter, blocks.join("\n"))
+        }
+        ResourceUri::Requirement(index) => {
+            let mut found = None;
+            for category in &categories {
+                let parsed = load_category(&requirements_dir, category)?;
+                found = parsed
+                    .search_index
+                    .into_iter()
+                    .map(|e| e.requirement)
+                    .find(|r| &r.index == index);
+                if found.is_some() {
+                    break;
+                }
+            }
+            let requirement = found.ok_or_else(|| "Requirement not found".to_string())?;
+            requirement_markdown(&requirement)
+        }
+    };
+    Ok(ResourceContent {
+        uri: resource.uri(),
+        mime_type: RESOURCE_MIME_TYPE.to_string(),
+        text,
+    })
+}
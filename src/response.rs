@@ -1,14 +1,16 @@
-// JSON response helpers (G.C.5, G.C.6)
+// JSON response helpers (G.C.5, G.C.6, G.S.1)
 
+use crate::models::ToolResponse;
+use rmcp::model::{CallToolResult, Content};
 use serde::Serialize;
-use serde_json::json;
 
 /// Create success JSON response (G.C.5)
 pub(crate) fn json_success<T: Serialize>(data: T) -> String {
-    serde_json::to_string_pretty(&json!({
-        "success": true,
-        "data": data
-    }))
+    serde_json::to_string_pretty(&ToolResponse {
+        success: true,
+        data: Some(data),
+        error: None,
+    })
     .unwrap_or_else(|_| {
         r#"{"success": false, "error": "Failed to serialize response"}"#.to_string()
     })
@@ -16,9 +18,22 @@ pub(crate) fn json_success<T: Serialize>(data: T) -> String {
 
 /// Create error JSON response (G.C.6)
 pub(crate) fn json_error(message: &str) -> String {
-    serde_json::to_string_pretty(&json!({
-        "success": false,
-        "error": message
-    }))
+    serde_json::to_string_pretty(&ToolResponse::<()> {
+        success: false,
+        data: None,
+        error: Some(message.to_string()),
+    })
     .unwrap_or_else(|_| format!(r#"{{"success": false, "error": "{}"}}"#, message))
 }
+
+/// Wrap a JSON response as text and structured content; failures are tool errors (G.S.1)
+pub(crate) fn tool_result(response: String) -> CallToolResult {
+    let structured: serde_json::Value = serde_json::from_str(&response).unwrap_or_default();
+    let is_error = structured["success"] != true;
+    CallToolResult {
+        content: vec![Content::text(response)],
+        is_error: Some(is_error),
+        meta: None,
+        structured_content: Some(structured),
+    }
+}
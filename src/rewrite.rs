@@ -0,0 +1,123 @@
+// Rewriting index references after requirements move (G.TOOLREQLIXR.3)
+
+use crate::filesystem::{get_requirements_dir, read_file_utf8, write_file_utf8};
+use crate::helpers::list_categories;
+use crate::models::{ReferenceChange, RewriteReport};
+use crate::parsing::parse_level2_heading;
+use crate::trace::{index_reference_matches, relative_path, source_files, TraceOptions};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Replace whole index tokens of a line according to the mapping, all at once (G.TOOLREQLIXR.3 step 4)
+/// Returns the new line and the number of replacements, or None if nothing changed
+#[cfg_attr(test, allow(dead_code))]
+pub fn rewrite_index_line(
+    line: &str,
+    mapping: &HashMap<String, String>,
+) -> Option<(String, usize)> {
+    let mut result = String::with_capacity(line.len());
+    let mut position = 0;
+    let mut replacements = 0;
+    for (offset, index) in index_reference_matches(line) {
+        let Some(new_index) = mapping.get(index) else {
+            continue;
+        };
+        if new_index == index {
+            continue;
+        }
+        result.push_str(&line[position..offset]);
+        result.push_str(new_index);
+        position = offset + index.len();
+        replacements += 1;
+    }
+    if replacements == 0 {
+        return None;
+    }
+    result.push_str(&line[position..]);
+    Some((result, replacements))
+}
+
+/// Unified diff of the changed lines of one file (G.TOOLREQLIXR.4)
+fn file_diff(file: &str, changes: &[ReferenceChange]) -> String {
+    let mut diff = format!("--- a/{}\n+++ b/{}\n", file, file);
+    for change in changes {
+        diff.push_str(&format!(
+            "@@ -{} +{} @@\n-{}\n+{}\n",
+            change.line, change.line, change.before, change.after
+        ));
+    }
+    diff
+}
+
+/// Rewrite index references in requirement texts and source files (G.TOOLREQLIXR.3)
+#[cfg_attr(test, allow(dead_code))]
+pub fn rewrite_references(
+    project_root: &str,
+    mapping: &HashMap<String, String>,
+    options: &TraceOptions,
+    dry_run: bool,
+) -> Result<RewriteReport, String> {
+    let root = Path::new(project_root);
+    let requirements_dir = get_requirements_dir(project_root)?;
+
+    // G.TOOLREQLIXR.3 step 2: Requirement files, then source files in scan order
+    let mut files: Vec<(String, PathBuf, String, bool)> = Vec::new();
+    for category in list_categories(&requirements_dir)? {
+        let path = requirements_dir.join(format!("{}.md", category));
+        let content = read_file_utf8(&path)?;
+        let file = relative_path(root, &path).unwrap_or_else(|| path.to_string_lossy().to_string());
+        files.push((file, path, content, true));
+    }
+    for (file, path, content) in source_files(root, &requirements_dir, options)? {
+        files.push((file, path, content, false));
+    }
+
+    // G.TOOLREQLIXR.3 steps 3-4: Rewrite lines, keeping line endings
+    let mut report = RewriteReport {
+        dry_run,
+        ..Default::default()
+    };
+    let mut updates: Vec<(PathBuf, String)> = Vec::new();
+    for (file, path, content, is_requirement_file) in files {
+        let mut new_content = String::with_capacity(content.len());
+        let mut changes = Vec::new();
+        for (number, segment) in content.split_inclusive('\n').enumerate() {
+            let line = segment.trim_end_matches(['\n', '\r']);
+            let rewritten = if is_requirement_file && parse_level2_heading(line).is_some() {
+                None
+            } else {
+                rewrite_index_line(line, mapping)
+            };
+            match rewritten {
+                Some((after, count)) => {
+                    new_content.push_str(&after);
+                    new_content.push_str(&segment[line.len()..]);
+                    report.replacements += count;
+                    changes.push(ReferenceChange {
+                        file: file.clone(),
+                        line: number + 1,
+                        before: line.to_string(),
+                        after,
+                    });
+                }
+                None => new_content.push_str(segment),
+            }
+        }
+        if changes.is_empty() {
+            continue;
+        }
+        report.files_changed += 1;
+        report.diff.push_str(&file_diff(&file, &changes));
+        report.changes.extend(changes);
+        updates.push((path, new_content));
+    }
+
+    // G.TOOLREQLIXR.3 step 5: Write files unless dry run
+    if !dry_run {
+        for (path, content) in updates {
+            write_file_utf8(&path, &content)?;
+        }
+    }
+
+    Ok(report)
+}
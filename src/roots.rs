@@ -0,0 +1,49 @@
+// Default project root from the server and client roots (G.PR.1, G.PR.2)
+
+use crate::resources::decode_uri_segment;
+
+/// Scheme of client roots that name local directories (G.PR.2)
+const FILE_URI_PREFIX: &str = "file://";
+
+/// Local directory of a client root URI; other schemes and hosts are ignored (G.PR.2)
+pub fn root_uri_path(uri: &str) -> Option<String> {
+    let rest = uri.strip_prefix(FILE_URI_PREFIX)?;
+    let path = match rest.strip_prefix("localhost") {
+        Some(path) => path,
+        None => rest,
+    };
+    if !path.starts_with('/') {
+        return None;
+    }
+    let mut path = decode_uri_segment(path)?;
+    // file:///C:/dir names the Windows directory C:/dir
+    let bytes = path.as_bytes();
+    if bytes.len() >= 3 && bytes[1].is_ascii_alphabetic() && bytes[2] == b':' {
+        path.remove(0);
+    }
+    if path.len() > 1 && path.ends_with('/') {
+        path.pop();
+    }
+    Some(path)
+}
+
+/// Project root of a request without `project_root` (G.PR.1)
+///
+/// The server default wins over client roots; a single client root is used as is.
+/// Returns `None` when neither is known and an error listing the roots when several are.
+pub fn resolve_project_root(
+    default: Option<&str>,
+    roots: &[String],
+) -> Result<Option<String>, String> {
+    if let Some(default) = default {
+        return Ok(Some(default.to_string()));
+    }
+    match roots {
+        [] => Ok(None),
+        [root] => Ok(Some(root.clone())),
+        _ => Err(format!(
+            "project_root is ambiguous; pass one of the client roots: {}",
+            roots.join(", ")
+        )),
+    }
+}
@@ -1,13 +1,27 @@
 // ServerHandler implementation
 
+use crate::config::{load_project_config, NamingConfig};
 use crate::descriptions::*;
+use crate::filesystem::existing_requirements_dir;
 use crate::handlers::*;
+use crate::models::*;
 use crate::params::*;
+use crate::prompts::{get_prompt, list_prompts, validate_prompt_arguments_in};
+use crate::resources::{list_resources, read_resource, ResourceUri};
+use crate::response::{json_error, tool_result};
+use crate::roots::{resolve_project_root, root_uri_path};
+use crate::subscriptions::RequirementsWatcher;
 use rmcp::{
     model::{
-        CallToolRequestParam, CallToolResult, Content, ListToolsResult, PaginatedRequestParam,
-        ServerCapabilities, Tool,
+        AnnotateAble, CallToolRequestParam, CallToolResult, GetPromptRequestParam, GetPromptResult,
+        ListPromptsResult, ListResourceTemplatesResult, ListResourcesResult, ListToolsResult,
+        PaginatedRequestParam, Prompt, PromptArgument, PromptMessage, PromptMessageRole,
+        RawResource, RawResourceTemplate, ReadResourceRequestParam, ReadResourceResult,
+        ResourceContents, ResourceUpdatedNotificationParam, ServerCapabilities,
+        SubscribeRequestParam, Tool, ToolAnnotations, UnsubscribeRequestParam,
     },
+    service::NotificationContext,
+    service::Peer,
     service::RequestContext,
     service::RoleServer,
     ServerHandler,
@@ -15,29 +29,195 @@ use rmcp::{
 use schemars::JsonSchema;
 use std::borrow::Cow;
 use std::env;
+use std::sync::Arc;
 
-/// Build tool schema from type and description
-fn build_tool_schema<T: JsonSchema>(name: &str, description: &'static str) -> Tool {
+/// Build tool schema from parameter type, response data type, description and hints (G.S.2)
+fn build_tool_schema<T: JsonSchema, R: JsonSchema>(
+    name: &str,
+    description: &'static str,
+    annotations: ToolAnnotations,
+) -> Tool {
     let schema = schemars::schema_for!(T);
     let input_schema: serde_json::Value = serde_json::to_value(&schema).unwrap_or_default();
+    let schema = schemars::schema_for!(ToolResponse<R>);
+    let output_schema: serde_json::Value = serde_json::to_value(&schema).unwrap_or_default();
 
     Tool {
         name: name.to_string().into(),
         description: Some(Cow::Borrowed(description)),
         input_schema: serde_json::from_value(input_schema).unwrap_or_default(),
-        annotations: None,
+        annotations: Some(annotations),
         icons: None,
         meta: None,
-        output_schema: None,
+        output_schema: Some(serde_json::from_value(output_schema).unwrap_or_default()),
         title: None,
     }
 }
 
+/// Replace the built-in name patterns of category and chapter properties with those of the project (G.P.5)
+fn apply_name_patterns(schema: &mut serde_json::Value, naming: &NamingConfig) {
+    match schema {
+        serde_json::Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                let pattern = match key.as_str() {
+                    "category" => Some(naming.category_schema_pattern()),
+                    "chapter" => Some(naming.chapter_schema_pattern()),
+                    _ => None,
+                };
+                if let (Some(pattern), serde_json::Value::Object(property)) = (pattern, &mut *value)
+                {
+                    if property.contains_key("pattern") {
+                        match pattern {
+                            Some(pattern) => property.insert("pattern".to_string(), pattern.into()),
+                            None => property.remove("pattern"),
+                        };
+                    }
+                }
+                apply_name_patterns(value, naming);
+            }
+        }
+        serde_json::Value::Array(values) => {
+            for value in values {
+                apply_name_patterns(value, naming);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Behavior hints of a tool; all tools stay within the project (G.S.3)
+fn tool_annotations(read_only: bool, destructive: bool, idempotent: bool) -> ToolAnnotations {
+    ToolAnnotations {
+        title: None,
+        read_only_hint: Some(read_only),
+        destructive_hint: Some(destructive),
+        idempotent_hint: Some(idempotent),
+        open_world_hint: Some(false),
+    }
+}
+
+/// Hints of a tool that only reads the project (G.S.3)
+fn read_only() -> ToolAnnotations {
+    tool_annotations(true, false, true)
+}
+
+impl crate::RequirementsServer {
+    /// Request the client roots, if supported, and remember their directories (G.PR.2)
+    async fn refresh_roots(&self, peer: &Peer<RoleServer>) -> Vec<String> {
+        let supported = peer
+            .peer_info()
+            .is_some_and(|info| info.capabilities.roots.is_some());
+        let roots = if supported {
+            match peer.list_roots().await {
+                Ok(result) => result
+                    .roots
+                    .iter()
+                    .filter_map(|root| root_uri_path(&root.uri))
+                    .collect(),
+                Err(e) => {
+                    tracing::warn!("Failed to list client roots: {}", e);
+                    Vec::new()
+                }
+            }
+        } else {
+            Vec::new()
+        };
+        *self.roots.lock().unwrap() = Some(roots.clone());
+        roots
+    }
+
+    /// Project root of a request without project_root (G.PR.1)
+    async fn default_project_root(
+        &self,
+        peer: &Peer<RoleServer>,
+    ) -> Result<Option<String>, String> {
+        let cached = self.roots.lock().unwrap().clone();
+        let roots = match cached {
+            Some(roots) => roots,
+            None if self.default_project_root.is_none() => self.refresh_roots(peer).await,
+            None => Vec::new(),
+        };
+        resolve_project_root(self.default_project_root.as_deref(), &roots)
+    }
+
+    /// Project known without asking the client: the default project root or the only
+    /// client root received so far (G.P.5)
+    fn known_project_root(&self) -> Option<String> {
+        let roots = self.roots.lock().unwrap().clone().unwrap_or_default();
+        resolve_project_root(self.default_project_root.as_deref(), &roots)
+            .ok()
+            .flatten()
+    }
+
+    /// Project of resource and prompt requests: the default project root,
+    /// otherwise the current working directory; ambiguous roots are an error (G.M.4)
+    async fn resource_project_root(
+        &self,
+        peer: &Peer<RoleServer>,
+    ) -> Result<String, rmcp::model::ErrorData> {
+        match self.default_project_root(peer).await {
+            Ok(Some(project_root)) => return Ok(project_root),
+            Ok(None) => {}
+            Err(e) => return Err(rmcp::model::ErrorData::invalid_request(e, None)),
+        }
+        env::current_dir()
+            .map(|dir| dir.to_string_lossy().to_string())
+            .map_err(|e| {
+                rmcp::model::ErrorData::internal_error(
+                    format!("Failed to get current directory: {}", e),
+                    None,
+                )
+            })
+    }
+}
+
+/// Map a resource error message to an MCP error (G.M.5)
+fn resource_error(message: String) -> rmcp::model::ErrorData {
+    if message.starts_with("Invalid resource URI") || message.ends_with("not found") {
+        rmcp::model::ErrorData::resource_not_found(message, None)
+    } else {
+        rmcp::model::ErrorData::internal_error(message, None)
+    }
+}
+
+/// Map a prompt error message to an MCP error (G.MCPP.4)
+fn prompt_error(message: String) -> rmcp::model::ErrorData {
+    if message.contains("not found")
+        || message.contains("must be a relative path inside the project")
+    {
+        rmcp::model::ErrorData::invalid_params(message, None)
+    } else {
+        rmcp::model::ErrorData::internal_error(message, None)
+    }
+}
+
+/// Build resource template (G.M.3)
+fn build_resource_template(
+    uri_template: &str,
+    name: &str,
+    description: &str,
+) -> rmcp::model::ResourceTemplate {
+    RawResourceTemplate {
+        uri_template: uri_template.to_string(),
+        name: name.to_string(),
+        title: None,
+        description: Some(description.to_string()),
+        mime_type: Some("text/markdown".to_string()),
+    }
+    .no_annotation()
+}
+
 #[allow(clippy::manual_async_fn)]
 impl ServerHandler for crate::RequirementsServer {
     fn get_info(&self) -> rmcp::model::ServerInfo {
         rmcp::model::ServerInfo {
-            capabilities: ServerCapabilities::builder().enable_tools().build(),
+            capabilities: ServerCapabilities::builder()
+                .enable_tools()
+                .enable_resources()
+                .enable_resources_subscribe()
+                .enable_resources_list_changed()
+                .enable_prompts()
+                .build(),
             server_info: rmcp::model::Implementation {
                 name: "reqlix".into(),
                 version: env!("CARGO_PKG_VERSION").into(),
@@ -49,6 +229,31 @@ impl ServerHandler for crate::RequirementsServer {
         }
     }
 
+    fn on_initialized(
+        &self,
+        context: NotificationContext<RoleServer>,
+    ) -> impl std::future::Future<Output = ()> + Send + '_ {
+        async move {
+            // G.PR.2: Request the client roots without blocking the session
+            if self.default_project_root.is_none() {
+                let server = self.clone();
+                tokio::spawn(async move {
+                    server.refresh_roots(&context.peer).await;
+                });
+            }
+        }
+    }
+
+    fn on_roots_list_changed(
+        &self,
+        _context: NotificationContext<RoleServer>,
+    ) -> impl std::future::Future<Output = ()> + Send + '_ {
+        async move {
+            // G.PR.2: Request the changed roots with the next call
+            *self.roots.lock().unwrap() = None;
+        }
+    }
+
     fn list_tools(
         &self,
         _request: Option<PaginatedRequestParam>,
@@ -58,43 +263,138 @@ impl ServerHandler for crate::RequirementsServer {
     > + Send
            + '_ {
         async move {
-            let tools = vec![
-                build_tool_schema::<GetInstructionsParams>(
+            let mut tools = vec![
+                build_tool_schema::<GetInstructionsParams, Instructions>(
                     "reqlix_get_instructions",
                     GET_INSTRUCTIONS_DESC,
+                    tool_annotations(false, false, true),
                 ),
-                build_tool_schema::<GetCategoriesParams>(
+                build_tool_schema::<GetCategoriesParams, CategoryList>(
                     "reqlix_get_categories",
                     GET_CATEGORIES_DESC,
+                    read_only(),
+                ),
+                build_tool_schema::<GetChaptersParams, ChapterList>(
+                    "reqlix_get_chapters",
+                    GET_CHAPTERS_DESC,
+                    read_only(),
                 ),
-                build_tool_schema::<GetChaptersParams>("reqlix_get_chapters", GET_CHAPTERS_DESC),
-                build_tool_schema::<GetRequirementsParams>(
+                build_tool_schema::<GetRequirementsParams, ChapterRequirements>(
                     "reqlix_get_requirements",
                     GET_REQUIREMENTS_DESC,
+                    read_only(),
                 ),
-                build_tool_schema::<GetRequirementParams>(
+                build_tool_schema::<GetRequirementParams, SingleOrBatch<RequirementFull>>(
                     "reqlix_get_requirement",
                     GET_REQUIREMENT_DESC,
+                    read_only(),
                 ),
-                build_tool_schema::<InsertRequirementParams>(
+                build_tool_schema::<InsertRequirementParams, InsertedRequirement>(
                     "reqlix_insert_requirement",
                     INSERT_REQUIREMENT_DESC,
+                    tool_annotations(false, false, false),
                 ),
-                build_tool_schema::<UpdateRequirementParams>(
+                build_tool_schema::<UpdateRequirementParams, SingleOrBatch<UpdatedRequirement>>(
                     "reqlix_update_requirement",
                     UPDATE_REQUIREMENT_DESC,
+                    tool_annotations(false, true, true),
+                ),
+                build_tool_schema::<GetVersionParams, VersionInfo>(
+                    "reqlix_get_version",
+                    GET_VERSION_DESC,
+                    read_only(),
                 ),
-                build_tool_schema::<GetVersionParams>("reqlix_get_version", GET_VERSION_DESC),
-                build_tool_schema::<DeleteRequirementParams>(
+                build_tool_schema::<DeleteRequirementParams, SingleOrBatch<DeletedRequirement>>(
                     "reqlix_delete_requirement",
                     DELETE_REQUIREMENT_DESC,
+                    tool_annotations(false, true, true),
                 ),
-                build_tool_schema::<SearchRequirementsParams>(
+                build_tool_schema::<SearchRequirementsParams, SearchResults>(
                     "reqlix_search_requirements",
                     SEARCH_REQUIREMENTS_DESC,
+                    read_only(),
+                ),
+                build_tool_schema::<FindDuplicatesParams, DuplicateReport>(
+                    "reqlix_find_duplicates",
+                    FIND_DUPLICATES_DESC,
+                    read_only(),
+                ),
+                build_tool_schema::<SuggestLocationParams, LocationSuggestions>(
+                    "reqlix_suggest_location",
+                    SUGGEST_LOCATION_DESC,
+                    read_only(),
+                ),
+                build_tool_schema::<TraceParams, TraceReport>(
+                    "reqlix_trace",
+                    TRACE_DESC,
+                    read_only(),
+                ),
+                build_tool_schema::<CoverageParams, RenderedCoverage>(
+                    "reqlix_coverage",
+                    COVERAGE_DESC,
+                    read_only(),
+                ),
+                build_tool_schema::<GetReferencesParams, RequirementReferences>(
+                    "reqlix_get_references",
+                    GET_REFERENCES_DESC,
+                    read_only(),
+                ),
+                build_tool_schema::<CheckReferencesParams, DanglingReport>(
+                    "reqlix_check_references",
+                    CHECK_REFERENCES_DESC,
+                    read_only(),
+                ),
+                build_tool_schema::<ImpactParams, ImpactReport>(
+                    "reqlix_impact",
+                    IMPACT_DESC,
+                    read_only(),
+                ),
+                build_tool_schema::<CheckLinksParams, LinkReport>(
+                    "reqlix_check_links",
+                    CHECK_LINKS_DESC,
+                    read_only(),
+                ),
+                build_tool_schema::<RewriteReferencesParams, RewriteReport>(
+                    "reqlix_rewrite_references",
+                    REWRITE_REFERENCES_DESC,
+                    tool_annotations(false, true, true),
+                ),
+                build_tool_schema::<TestMapParams, TestMap>(
+                    "reqlix_test_map",
+                    TEST_MAP_DESC,
+                    read_only(),
+                ),
+                build_tool_schema::<ImportTestResultsParams, VerificationReport>(
+                    "reqlix_import_test_results",
+                    IMPORT_TEST_RESULTS_DESC,
+                    tool_annotations(false, true, true),
+                ),
+                build_tool_schema::<ExportMatrixParams, MatrixExport>(
+                    "reqlix_export_matrix",
+                    EXPORT_MATRIX_DESC,
+                    tool_annotations(false, true, true),
+                ),
+                build_tool_schema::<ExportGraphParams, GraphExport>(
+                    "reqlix_export_graph",
+                    EXPORT_GRAPH_DESC,
+                    read_only(),
                 ),
             ];
 
+            // G.P.5: Name patterns follow the naming rules of the known project
+            let config = self
+                .known_project_root()
+                .and_then(|root| load_project_config(&root).ok());
+            if let Some(config) = config {
+                for tool in &mut tools {
+                    let mut schema = serde_json::Value::Object((*tool.input_schema).clone());
+                    apply_name_patterns(&mut schema, &config.naming);
+                    if let serde_json::Value::Object(schema) = schema {
+                        tool.input_schema = Arc::new(schema);
+                    }
+                }
+            }
+
             Ok(ListToolsResult {
                 tools,
                 next_cursor: None,
@@ -103,14 +403,263 @@ impl ServerHandler for crate::RequirementsServer {
         }
     }
 
-    fn call_tool(
+    fn list_resources(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+        context: RequestContext<RoleServer>,
+    ) -> impl std::future::Future<
+        Output = std::result::Result<ListResourcesResult, rmcp::model::ErrorData>,
+    > + Send
+           + '_ {
+        async move {
+            // G.M.3: Instructions, categories, chapters and requirements
+            let project_root = self.resource_project_root(&context.peer).await?;
+            let resources = list_resources(&project_root)
+                .map_err(resource_error)?
+                .into_iter()
+                .map(|info| {
+                    let mut resource = RawResource::new(info.uri, info.name);
+                    resource.title = Some(info.title);
+                    resource.description = Some(info.description);
+                    resource.mime_type = Some(info.mime_type);
+                    resource.no_annotation()
+                })
+                .collect();
+            Ok(ListResourcesResult {
+                resources,
+                next_cursor: None,
+                meta: None,
+            })
+        }
+    }
+
+    fn list_resource_templates(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+        _context: RequestContext<RoleServer>,
+    ) -> impl std::future::Future<
+        Output = std::result::Result<ListResourceTemplatesResult, rmcp::model::ErrorData>,
+    > + Send
+           + '_ {
+        async move {
+            // G.M.3: Templates for requirements, categories and chapters
+            Ok(ListResourceTemplatesResult {
+                resource_templates: vec![
+                    build_resource_template("reqlix://{index}", "requirement", "One requirement"),
+                    build_resource_template(
+                        "reqlix://category/{category}",
+                        "category",
+                        "All requirements of a category",
+                    ),
+                    build_resource_template(
+                        "reqlix://chapter/{category}/{chapter}",
+                        "chapter",
+                        "All requirements of a chapter",
+                    ),
+                ],
+                next_cursor: None,
+                meta: None,
+            })
+        }
+    }
+
+    fn read_resource(
+        &self,
+        request: ReadResourceRequestParam,
+        context: RequestContext<RoleServer>,
+    ) -> impl std::future::Future<
+        Output = std::result::Result<ReadResourceResult, rmcp::model::ErrorData>,
+    > + Send
+           + '_ {
+        async move {
+            // G.M.2: Markdown content of the resource
+            let project_root = self.resource_project_root(&context.peer).await?;
+            let content = read_resource(&project_root, &request.uri).map_err(resource_error)?;
+            Ok(ReadResourceResult {
+                contents: vec![ResourceContents::TextResourceContents {
+                    uri: content.uri,
+                    mime_type: Some(content.mime_type),
+                    text: content.text,
+                    meta: None,
+                }],
+            })
+        }
+    }
+
+    fn subscribe(
+        &self,
+        request: SubscribeRequestParam,
+        context: RequestContext<RoleServer>,
+    ) -> impl std::future::Future<Output = std::result::Result<(), rmcp::model::ErrorData>> + Send + '_
+    {
+        async move {
+            // G.M.6: Valid URI and existing requirements directory
+            let uri = ResourceUri::parse(&request.uri)
+                .map_err(resource_error)?
+                .uri();
+            let project_root = self.resource_project_root(&context.peer).await?;
+            let requirements_dir =
+                existing_requirements_dir(&project_root).map_err(resource_error)?;
+
+            let mut subscriptions = self.subscriptions.lock().unwrap();
+            subscriptions.uris.insert(uri);
+            if subscriptions.watcher.is_none() {
+                // G.M.8: Notify about subscribed changed resources, then about the list
+                let shared = self.subscriptions.clone();
+                let runtime = tokio::runtime::Handle::current();
+                let peer = context.peer.clone();
+                let watcher = RequirementsWatcher::start(requirements_dir, move |changes| {
+                    let updated: Vec<String> = {
+                        let subscriptions = shared.lock().unwrap();
+                        changes
+                            .updated
+                            .into_iter()
+                            .filter(|uri| subscriptions.uris.contains(uri))
+                            .collect()
+                    };
+                    let peer = peer.clone();
+                    runtime.spawn(async move {
+                        for uri in updated {
+                            let _ = peer
+                                .notify_resource_updated(ResourceUpdatedNotificationParam { uri })
+                                .await;
+                        }
+                        if changes.list_changed {
+                            let _ = peer.notify_resource_list_changed().await;
+                        }
+                    });
+                })
+                .map_err(|e| rmcp::model::ErrorData::internal_error(e, None))?;
+                subscriptions.watcher = Some(watcher);
+            }
+            Ok(())
+        }
+    }
+
+    fn unsubscribe(
         &self,
-        request: CallToolRequestParam,
+        request: UnsubscribeRequestParam,
         _context: RequestContext<RoleServer>,
+    ) -> impl std::future::Future<Output = std::result::Result<(), rmcp::model::ErrorData>> + Send + '_
+    {
+        async move {
+            // G.M.6: Stop watching with the last subscription
+            let mut subscriptions = self.subscriptions.lock().unwrap();
+            if let Ok(resource) = ResourceUri::parse(&request.uri) {
+                subscriptions.uris.remove(&resource.uri());
+            }
+            if subscriptions.uris.is_empty() {
+                subscriptions.watcher = None;
+            }
+            Ok(())
+        }
+    }
+
+    fn list_prompts(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+        _context: RequestContext<RoleServer>,
+    ) -> impl std::future::Future<
+        Output = std::result::Result<ListPromptsResult, rmcp::model::ErrorData>,
+    > + Send
+           + '_ {
+        async move {
+            // G.MCPP.1: Prompts with titles and argument descriptions
+            let prompts = list_prompts()
+                .into_iter()
+                .map(|info| Prompt {
+                    name: info.name,
+                    title: Some(info.title),
+                    description: Some(info.description),
+                    arguments: Some(
+                        info.arguments
+                            .into_iter()
+                            .map(|argument| PromptArgument {
+                                name: argument.name,
+                                title: None,
+                                description: Some(argument.description),
+                                required: Some(argument.required),
+                            })
+                            .collect(),
+                    ),
+                    icons: None,
+                    meta: None,
+                })
+                .collect();
+            Ok(ListPromptsResult {
+                prompts,
+                next_cursor: None,
+                meta: None,
+            })
+        }
+    }
+
+    fn get_prompt(
+        &self,
+        request: GetPromptRequestParam,
+        context: RequestContext<RoleServer>,
+    ) -> impl std::future::Future<
+        Output = std::result::Result<GetPromptResult, rmcp::model::ErrorData>,
+    > + Send
+           + '_ {
+        async move {
+            // G.MCPP.2: String arguments only
+            let arguments = request
+                .arguments
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|(name, value)| value.as_str().map(|v| (name, v.to_string())))
+                .collect();
+            let project_root = self.resource_project_root(&context.peer).await?;
+            let config = load_project_config(&project_root).map_err(prompt_error)?;
+            validate_prompt_arguments_in(&request.name, &arguments, &config)
+                .map_err(|e| rmcp::model::ErrorData::invalid_params(e, None))?;
+
+            // G.MCPP.3: One user message with the filled-in material
+            let content =
+                get_prompt(&project_root, &request.name, &arguments).map_err(prompt_error)?;
+            Ok(GetPromptResult {
+                description: Some(content.description),
+                messages: vec![PromptMessage::new_text(
+                    PromptMessageRole::User,
+                    content.text,
+                )],
+            })
+        }
+    }
+
+    fn call_tool(
+        &self,
+        mut request: CallToolRequestParam,
+        context: RequestContext<RoleServer>,
     ) -> impl std::future::Future<Output = std::result::Result<CallToolResult, rmcp::model::ErrorData>>
            + Send
            + '_ {
         async move {
+            // G.PR.1: Fill in a missing project_root
+            if request.name != "reqlix_get_version" {
+                let mut arguments = request.arguments.take().unwrap_or_default();
+                if arguments
+                    .get("project_root")
+                    .filter(|value| !value.is_null())
+                    .is_none()
+                {
+                    match self.default_project_root(&context.peer).await {
+                        Ok(Some(project_root)) => {
+                            arguments.insert("project_root".to_string(), project_root.into());
+                        }
+                        Ok(None) => {
+                            return Ok(tool_result(json_error(
+                                "project_root is required: pass project_root, start the server \
+with --project-root or use a client that provides roots",
+                            )))
+                        }
+                        Err(e) => return Ok(tool_result(json_error(&e))),
+                    }
+                }
+                request.arguments = Some(arguments);
+            }
+
             let result = match request.name.as_ref() {
                 "reqlix_get_instructions" => {
                     let params: GetInstructionsParams =
@@ -195,6 +744,123 @@ impl ServerHandler for crate::RequirementsServer {
                             })?;
                     handle_search_requirements(params)
                 }
+                "reqlix_find_duplicates" => {
+                    // G.TOOLREQLIXF.2: Parse parameters
+                    let params: FindDuplicatesParams =
+                        serde_json::from_value(request.arguments.unwrap_or_default().into())
+                            .map_err(|e| {
+                                rmcp::model::ErrorData::invalid_params(e.to_string(), None)
+                            })?;
+                    handle_find_duplicates(params)
+                }
+                "reqlix_suggest_location" => {
+                    // G.TOOLREQLIXSU.2: Parse parameters
+                    let params: SuggestLocationParams =
+                        serde_json::from_value(request.arguments.unwrap_or_default().into())
+                            .map_err(|e| {
+                                rmcp::model::ErrorData::invalid_params(e.to_string(), None)
+                            })?;
+                    handle_suggest_location(params)
+                }
+                "reqlix_trace" => {
+                    // G.TOOLREQLIXT.2: Parse parameters
+                    let params: TraceParams =
+                        serde_json::from_value(request.arguments.unwrap_or_default().into())
+                            .map_err(|e| {
+                                rmcp::model::ErrorData::invalid_params(e.to_string(), None)
+                            })?;
+                    handle_trace(params)
+                }
+                "reqlix_coverage" => {
+                    // G.TOOLREQLIXC.2: Parse parameters
+                    let params: CoverageParams =
+                        serde_json::from_value(request.arguments.unwrap_or_default().into())
+                            .map_err(|e| {
+                                rmcp::model::ErrorData::invalid_params(e.to_string(), None)
+                            })?;
+                    handle_coverage(params)
+                }
+                "reqlix_get_references" => {
+                    // G.TOOLREQLIXGETREF.2: Parse parameters
+                    let params: GetReferencesParams =
+                        serde_json::from_value(request.arguments.unwrap_or_default().into())
+                            .map_err(|e| {
+                                rmcp::model::ErrorData::invalid_params(e.to_string(), None)
+                            })?;
+                    handle_get_references(params)
+                }
+                "reqlix_check_references" => {
+                    // G.TOOLREQLIXCH.2: Parse parameters
+                    let params: CheckReferencesParams =
+                        serde_json::from_value(request.arguments.unwrap_or_default().into())
+                            .map_err(|e| {
+                                rmcp::model::ErrorData::invalid_params(e.to_string(), None)
+                            })?;
+                    handle_check_references(params)
+                }
+                "reqlix_impact" => {
+                    // G.TOOLREQLIXIM.2: Parse parameters
+                    let params: ImpactParams =
+                        serde_json::from_value(request.arguments.unwrap_or_default().into())
+                            .map_err(|e| {
+                                rmcp::model::ErrorData::invalid_params(e.to_string(), None)
+                            })?;
+                    handle_impact(params)
+                }
+                "reqlix_check_links" => {
+                    // G.TOOLREQLIXCHECKL.2: Parse parameters
+                    let params: CheckLinksParams =
+                        serde_json::from_value(request.arguments.unwrap_or_default().into())
+                            .map_err(|e| {
+                                rmcp::model::ErrorData::invalid_params(e.to_string(), None)
+                            })?;
+                    handle_check_links(params)
+                }
+                "reqlix_rewrite_references" => {
+                    // G.TOOLREQLIXR.2: Parse parameters
+                    let params: RewriteReferencesParams =
+                        serde_json::from_value(request.arguments.unwrap_or_default().into())
+                            .map_err(|e| {
+                                rmcp::model::ErrorData::invalid_params(e.to_string(), None)
+                            })?;
+                    handle_rewrite_references(params)
+                }
+                "reqlix_test_map" => {
+                    // G.TOOLREQLIXTE.2: Parse parameters
+                    let params: TestMapParams =
+                        serde_json::from_value(request.arguments.unwrap_or_default().into())
+                            .map_err(|e| {
+                                rmcp::model::ErrorData::invalid_params(e.to_string(), None)
+                            })?;
+                    handle_test_map(params)
+                }
+                "reqlix_import_test_results" => {
+                    // G.TOOLREQLIXIMPO.2: Parse parameters
+                    let params: ImportTestResultsParams =
+                        serde_json::from_value(request.arguments.unwrap_or_default().into())
+                            .map_err(|e| {
+                                rmcp::model::ErrorData::invalid_params(e.to_string(), None)
+                            })?;
+                    handle_import_test_results(params)
+                }
+                "reqlix_export_matrix" => {
+                    // G.TOOLREQLIXE.2: Parse parameters
+                    let params: ExportMatrixParams =
+                        serde_json::from_value(request.arguments.unwrap_or_default().into())
+                            .map_err(|e| {
+                                rmcp::model::ErrorData::invalid_params(e.to_string(), None)
+                            })?;
+                    handle_export_matrix(params)
+                }
+                "reqlix_export_graph" => {
+                    // G.TOOLREQLIXEXPORTG.2: Parse parameters
+                    let params: ExportGraphParams =
+                        serde_json::from_value(request.arguments.unwrap_or_default().into())
+                            .map_err(|e| {
+                                rmcp::model::ErrorData::invalid_params(e.to_string(), None)
+                            })?;
+                    handle_export_graph(params)
+                }
                 _ => {
                     return Err(rmcp::model::ErrorData::invalid_params(
                         format!("Unknown tool: {}", request.name),
@@ -203,12 +869,8 @@ impl ServerHandler for crate::RequirementsServer {
                 }
             };
 
-            Ok(CallToolResult {
-                content: vec![Content::text(result)],
-                is_error: None,
-                meta: None,
-                structured_content: None,
-            })
+            // G.S.1: Text and structured content, failures marked as errors
+            Ok(tool_result(result))
         }
     }
 }
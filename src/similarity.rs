@@ -0,0 +1,112 @@
+// Text similarity helpers: tokenization, TF-IDF cosine similarity and name similarity
+// (G.TOOLREQLIXF.3, G.TOOLREQLIXSU.3, G.REQLIX_I.7)
+
+use std::collections::HashMap;
+
+/// Split text into lowercase alphanumeric tokens (G.TOOLREQLIXF.3)
+#[cfg_attr(test, allow(dead_code))]
+pub fn tokenize(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|t| !t.is_empty())
+        .map(|t| t.to_lowercase())
+        .collect()
+}
+
+/// TF-IDF vectors for a set of documents (G.TOOLREQLIXF.3)
+/// Uses raw term counts and smoothed IDF: ln((1 + N) / (1 + df)) + 1. Vectors are L2-normalized.
+pub struct TfIdfIndex {
+    vectors: Vec<HashMap<String, f64>>,
+}
+
+impl TfIdfIndex {
+    /// Build TF-IDF vectors for the given documents
+    pub fn build(documents: &[String]) -> Self {
+        let tokenized: Vec<Vec<String>> = documents.iter().map(|d| tokenize(d)).collect();
+
+        // Document frequency of each term
+        let mut document_frequency: HashMap<&str, usize> = HashMap::new();
+        for tokens in &tokenized {
+            let mut seen: Vec<&str> = tokens.iter().map(|t| t.as_str()).collect();
+            seen.sort_unstable();
+            seen.dedup();
+            for term in seen {
+                *document_frequency.entry(term).or_insert(0) += 1;
+            }
+        }
+
+        let n = tokenized.len() as f64;
+        let vectors = tokenized
+            .iter()
+            .map(|tokens| {
+                let mut counts: HashMap<String, f64> = HashMap::new();
+                for token in tokens {
+                    *counts.entry(token.clone()).or_insert(0.0) += 1.0;
+                }
+                for (term, weight) in counts.iter_mut() {
+                    let df = document_frequency[term.as_str()] as f64;
+                    *weight *= ((1.0 + n) / (1.0 + df)).ln() + 1.0;
+                }
+                let norm = counts.values().map(|w| w * w).sum::<f64>().sqrt();
+                if norm > 0.0 {
+                    for weight in counts.values_mut() {
+                        *weight /= norm;
+                    }
+                }
+                counts
+            })
+            .collect();
+
+        Self { vectors }
+    }
+
+    /// Cosine similarity between two documents of the index (0.0 to 1.0)
+    pub fn similarity(&self, a: usize, b: usize) -> f64 {
+        let (small, large) = if self.vectors[a].len() <= self.vectors[b].len() {
+            (&self.vectors[a], &self.vectors[b])
+        } else {
+            (&self.vectors[b], &self.vectors[a])
+        };
+        small
+            .iter()
+            .filter_map(|(term, w)| large.get(term).map(|v| w * v))
+            .sum::<f64>()
+            .min(1.0)
+    }
+}
+
+/// Normalize a name: lowercase tokens joined with single spaces (G.REQLIX_I.7)
+#[cfg_attr(test, allow(dead_code))]
+pub fn normalize_name(name: &str) -> String {
+    tokenize(name).join(" ")
+}
+
+/// Character edit (Levenshtein) distance between two strings
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b_chars: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b_chars.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b_chars.len() + 1];
+        for (j, cb) in b_chars.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+    previous[b_chars.len()]
+}
+
+/// Similarity of two names: 1 - edit distance / longer length, after normalization (G.REQLIX_I.7)
+#[cfg_attr(test, allow(dead_code))]
+pub fn name_similarity(a: &str, b: &str) -> f64 {
+    let (a, b) = (normalize_name(a), normalize_name(b));
+    let longest = a.chars().count().max(b.chars().count());
+    if longest == 0 {
+        return 1.0;
+    }
+    1.0 - edit_distance(&a, &b) as f64 / longest as f64
+}
+
+/// Round similarity score for responses (4 decimal places)
+pub fn round_score(score: f64) -> f64 {
+    (score * 10000.0).round() / 10000.0
+}
@@ -0,0 +1,246 @@
+// Resource subscriptions and change detection (G.M.6, G.M.7, G.M.8)
+
+use crate::cache::{content_hash, load_category};
+use crate::helpers::list_categories;
+use crate::models::{RequirementFull, ResourceChanges};
+use crate::resources::ResourceUri;
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{channel, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
+
+/// Quiet period before a change is processed (G.M.7)
+const CHANGE_DEBOUNCE: Duration = Duration::from_millis(100);
+
+/// Parsed state of one category file (G.M.7)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct CategorySnapshot {
+    hash: u64,
+    chapters: Vec<String>,
+    requirements: Vec<RequirementFull>,
+}
+
+/// Parsed state of the requirements directory, compared to detect changes (G.M.7)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ResourceSnapshot {
+    instructions: Option<u64>,
+    categories: BTreeMap<String, CategorySnapshot>,
+}
+
+/// Read the current state of the requirements directory (G.M.7)
+#[cfg_attr(test, allow(dead_code))]
+pub fn resource_snapshot(requirements_dir: &Path) -> Result<ResourceSnapshot, String> {
+    let mut snapshot = ResourceSnapshot {
+        instructions: fs::read(requirements_dir.join("AGENTS.md"))
+            .ok()
+            .map(|bytes| content_hash(&bytes)),
+        ..Default::default()
+    };
+    for category in list_categories(&requirements_dir.to_path_buf())? {
+        let path = requirements_dir.join(format!("{}.md", category));
+        let Ok(bytes) = fs::read(&path) else {
+            // Removed between listing and reading; the next change picks it up
+            continue;
+        };
+        let parsed = load_category(requirements_dir, &category)?;
+        snapshot.categories.insert(
+            category,
+            CategorySnapshot {
+                hash: content_hash(&bytes),
+                chapters: parsed.chapter_names(),
+                requirements: parsed
+                    .search_index
+                    .into_iter()
+                    .map(|e| e.requirement)
+                    .collect(),
+            },
+        );
+    }
+    Ok(snapshot)
+}
+
+impl ResourceSnapshot {
+    /// Requirements by index, first category in alphabetical order wins (G.M.2)
+    fn requirements(&self) -> HashMap<&str, &RequirementFull> {
+        let mut requirements = HashMap::new();
+        for category in self.categories.values() {
+            for requirement in &category.requirements {
+                requirements
+                    .entry(requirement.index.as_str())
+                    .or_insert(requirement);
+            }
+        }
+        requirements
+    }
+
+    /// Requirements of a chapter in file order, None if the chapter does not exist (G.M.2)
+    fn chapter(&self, category: &str, chapter: &str) -> Option<Vec<&RequirementFull>> {
+        let snapshot = self.categories.get(category)?;
+        if !snapshot.chapters.iter().any(|c| c == chapter) {
+            return None;
+        }
+        Some(
+            snapshot
+                .requirements
+                .iter()
+                .filter(|r| r.chapter == chapter)
+                .collect(),
+        )
+    }
+
+    /// All chapters as (category, chapter) pairs in listing order (G.M.3)
+    fn chapters(&self) -> Vec<(&str, &str)> {
+        self.categories
+            .iter()
+            .flat_map(|(category, snapshot)| {
+                snapshot
+                    .chapters
+                    .iter()
+                    .map(move |chapter| (category.as_str(), chapter.as_str()))
+            })
+            .collect()
+    }
+
+    /// All requirement indices in listing order (G.M.3)
+    fn indices(&self) -> Vec<&str> {
+        self.categories
+            .values()
+            .flat_map(|c| c.requirements.iter().map(|r| r.index.as_str()))
+            .collect()
+    }
+}
+
+/// Changed resources and whether the resource list changed between two snapshots (G.M.7)
+#[cfg_attr(test, allow(dead_code))]
+pub fn resource_changes(old: &ResourceSnapshot, new: &ResourceSnapshot) -> ResourceChanges {
+    let mut changes = ResourceChanges::default();
+
+    if old.instructions.is_some()
+        && new.instructions.is_some()
+        && old.instructions != new.instructions
+    {
+        changes.updated.push(ResourceUri::Instructions.uri());
+    }
+    changes.list_changed |= old.instructions.is_some() != new.instructions.is_some();
+
+    for (category, snapshot) in &new.categories {
+        match old.categories.get(category) {
+            Some(previous) if previous.hash != snapshot.hash => changes
+                .updated
+                .push(ResourceUri::Category(category.clone()).uri()),
+            Some(_) => {}
+            None => changes.list_changed = true,
+        }
+    }
+    changes.list_changed |= old
+        .categories
+        .keys()
+        .any(|category| !new.categories.contains_key(category));
+
+    let old_chapters = old.chapters();
+    let new_chapters = new.chapters();
+    for &(category, chapter) in &new_chapters {
+        match old.chapter(category, chapter) {
+            previous @ Some(_) if previous != new.chapter(category, chapter) => changes
+                .updated
+                .push(ResourceUri::Chapter(category.to_string(), chapter.to_string()).uri()),
+            Some(_) => {}
+            None => changes.list_changed = true,
+        }
+    }
+    changes.list_changed |= old_chapters.iter().any(|c| !new_chapters.contains(c));
+
+    let old_requirements = old.requirements();
+    let new_requirements = new.requirements();
+    for index in new.indices() {
+        // Repeated indices are reported once, for the first occurrence
+        let uri = ResourceUri::Requirement(index.to_string()).uri();
+        if changes.updated.contains(&uri) {
+            continue;
+        }
+        match old_requirements.get(index) {
+            Some(previous) if *previous != new_requirements[index] => changes.updated.push(uri),
+            Some(_) => {}
+            None => changes.list_changed = true,
+        }
+    }
+    changes.list_changed |= old_requirements
+        .keys()
+        .any(|index| !new_requirements.contains_key(index));
+
+    changes
+}
+
+/// Watch on a requirements directory that reports resource changes (G.M.6, G.M.7)
+pub struct RequirementsWatcher {
+    _watcher: RecommendedWatcher,
+}
+
+impl std::fmt::Debug for RequirementsWatcher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RequirementsWatcher")
+            .finish_non_exhaustive()
+    }
+}
+
+impl RequirementsWatcher {
+    /// Watch `.md` files of the requirements directory and call `on_change` for every detected change.
+    /// The watch stops when the watcher is dropped (G.M.6).
+    pub fn start<F>(requirements_dir: PathBuf, on_change: F) -> Result<Self, String>
+    where
+        F: Fn(ResourceChanges) + Send + 'static,
+    {
+        let mut snapshot = resource_snapshot(&requirements_dir)?;
+        let (sender, receiver) = channel();
+        let mut watcher =
+            notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+                if let Ok(event) = event {
+                    if event
+                        .paths
+                        .iter()
+                        .any(|p| p.extension().is_some_and(|e| e == "md"))
+                    {
+                        let _ = sender.send(());
+                    }
+                }
+            })
+            .map_err(|e| format!("Failed to watch requirements directory: {}", e))?;
+        watcher
+            .watch(&requirements_dir, RecursiveMode::NonRecursive)
+            .map_err(|e| format!("Failed to watch requirements directory: {}", e))?;
+
+        thread::spawn(move || {
+            // Ends when the watcher and with it the sender are dropped
+            while receiver.recv().is_ok() {
+                // G.M.7: Wait for a quiet period
+                loop {
+                    match receiver.recv_timeout(CHANGE_DEBOUNCE) {
+                        Ok(()) => continue,
+                        Err(RecvTimeoutError::Timeout) => break,
+                        Err(RecvTimeoutError::Disconnected) => return,
+                    }
+                }
+                let Ok(current) = resource_snapshot(&requirements_dir) else {
+                    continue;
+                };
+                let changes = resource_changes(&snapshot, &current);
+                snapshot = current;
+                if !changes.updated.is_empty() || changes.list_changed {
+                    on_change(changes);
+                }
+            }
+        });
+
+        Ok(Self { _watcher: watcher })
+    }
+}
+
+/// Subscribed resource URIs of a session and the watch that serves them (G.M.6)
+#[derive(Debug, Default)]
+pub struct Subscriptions {
+    pub uris: HashSet<String>,
+    pub watcher: Option<RequirementsWatcher>,
+}
@@ -0,0 +1,113 @@
+// Test-to-requirement mapping from test comments (G.TE.2, G.TOOLREQLIXTE.3)
+
+use crate::filesystem::existing_requirements_dir;
+use crate::models::{TestFunction, TestMap};
+use crate::trace::{collect_requirements, find_index_references, source_files, TraceOptions};
+use regex::Regex;
+use std::collections::HashSet;
+use std::path::Path;
+use std::sync::OnceLock;
+
+/// Function declaration: optional visibility and async, then `fn name` (G.TOOLREQLIXTE.3)
+fn function_pattern() -> &'static Regex {
+    static PATTERN: OnceLock<Regex> = OnceLock::new();
+    PATTERN.get_or_init(|| {
+        Regex::new(r"^(?:pub(?:\([^)]*\))?\s+)?(?:async\s+)?fn\s+([A-Za-z_][A-Za-z0-9_]*)")
+            .expect("valid function pattern")
+    })
+}
+
+/// Test attribute: `#[test]` or a path ending in `::test`, with optional arguments (G.TOOLREQLIXTE.3)
+fn is_test_attribute(line: &str) -> bool {
+    let Some(inner) = line.strip_prefix("#[").and_then(|l| l.strip_suffix(']')) else {
+        return false;
+    };
+    let path = inner.split('(').next().unwrap_or_default().trim();
+    path == "test" || path.ends_with("::test")
+}
+
+/// Indices listed on a "Covers Requirement:" comment line (G.TE.2)
+fn covered_indices(comment: &str) -> Vec<String> {
+    ["Covers Requirement:", "Covers Requirements:"]
+        .iter()
+        .find_map(|marker| comment.split_once(marker))
+        .map(|(_, rest)| find_index_references(rest))
+        .unwrap_or_default()
+}
+
+/// Test functions of a Rust file with the indices from their preceding comments (G.TOOLREQLIXTE.3 step 3)
+#[cfg_attr(test, allow(dead_code))]
+pub fn parse_test_functions(file: &str, content: &str) -> Vec<TestFunction> {
+    let mut tests = Vec::new();
+    let mut requirements: Vec<String> = Vec::new();
+    let mut is_test = false;
+    for (number, line) in content.lines().enumerate() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+        if trimmed.starts_with("//") {
+            for index in covered_indices(trimmed) {
+                if !requirements.contains(&index) {
+                    requirements.push(index);
+                }
+            }
+            continue;
+        }
+        if trimmed.starts_with("#[") {
+            is_test |= is_test_attribute(trimmed);
+            continue;
+        }
+        if is_test {
+            if let Some(name) = function_pattern().captures(trimmed).and_then(|c| c.get(1)) {
+                tests.push(TestFunction {
+                    name: name.as_str().to_string(),
+                    file: file.to_string(),
+                    line: number + 1,
+                    requirements: std::mem::take(&mut requirements),
+                });
+            }
+        }
+        requirements.clear();
+        is_test = false;
+    }
+    tests
+}
+
+/// Map test functions to requirements and find the gaps on both sides (G.TOOLREQLIXTE.3)
+#[cfg_attr(test, allow(dead_code))]
+pub fn map_tests(project_root: &str, options: &TraceOptions) -> Result<TestMap, String> {
+    let requirements_dir = existing_requirements_dir(project_root)?;
+
+    // G.TOOLREQLIXTE.3 step 2: Collect requirements
+    let requirements = collect_requirements(&requirements_dir)?;
+
+    // G.TOOLREQLIXTE.3 step 3: Parse Rust files
+    let mut map = TestMap::default();
+    for (file, _, content) in source_files(Path::new(project_root), &requirements_dir, options)? {
+        if !file.ends_with(".rs") {
+            continue;
+        }
+        map.files_scanned += 1;
+        map.tests.extend(parse_test_functions(&file, &content));
+    }
+
+    // G.TOOLREQLIXTE.3 step 4: Find gaps
+    let covered: HashSet<&str> = map
+        .tests
+        .iter()
+        .flat_map(|t| t.requirements.iter().map(String::as_str))
+        .collect();
+    map.untested_requirements = requirements
+        .into_iter()
+        .filter(|r| !covered.contains(r.index.as_str()))
+        .collect();
+    map.unreferenced_tests = map
+        .tests
+        .iter()
+        .filter(|t| t.requirements.is_empty())
+        .cloned()
+        .collect();
+
+    Ok(map)
+}
@@ -0,0 +1,246 @@
+// Source-code traceability scan (G.TR.1, G.TR.2, G.TR.3, G.TR.4, G.TR.5, G.TOOLREQLIXT.3)
+
+use crate::cache::load_category;
+use crate::filesystem::existing_requirements_dir;
+use crate::helpers::list_categories;
+use crate::models::{
+    CodeLocation, IndexReference, ReferenceScan, RequirementLocation, TraceReport,
+    TracedRequirement,
+};
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+use ignore::WalkBuilder;
+use regex::Regex;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+/// Include and exclude glob patterns for the scan (G.TR.3, G.TR.5)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TraceOptions {
+    pub include: Vec<String>,
+    pub exclude: Vec<String>,
+}
+
+/// Index token pattern (G.TR.1). Boundaries are checked separately.
+fn index_pattern() -> &'static Regex {
+    static PATTERN: OnceLock<Regex> = OnceLock::new();
+    PATTERN.get_or_init(|| {
+        Regex::new(r"[A-Z][A-Z0-9_]*\.[A-Z][A-Z0-9_]*\.[0-9]+").expect("valid index pattern")
+    })
+}
+
+/// Check whether a byte can be part of an index token (G.TR.1)
+fn is_token_byte(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || byte == b'_'
+}
+
+/// Index references in a line with their byte offsets, in order of appearance (G.TR.1)
+pub(crate) fn index_reference_matches(line: &str) -> Vec<(usize, &str)> {
+    let bytes = line.as_bytes();
+    index_pattern()
+        .find_iter(line)
+        .filter(|m| {
+            let before = m.start() > 0 && is_token_byte(bytes[m.start() - 1]);
+            let after = m.end() < bytes.len() && is_token_byte(bytes[m.end()]);
+            !before && !after
+        })
+        .map(|m| (m.start(), m.as_str()))
+        .collect()
+}
+
+/// Find all index references in a line of text, in order of appearance (G.TR.1)
+#[cfg_attr(test, allow(dead_code))]
+pub fn find_index_references(line: &str) -> Vec<String> {
+    index_reference_matches(line)
+        .into_iter()
+        .map(|(_, index)| index.to_string())
+        .collect()
+}
+
+/// Compile glob patterns into a set; `name` is used in error messages (G.TR.3, G.TOOLREQLIXT.5)
+pub fn build_glob_set(name: &str, patterns: &[String]) -> Result<GlobSet, String> {
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        let glob = GlobBuilder::new(pattern)
+            .literal_separator(true)
+            .build()
+            .map_err(|e| format!("Invalid {} pattern '{}': {}", name, pattern, e.kind()))?;
+        builder.add(glob);
+    }
+    builder
+        .build()
+        .map_err(|e| format!("Invalid {} pattern: {}", name, e))
+}
+
+/// Path relative to the project root with `/` separators (G.TR.3, G.TR.4)
+pub(crate) fn relative_path(project_root: &Path, path: &Path) -> Option<String> {
+    let relative = path.strip_prefix(project_root).ok()?;
+    Some(
+        relative
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/"),
+    )
+}
+
+/// Readable UTF-8 source files under the project root in scan order, with their relative paths and
+/// contents (G.TR.2, G.TR.3)
+pub(crate) fn source_files(
+    project_root: &Path,
+    requirements_dir: &Path,
+    options: &TraceOptions,
+) -> Result<Vec<(String, PathBuf, String)>, String> {
+    if !project_root.is_dir() {
+        return Err(format!(
+            "Project root is not a directory: {}",
+            project_root.to_string_lossy()
+        ));
+    }
+    let include = build_glob_set("include", &options.include)?;
+    let exclude = build_glob_set("exclude", &options.exclude)?;
+
+    // G.TR.2: Skip the requirements directory, compared by canonical path
+    let skipped_dir = fs::canonicalize(requirements_dir).unwrap_or(requirements_dir.to_path_buf());
+    let root = project_root.to_path_buf();
+    let walker = WalkBuilder::new(project_root)
+        .hidden(true)
+        .parents(false)
+        .ignore(true)
+        .git_ignore(true)
+        .git_exclude(true)
+        .git_global(false)
+        .require_git(false)
+        .follow_links(false)
+        .sort_by_file_name(|a, b| a.cmp(b))
+        .filter_entry(move |entry| {
+            if entry.file_type().is_some_and(|t| t.is_dir())
+                && fs::canonicalize(entry.path()).is_ok_and(|p| p == skipped_dir)
+            {
+                return false;
+            }
+            // G.TR.3: Exclude patterns apply to files and whole directories
+            match relative_path(&root, entry.path()) {
+                Some(relative) if !relative.is_empty() => !exclude.is_match(&relative),
+                _ => true,
+            }
+        })
+        .build();
+
+    let mut files = Vec::new();
+    for entry in walker.flatten() {
+        if !entry.file_type().is_some_and(|t| t.is_file()) {
+            continue;
+        }
+        let Some(file) = relative_path(project_root, entry.path()) else {
+            continue;
+        };
+        if !options.include.is_empty() && !include.is_match(&file) {
+            continue;
+        }
+        // G.TR.2: Skip unreadable and non-UTF-8 files
+        let Ok(content) = fs::read_to_string(entry.path()) else {
+            continue;
+        };
+        files.push((file, entry.path().to_path_buf(), content));
+    }
+
+    Ok(files)
+}
+
+/// Scan source files under the project root for index references (G.TR.2, G.TR.3, G.TR.4, G.TR.5)
+#[cfg_attr(test, allow(dead_code))]
+pub fn scan_references(
+    project_root: &Path,
+    requirements_dir: &Path,
+    options: &TraceOptions,
+) -> Result<ReferenceScan, String> {
+    let mut scan = ReferenceScan::default();
+    for (file, _, content) in source_files(project_root, requirements_dir, options)? {
+        scan.files_scanned += 1;
+
+        for (number, line) in content.lines().enumerate() {
+            for index in find_index_references(line) {
+                scan.references.push(IndexReference {
+                    index,
+                    file: file.clone(),
+                    line: number + 1,
+                });
+            }
+        }
+    }
+
+    Ok(scan)
+}
+
+/// Collect every requirement in category and file order; first occurrence of each index wins
+/// (G.TOOLREQLIXT.3 step 2)
+pub(crate) fn collect_requirements(
+    requirements_dir: &PathBuf,
+) -> Result<Vec<RequirementLocation>, String> {
+    let mut requirements: Vec<RequirementLocation> = Vec::new();
+    for category in list_categories(requirements_dir)? {
+        let parsed = load_category(requirements_dir, &category)?;
+        for chapter in parsed.chapters {
+            for summary in chapter.requirements {
+                if requirements.iter().any(|r| r.index == summary.index) {
+                    continue;
+                }
+                requirements.push(RequirementLocation {
+                    index: summary.index,
+                    title: summary.title,
+                    category: category.clone(),
+                    chapter: chapter.name.clone(),
+                });
+            }
+        }
+    }
+    Ok(requirements)
+}
+
+/// Trace every requirement to the source locations that cite it (G.TR.5, G.TOOLREQLIXT.3)
+#[cfg_attr(test, allow(dead_code))]
+pub fn trace_requirements(
+    project_root: &str,
+    options: &TraceOptions,
+) -> Result<TraceReport, String> {
+    let requirements_dir = existing_requirements_dir(project_root)?;
+
+    // G.TOOLREQLIXT.3 step 2: Collect requirements through the cache (G.CA.5)
+    let mut requirements: Vec<TracedRequirement> = collect_requirements(&requirements_dir)?
+        .into_iter()
+        .map(|r| TracedRequirement {
+            index: r.index,
+            title: r.title,
+            category: r.category,
+            chapter: r.chapter,
+            locations: Vec::new(),
+        })
+        .collect();
+
+    // G.TOOLREQLIXT.3 step 3: Scan references
+    let scan = scan_references(Path::new(project_root), &requirements_dir, options)?;
+
+    // G.TOOLREQLIXT.3 step 4: Group locations by index
+    let mut locations: HashMap<String, Vec<CodeLocation>> = HashMap::new();
+    for reference in scan.references {
+        locations
+            .entry(reference.index)
+            .or_default()
+            .push(CodeLocation {
+                file: reference.file,
+                line: reference.line,
+            });
+    }
+    for requirement in &mut requirements {
+        if let Some(found) = locations.remove(&requirement.index) {
+            requirement.locations = found;
+        }
+    }
+
+    Ok(TraceReport {
+        files_scanned: scan.files_scanned,
+        requirements,
+    })
+}
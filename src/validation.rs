@@ -1,9 +1,34 @@
-// Parameter validation (G.P.2, G.P.3)
+// Parameter validation (G.P.2, G.P.3, G.SE.4, G.SE.5)
 
+use crate::config::ProjectConfig;
 use crate::constants::*;
-use crate::params::KeywordsParam;
+use crate::filesystem::validate_relative_path;
+use crate::params::{IndexMapping, KeywordsParam, RelationsParam};
+use crate::trace::{build_glob_set, find_index_references};
 use pulldown_cmark::{Event, HeadingLevel, Parser, Tag};
 
+/// Built-in category character set, extended by naming.extra_category_characters (G.P.3, G.SE.5)
+fn is_category_char(c: char, extra: &str) -> bool {
+    c.is_ascii_lowercase()
+        || c.is_ascii_digit()
+        || CATEGORY_PUNCTUATION.contains(c)
+        || extra.contains(c)
+}
+
+/// Built-in chapter character set, extended by naming.extra_chapter_characters (G.P.3, G.SE.5)
+fn is_chapter_char(c: char, extra: &str) -> bool {
+    c.is_ascii_alphanumeric() || CHAPTER_PUNCTUATION.contains(c) || extra.contains(c)
+}
+
+/// Description of the configured extra characters for error messages (G.SE.5)
+fn extra_characters_note(key: &str, extra: &str) -> String {
+    if extra.is_empty() {
+        String::new()
+    } else {
+        format!(" or characters of naming.{} ({})", key, extra)
+    }
+}
+
 /// Validate project_root parameter (G.P.1, G.P.2)
 #[cfg_attr(test, allow(dead_code))]
 pub fn validate_project_root(value: &str) -> Result<(), String> {
@@ -37,6 +62,11 @@ pub fn validate_operation_description(value: &str) -> Result<(), String> {
 /// Validate category parameter (G.P.1, G.P.3)
 #[cfg_attr(test, allow(dead_code))]
 pub fn validate_category(value: &str) -> Result<(), String> {
+    validate_category_in(value, &ProjectConfig::default())
+}
+
+/// Validate category parameter with the naming rules of the project (G.P.3, G.SE.5)
+pub fn validate_category_in(value: &str, config: &ProjectConfig) -> Result<(), String> {
     // Basic constraints (G.P.1)
     if value.is_empty() {
         return Err("category is required".to_string());
@@ -54,17 +84,31 @@ pub fn validate_category(value: &str) -> Result<(), String> {
         return Err("category name must not start or end with whitespace".to_string());
     }
 
-    // Must contain only lowercase English letters (a-z) and underscore (_)
-    if !value.chars().all(|c| c.is_ascii_lowercase() || c == '_') {
-        return Err(
-            "category name must contain only lowercase English letters (a-z) and underscore (_)"
-                .to_string(),
-        );
+    // Must contain only lowercase English letters (a-z), digits (0-9) and underscore (_)
+    // plus naming.extra_category_characters, and at least one letter,
+    // or match naming.category_pattern of reqlix.toml instead (G.SE.5)
+    if let Some(pattern) = config.naming.category_regex() {
+        if !pattern.is_match(value) {
+            return Err(format!(
+                "category name must match naming.category_pattern: {}",
+                pattern.as_str()
+            ));
+        }
+    } else {
+        let extra = &config.naming.extra_category_characters;
+        if !value.chars().all(|c| is_category_char(c, extra)) {
+            return Err(format!(
+                "category name must contain only lowercase English letters (a-z), digits (0-9) and underscore (_){}",
+                extra_characters_note("extra_category_characters", extra)
+            ));
+        }
+        if !value.chars().any(|c| c.is_ascii_lowercase()) {
+            return Err("category name must contain at least one letter (a-z)".to_string());
+        }
     }
 
     // Must be a valid filename (cannot contain invalid characters)
-    let invalid_chars = ['/', '\\', ':', '*', '?', '"', '<', '>', '|'];
-    if let Some(ch) = value.chars().find(|c| invalid_chars.contains(c)) {
+    if let Some(ch) = value.chars().find(|c| INVALID_FILENAME_CHARS.contains(c)) {
         return Err(format!(
             "category name contains invalid character: '{}' (invalid for filename)",
             ch
@@ -92,6 +136,11 @@ pub fn validate_category(value: &str) -> Result<(), String> {
 /// Validate chapter parameter (G.P.1, G.P.3)
 #[cfg_attr(test, allow(dead_code))]
 pub fn validate_chapter(value: &str) -> Result<(), String> {
+    validate_chapter_in(value, &ProjectConfig::default())
+}
+
+/// Validate chapter parameter with the naming rules of the project (G.P.3, G.SE.5)
+pub fn validate_chapter_in(value: &str, config: &ProjectConfig) -> Result<(), String> {
     // Basic constraints (G.P.1)
     if value.is_empty() {
         return Err("chapter is required".to_string());
@@ -109,12 +158,27 @@ pub fn validate_chapter(value: &str) -> Result<(), String> {
         return Err("chapter name must not start or end with whitespace".to_string());
     }
 
-    // Must contain only uppercase and lowercase English letters (A-Z, a-z), spaces, colons (:), hyphens (-), and underscores (_) - G.P.3
-    if !value
-        .chars()
-        .all(|c| c.is_ascii_alphabetic() || c == ' ' || c == ':' || c == '-' || c == '_')
-    {
-        return Err("chapter name must contain only uppercase and lowercase English letters (A-Z, a-z), spaces, colons (:), hyphens (-), and underscores (_)".to_string());
+    // Must contain only uppercase and lowercase English letters (A-Z, a-z), digits (0-9), spaces, colons (:), hyphens (-), and underscores (_) - G.P.3
+    // plus naming.extra_chapter_characters, and at least one letter
+    // With naming.chapter_pattern of reqlix.toml the pattern replaces the character set (G.SE.5)
+    if let Some(pattern) = config.naming.chapter_regex() {
+        if !pattern.is_match(value) {
+            return Err(format!(
+                "chapter name must match naming.chapter_pattern: {}",
+                pattern.as_str()
+            ));
+        }
+    } else {
+        let extra = &config.naming.extra_chapter_characters;
+        if !value.chars().all(|c| is_chapter_char(c, extra)) {
+            return Err(format!(
+                "chapter name must contain only uppercase and lowercase English letters (A-Z, a-z), digits (0-9), spaces, colons (:), hyphens (-), and underscores (_){}",
+                extra_characters_note("extra_chapter_characters", extra)
+            ));
+        }
+        if !value.chars().any(|c| c.is_ascii_alphabetic()) {
+            return Err("chapter name must contain at least one letter (A-Z, a-z)".to_string());
+        }
     }
 
     // Must not contain newline characters (would break markdown heading structure)
@@ -169,13 +233,18 @@ pub fn validate_index(value: &str) -> Result<(), String> {
 /// Validate text parameter (G.P.1, G.P.2)
 #[cfg_attr(test, allow(dead_code))]
 pub fn validate_text(value: &str) -> Result<(), String> {
+    validate_text_in(value, &ProjectConfig::default())
+}
+
+/// Validate text parameter with the limits of the project (G.P.2, G.SE.4)
+pub fn validate_text_in(value: &str, config: &ProjectConfig) -> Result<(), String> {
     if value.is_empty() {
         return Err("text is required".to_string());
     }
-    if value.len() > MAX_TEXT_LEN {
+    if value.len() > config.limits.max_text_len {
         return Err(format!(
             "text exceeds maximum length of {} characters",
-            MAX_TEXT_LEN
+            config.limits.max_text_len
         ));
     }
     Ok(())
@@ -184,13 +253,22 @@ pub fn validate_text(value: &str) -> Result<(), String> {
 /// Validate title parameter (G.P.1, G.P.2, G.P.3)
 #[cfg_attr(test, allow(dead_code))]
 pub fn validate_title(value: &str, required: bool) -> Result<(), String> {
+    validate_title_in(value, required, &ProjectConfig::default())
+}
+
+/// Validate title parameter with the limits of the project (G.P.2, G.P.3, G.SE.4)
+pub fn validate_title_in(
+    value: &str,
+    required: bool,
+    config: &ProjectConfig,
+) -> Result<(), String> {
     if required && value.is_empty() {
         return Err("title is required".to_string());
     }
-    if value.len() > MAX_TITLE_LEN {
+    if value.len() > config.limits.max_title_len {
         return Err(format!(
             "title exceeds maximum length of {} characters",
-            MAX_TITLE_LEN
+            config.limits.max_title_len
         ));
     }
 
@@ -234,14 +312,25 @@ pub fn validate_title(value: &str, required: bool) -> Result<(), String> {
 /// Returns filtered non-empty keywords or error
 #[cfg_attr(test, allow(dead_code))]
 pub fn validate_keywords(keywords: &KeywordsParam) -> Result<Vec<String>, String> {
+    validate_keywords_in(keywords, &ProjectConfig::default())
+}
+
+/// Validate keywords parameter with the batch limit of the project (G.TOOLREQLIXS.5, G.SE.4)
+pub fn validate_keywords_in(
+    keywords: &KeywordsParam,
+    config: &ProjectConfig,
+) -> Result<Vec<String>, String> {
     let keywords_vec = match keywords {
         KeywordsParam::Single(s) => vec![s.clone()],
         KeywordsParam::Batch(v) => v.clone(),
     };
 
     // G.TOOLREQLIXS.5: Maximum 100 keywords
-    if keywords_vec.len() > MAX_BATCH_SIZE {
-        return Err("Keywords count exceeds maximum limit of 100".to_string());
+    if keywords_vec.len() > config.limits.max_batch_size {
+        return Err(format!(
+            "Keywords count exceeds maximum limit of {}",
+            config.limits.max_batch_size
+        ));
     }
 
     // G.TOOLREQLIXS.5: Validate each keyword length and filter empty strings
@@ -261,3 +350,190 @@ pub fn validate_keywords(keywords: &KeywordsParam) -> Result<Vec<String>, String
 
     Ok(filtered)
 }
+
+/// Validate similarity threshold parameter (G.P.1, G.TOOLREQLIXF.5)
+#[cfg_attr(test, allow(dead_code))]
+pub fn validate_threshold(value: f64) -> Result<(), String> {
+    if !(0.0..=1.0).contains(&value) {
+        return Err("threshold must be between 0.0 and 1.0".to_string());
+    }
+    Ok(())
+}
+
+/// Validate suggestion limit parameter (G.P.1, G.TOOLREQLIXSU.5)
+#[cfg_attr(test, allow(dead_code))]
+pub fn validate_limit(value: usize) -> Result<(), String> {
+    if !(1..=MAX_SUGGESTION_LIMIT).contains(&value) {
+        return Err(format!(
+            "limit must be between 1 and {}",
+            MAX_SUGGESTION_LIMIT
+        ));
+    }
+    Ok(())
+}
+
+/// Validate glob pattern list parameter; `name` is the parameter name (G.P.1, G.TOOLREQLIXT.5, G.TOOLREQLIXC.8)
+#[cfg_attr(test, allow(dead_code))]
+pub fn validate_globs(name: &str, patterns: &[String]) -> Result<(), String> {
+    validate_globs_in(name, patterns, &ProjectConfig::default())
+}
+
+/// Validate glob pattern list parameter with the limits of the project (G.P.1, G.SE.4)
+pub fn validate_globs_in(
+    name: &str,
+    patterns: &[String],
+    config: &ProjectConfig,
+) -> Result<(), String> {
+    let max_batch_size = config.limits.max_batch_size;
+    if patterns.len() > max_batch_size {
+        return Err(format!(
+            "{} exceeds maximum of {} patterns",
+            name, max_batch_size
+        ));
+    }
+    for pattern in patterns {
+        if pattern.len() > MAX_GLOB_LEN {
+            return Err(format!(
+                "{} pattern exceeds maximum length of {} characters",
+                name, MAX_GLOB_LEN
+            ));
+        }
+    }
+    build_glob_set(name, patterns).map(|_| ())
+}
+
+/// Validate output format parameter against the allowed values (G.P.1, G.TOOLREQLIXC.8)
+#[cfg_attr(test, allow(dead_code))]
+pub fn validate_format(value: &str, allowed: &[&str]) -> Result<(), String> {
+    if !allowed.contains(&value) {
+        return Err(format!("format must be one of: {}", allowed.join(", ")));
+    }
+    Ok(())
+}
+
+/// Validate relations parameter: array sizes and indices (G.P.1, G.RELA.4)
+#[cfg_attr(test, allow(dead_code))]
+pub fn validate_relations(relations: &RelationsParam) -> Result<(), String> {
+    validate_relations_in(relations, &ProjectConfig::default())
+}
+
+/// Validate relations parameter with the limits of the project (G.RELA.4, G.SE.4)
+pub fn validate_relations_in(
+    relations: &RelationsParam,
+    config: &ProjectConfig,
+) -> Result<(), String> {
+    let max_batch_size = config.limits.max_batch_size;
+    for (relation, targets) in relations.entries() {
+        if targets.len() > max_batch_size {
+            return Err(format!(
+                "relations.{} exceeds maximum of {} indices",
+                relation, max_batch_size
+            ));
+        }
+        for target in targets {
+            validate_index(target)?;
+        }
+    }
+    Ok(())
+}
+
+/// Validate index mappings: size, indices and unique old indices (G.P.1, G.TOOLREQLIXR.5)
+#[cfg_attr(test, allow(dead_code))]
+pub fn validate_mappings(mappings: &[IndexMapping]) -> Result<(), String> {
+    validate_mappings_in(mappings, &ProjectConfig::default())
+}
+
+/// Validate index mappings with the limits of the project (G.TOOLREQLIXR.5, G.SE.4)
+pub fn validate_mappings_in(
+    mappings: &[IndexMapping],
+    config: &ProjectConfig,
+) -> Result<(), String> {
+    let max_batch_size = config.limits.max_batch_size;
+    if mappings.len() > max_batch_size {
+        return Err(format!(
+            "mappings exceeds maximum of {} items",
+            max_batch_size
+        ));
+    }
+    for (position, mapping) in mappings.iter().enumerate() {
+        for index in [&mapping.from, &mapping.to] {
+            validate_index(index)?;
+            if find_index_references(index) != [index.as_str()] {
+                return Err(format!("Invalid index format: {}", index));
+            }
+        }
+        if mappings[..position].iter().any(|m| m.from == mapping.from) {
+            return Err(format!("Duplicate mapping for index: {}", mapping.from));
+        }
+    }
+    Ok(())
+}
+
+/// Validate file parameter (G.P.1, G.P.6, G.TOOLREQLIXIMPO.5)
+#[cfg_attr(test, allow(dead_code))]
+pub fn validate_file(value: &str) -> Result<(), String> {
+    if value.is_empty() {
+        return Err("file is required".to_string());
+    }
+    if value.len() > MAX_FILE_PATH_LEN {
+        return Err(format!(
+            "file exceeds maximum length of {} characters",
+            MAX_FILE_PATH_LEN
+        ));
+    }
+    validate_relative_path("file", value)
+}
+
+/// Validate output parameter (G.P.1, G.P.6, G.TOOLREQLIXE.6)
+#[cfg_attr(test, allow(dead_code))]
+pub fn validate_output(value: &str) -> Result<(), String> {
+    if value.is_empty() {
+        return Err("output must not be empty".to_string());
+    }
+    if value.len() > MAX_FILE_PATH_LEN {
+        return Err(format!(
+            "output exceeds maximum length of {} characters",
+            MAX_FILE_PATH_LEN
+        ));
+    }
+    validate_relative_path("output", value)
+}
+
+/// Validate neighborhood radius parameter (G.P.1, G.TOOLREQLIXEXPORTG.6)
+#[cfg_attr(test, allow(dead_code))]
+pub fn validate_radius(value: usize) -> Result<(), String> {
+    if value > MAX_GRAPH_RADIUS {
+        return Err(format!("radius must be between 0 and {}", MAX_GRAPH_RADIUS));
+    }
+    Ok(())
+}
+
+/// Validate diff prompt argument (G.P.1, G.MCPP.2)
+#[cfg_attr(test, allow(dead_code))]
+pub fn validate_diff(value: &str) -> Result<(), String> {
+    if value.is_empty() {
+        return Err("diff is required".to_string());
+    }
+    if value.len() > MAX_DIFF_LEN {
+        return Err(format!(
+            "diff exceeds maximum length of {} characters",
+            MAX_DIFF_LEN
+        ));
+    }
+    Ok(())
+}
+
+/// Validate feature prompt argument (G.P.1, G.MCPP.2)
+#[cfg_attr(test, allow(dead_code))]
+pub fn validate_feature(value: &str) -> Result<(), String> {
+    if value.is_empty() {
+        return Err("feature is required".to_string());
+    }
+    if value.len() > MAX_TEXT_LEN {
+        return Err(format!(
+            "feature exceeds maximum length of {} characters",
+            MAX_TEXT_LEN
+        ));
+    }
+    Ok(())
+}
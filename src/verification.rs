@@ -0,0 +1,250 @@
+// Test result import and requirement verification status (G.TOOLREQLIXIMPO.3, G.TOOLREQLIXIMPO.4)
+
+use crate::constants::VERIFICATION_FILE;
+use crate::filesystem::{
+    get_requirements_dir, read_file_utf8, resolve_project_path, write_file_utf8,
+};
+use crate::models::{RequirementVerification, TestOutcome, VerificationReport, VerifiedTest};
+use crate::testmap::map_tests;
+use crate::trace::{collect_requirements, TraceOptions};
+use std::collections::HashMap;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Parse JUnit XML test results (G.TOOLREQLIXIMPO.3 step 2)
+#[cfg_attr(test, allow(dead_code))]
+pub fn parse_junit(content: &str) -> Result<Vec<TestOutcome>, String> {
+    let document =
+        roxmltree::Document::parse(content).map_err(|e| format!("Invalid JUnit XML: {}", e))?;
+    Ok(document
+        .descendants()
+        .filter(|n| n.has_tag_name("testcase"))
+        .map(|case| {
+            let child = |tag: &str| case.children().any(|c| c.has_tag_name(tag));
+            let result = if child("failure") || child("error") {
+                "failed"
+            } else if child("skipped") {
+                "skipped"
+            } else {
+                "passed"
+            };
+            let name = case.attribute("name").unwrap_or_default();
+            let name = match case.attribute("classname") {
+                Some(classname) if !classname.is_empty() => format!("{}::{}", classname, name),
+                _ => name.to_string(),
+            };
+            TestOutcome {
+                name,
+                result: result.to_string(),
+            }
+        })
+        .collect())
+}
+
+/// Parse libtest JSON lines; lines that are not test results are ignored (G.TOOLREQLIXIMPO.3 step 2)
+#[cfg_attr(test, allow(dead_code))]
+pub fn parse_libtest(content: &str) -> Vec<TestOutcome> {
+    content
+        .lines()
+        .filter_map(|line| serde_json::from_str::<serde_json::Value>(line.trim()).ok())
+        .filter(|event| event["type"] == "test")
+        .filter_map(|event| {
+            let result = match event["event"].as_str()? {
+                "ok" => "passed",
+                "failed" | "timeout" => "failed",
+                "ignored" => "skipped",
+                _ => return None,
+            };
+            Some(TestOutcome {
+                name: event["name"].as_str()?.to_string(),
+                result: result.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Detect the result format: XML starts with `<`, anything else is libtest JSON (G.TOOLREQLIXIMPO.3 step 2)
+fn detect_format(content: &str) -> &'static str {
+    if content.trim_start().starts_with('<') {
+        "junit"
+    } else {
+        "libtest"
+    }
+}
+
+/// Path segments of a reported test name, split at `::` and `.`; the last one is the function name
+/// (G.TOOLREQLIXIMPO.3 step 4)
+fn name_segments(name: &str) -> Vec<&str> {
+    name.split("::").flat_map(|part| part.split('.')).collect()
+}
+
+/// Module of a test function: the file stem, or the directory name for `mod.rs`, `lib.rs` and `main.rs`
+/// (G.TOOLREQLIXIMPO.3 step 4)
+fn test_module(file: &str) -> &str {
+    let path = Path::new(file);
+    let stem = path
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or_default();
+    if matches!(stem, "mod" | "lib" | "main") {
+        if let Some(dir) = path
+            .parent()
+            .and_then(|p| p.file_name())
+            .and_then(|n| n.to_str())
+        {
+            return dir;
+        }
+    }
+    stem
+}
+
+/// Result rank when a function matches more than one result: failed wins over passed over skipped
+fn result_rank(result: &str) -> u8 {
+    match result {
+        "failed" => 2,
+        "passed" => 1,
+        _ => 0,
+    }
+}
+
+/// Verification status from the results of the linked tests (G.TOOLREQLIXIMPO.3 step 5)
+fn verification_status(tests: &[VerifiedTest]) -> &'static str {
+    if tests.iter().any(|t| t.result == "failed") {
+        "failing"
+    } else if !tests.is_empty() && tests.iter().all(|t| t.result == "passed") {
+        "verified"
+    } else {
+        "unverified"
+    }
+}
+
+/// Current time as an RFC 3339 UTC timestamp with second precision (G.TOOLREQLIXIMPO.3 step 6)
+fn utc_timestamp(time: SystemTime) -> String {
+    let seconds = time.duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+    let (days, rest) = (seconds / 86_400, seconds % 86_400);
+    // Civil date from days since 1970-01-01 (Howard Hinnant's algorithm)
+    let z = days as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        rest / 3_600,
+        rest % 3_600 / 60,
+        rest % 60
+    )
+}
+
+/// Import test results, compute verification status and store it (G.TOOLREQLIXIMPO.3)
+#[cfg_attr(test, allow(dead_code))]
+pub fn import_test_results(
+    project_root: &str,
+    file: &str,
+    format: Option<&str>,
+    options: &TraceOptions,
+) -> Result<VerificationReport, String> {
+    let requirements_dir = get_requirements_dir(project_root)?;
+
+    // G.TOOLREQLIXIMPO.3 step 2: Read results
+    let path = resolve_project_path(project_root, "file", file)?;
+    let content = read_file_utf8(&path)?;
+    let format = format.unwrap_or_else(|| detect_format(&content));
+    let outcomes = match format {
+        "junit" => parse_junit(&content)?,
+        _ => parse_libtest(&content),
+    };
+    let mut reported: HashMap<&str, Vec<(Vec<&str>, &str)>> = HashMap::new();
+    for outcome in &outcomes {
+        let mut segments = name_segments(&outcome.name);
+        let function = segments.pop().unwrap_or_default();
+        reported
+            .entry(function)
+            .or_default()
+            .push((segments, &outcome.result));
+    }
+
+    // G.TOOLREQLIXIMPO.3 step 3: Map tests to requirements
+    let map = map_tests(project_root, options)?;
+
+    // G.TOOLREQLIXIMPO.3 step 4: Match results by module, or by unique function name
+    let mut functions: HashMap<&str, usize> = HashMap::new();
+    for test in &map.tests {
+        *functions.entry(test.name.as_str()).or_default() += 1;
+    }
+    let results: Vec<&str> = map
+        .tests
+        .iter()
+        .map(|test| {
+            let candidates = reported
+                .get(test.name.as_str())
+                .map_or(&[][..], Vec::as_slice);
+            let module = test_module(&test.file);
+            let qualified: Vec<&str> = candidates
+                .iter()
+                .filter(|(segments, _)| segments.contains(&module))
+                .map(|(_, result)| *result)
+                .collect();
+            let matched = if !qualified.is_empty() {
+                qualified
+            } else if functions[test.name.as_str()] == 1 {
+                candidates.iter().map(|(_, result)| *result).collect()
+            } else {
+                Vec::new()
+            };
+            matched
+                .into_iter()
+                .max_by_key(|result| result_rank(result))
+                .unwrap_or("missing")
+        })
+        .collect();
+
+    // G.TOOLREQLIXIMPO.3 step 5: Classify requirements
+    let mut report = VerificationReport {
+        imported_at: utc_timestamp(SystemTime::now()),
+        file: file.to_string(),
+        format: format.to_string(),
+        tests_found: outcomes.len(),
+        tests_matched: results.iter().filter(|r| **r != "missing").count(),
+        ..Default::default()
+    };
+    for requirement in collect_requirements(&requirements_dir)? {
+        let tests: Vec<VerifiedTest> = map
+            .tests
+            .iter()
+            .zip(&results)
+            .filter(|(t, _)| t.requirements.contains(&requirement.index))
+            .map(|(t, result)| VerifiedTest {
+                name: t.name.clone(),
+                file: t.file.clone(),
+                line: t.line,
+                result: result.to_string(),
+            })
+            .collect();
+        let status = verification_status(&tests);
+        match status {
+            "verified" => report.summary.verified += 1,
+            "failing" => report.summary.failing += 1,
+            _ => report.summary.unverified += 1,
+        }
+        report.requirements.push(RequirementVerification {
+            requirement,
+            status: status.to_string(),
+            tests,
+        });
+    }
+
+    // G.TOOLREQLIXIMPO.3 step 6: Store result
+    let stored = serde_json::to_string_pretty(&report)
+        .map_err(|e| format!("Failed to serialize verification: {}", e))?;
+    write_file_utf8(&requirements_dir.join(VERIFICATION_FILE), &(stored + "\n"))?;
+
+    Ok(report)
+}
@@ -12,6 +12,18 @@ mod requirements_storage_format_tests;
 #[path = "unit/configuration_tests.rs"]
 mod configuration_tests;
 
+#[path = "unit/caching_tests.rs"]
+mod caching_tests;
+
+#[path = "unit/traceability_tests.rs"]
+mod traceability_tests;
+
+#[path = "unit/cross_references_tests.rs"]
+mod cross_references_tests;
+
+#[path = "unit/relations_tests.rs"]
+mod relations_tests;
+
 // Tool-specific tests
 #[path = "unit/tool_get_instructions_tests.rs"]
 mod tool_get_instructions_tests;
@@ -42,3 +54,63 @@ mod tool_search_requirements_tests;
 
 #[path = "unit/tool_get_version_tests.rs"]
 mod tool_get_version_tests;
+
+#[path = "unit/tool_find_duplicates_tests.rs"]
+mod tool_find_duplicates_tests;
+
+#[path = "unit/tool_suggest_location_tests.rs"]
+mod tool_suggest_location_tests;
+
+#[path = "unit/tool_trace_tests.rs"]
+mod tool_trace_tests;
+
+#[path = "unit/tool_coverage_tests.rs"]
+mod tool_coverage_tests;
+
+#[path = "unit/tool_check_references_tests.rs"]
+mod tool_check_references_tests;
+
+#[path = "unit/tool_get_references_tests.rs"]
+mod tool_get_references_tests;
+
+#[path = "unit/tool_impact_tests.rs"]
+mod tool_impact_tests;
+
+#[path = "unit/tool_check_links_tests.rs"]
+mod tool_check_links_tests;
+
+#[path = "unit/tool_rewrite_references_tests.rs"]
+mod tool_rewrite_references_tests;
+
+#[path = "unit/tool_test_map_tests.rs"]
+mod tool_test_map_tests;
+
+#[path = "unit/tool_import_test_results_tests.rs"]
+mod tool_import_test_results_tests;
+
+#[path = "unit/tool_export_matrix_tests.rs"]
+mod tool_export_matrix_tests;
+
+#[path = "unit/tool_export_graph_tests.rs"]
+mod tool_export_graph_tests;
+
+#[path = "unit/mcp_resources_tests.rs"]
+mod mcp_resources_tests;
+
+#[path = "unit/mcp_prompts_tests.rs"]
+mod mcp_prompts_tests;
+
+#[path = "unit/http_transport_tests.rs"]
+mod http_transport_tests;
+
+#[path = "unit/structured_tool_results_tests.rs"]
+mod structured_tool_results_tests;
+
+#[path = "unit/project_root_tests.rs"]
+mod project_root_tests;
+
+#[path = "unit/settings_tests.rs"]
+mod settings_tests;
+
+#[path = "unit/instructions_template_tests.rs"]
+mod instructions_template_tests;
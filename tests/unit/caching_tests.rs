@@ -0,0 +1,372 @@
+// Tests for Caching (G.CA.*)
+// Covers Requirements: G.CA.1, G.CA.2, G.CA.3, G.CA.4, G.CA.5
+
+use reqlix::{KeywordsParam, RequirementsServer, SearchRequirementsParams};
+use std::fs;
+use std::time::{Duration, SystemTime};
+use tempfile::TempDir;
+
+use super::common::{
+    create_agents_file_in_req_dir, create_category_file_in_req_dir, create_requirements_dir,
+    parse_response,
+};
+
+const GENERAL_CONTENT: &str =
+    "# Security\n\n## G.S.1: Login\n\nUsers must log in.\n\n## G.S.2: Logout\n\nUsers can log out.\n";
+
+// =============================================================================
+// Tests for G.CA.1: Cache location
+// =============================================================================
+
+/// Test: load_category writes cache file and .gitignore
+/// Precondition: System has requirements directory with one category file and no cache
+/// Action: Call load_category for the category
+/// Result: Cache file is created in .reqlix/cache together with a .gitignore containing "*"
+/// Covers Requirement: G.CA.1
+#[test]
+fn test_load_category_creates_cache_file() {
+    let temp_dir = TempDir::new().unwrap();
+    let req_dir = create_requirements_dir(&temp_dir);
+    create_category_file_in_req_dir(&req_dir, "general", GENERAL_CONTENT);
+
+    RequirementsServer::load_category(&req_dir, "general").unwrap();
+
+    let cache_path = RequirementsServer::get_cache_path(&req_dir, "general");
+    assert_eq!(cache_path, req_dir.join(".reqlix/cache/general.json"));
+    assert!(cache_path.exists());
+    let gitignore = fs::read_to_string(req_dir.join(".reqlix/cache/.gitignore")).unwrap();
+    assert_eq!(gitignore.trim(), "*");
+}
+
+/// Test: cache directory does not appear as a category
+/// Precondition: System has requirements directory with populated cache
+/// Action: Call list_categories
+/// Result: Only the category file is listed
+/// Covers Requirement: G.CA.1
+#[test]
+fn test_load_category_cache_not_listed_as_category() {
+    let temp_dir = TempDir::new().unwrap();
+    let req_dir = create_requirements_dir(&temp_dir);
+    create_category_file_in_req_dir(&req_dir, "general", GENERAL_CONTENT);
+
+    RequirementsServer::load_category(&req_dir, "general").unwrap();
+
+    let categories = RequirementsServer::list_categories(&req_dir).unwrap();
+    assert_eq!(categories, vec!["general".to_string()]);
+}
+
+// =============================================================================
+// Tests for G.CA.2: Cache contents
+// =============================================================================
+
+/// Test: load_category returns chapters, summaries and search index
+/// Precondition: System has category file with one chapter and two requirements
+/// Action: Call load_category
+/// Result: Parsed data contains the chapter, both summaries and lowercase search entries
+/// Covers Requirement: G.CA.2
+#[test]
+fn test_load_category_parsed_contents() {
+    let temp_dir = TempDir::new().unwrap();
+    let req_dir = create_requirements_dir(&temp_dir);
+    create_category_file_in_req_dir(&req_dir, "general", GENERAL_CONTENT);
+
+    let parsed = RequirementsServer::load_category(&req_dir, "general").unwrap();
+
+    assert_eq!(parsed.chapter_names(), vec!["Security".to_string()]);
+    let summaries = parsed.chapter_requirements("Security").unwrap();
+    assert_eq!(summaries.len(), 2);
+    assert_eq!(summaries[1].index, "G.S.2");
+    assert_eq!(parsed.search_index.len(), 2);
+    assert_eq!(
+        parsed.search_index[0].requirement.text,
+        "Users must log in."
+    );
+    assert_eq!(parsed.search_index[0].title_lower, "login");
+}
+
+/// Test: the single-pass parse matches the streaming parsers
+/// Precondition: System has category file with a code block, a repeated chapter name and several requirements
+/// Action: Call load_category and the streaming parsers
+/// Result: Chapters, summaries and full requirements are identical
+/// Covers Requirement: G.CA.2
+#[test]
+fn test_load_category_matches_streaming_parsers() {
+    let temp_dir = TempDir::new().unwrap();
+    let req_dir = create_requirements_dir(&temp_dir);
+    create_category_file_in_req_dir(
+        &req_dir,
+        "general",
+        "# Security\n\n## G.S.1: Login\n\nUsers log in.\n\n```\n## G.S.9: Fake\n# Fake\n```\n\n\
+         ## G.S.2: Logout\n\nUsers log out.\n\n# Audit\n\n## G.A.1: Trail\n\nKeep a trail.\n\n\
+         # Security\n\n## G.S.3: Tokens\n\nTokens expire.\n",
+    );
+    let path = req_dir.join("general.md");
+
+    let parsed = RequirementsServer::load_category(&req_dir, "general").unwrap();
+
+    let chapters = RequirementsServer::read_chapters_streaming(&path).unwrap();
+    assert_eq!(parsed.chapter_names(), chapters);
+    for chapter in &chapters {
+        assert_eq!(
+            parsed.chapter_requirements(chapter).unwrap(),
+            &RequirementsServer::read_requirements_streaming(&path, chapter).unwrap()
+        );
+    }
+    let indices: Vec<&str> = parsed
+        .search_index
+        .iter()
+        .map(|e| e.requirement.index.as_str())
+        .collect();
+    assert_eq!(indices, vec!["G.S.1", "G.S.2", "G.A.1", "G.S.3"]);
+    for entry in &parsed.search_index {
+        assert_eq!(
+            entry.requirement,
+            RequirementsServer::find_requirement_streaming(
+                &path,
+                "general",
+                &entry.requirement.index
+            )
+            .unwrap()
+        );
+    }
+}
+
+/// Test: content_hash is stable FNV-1a
+/// Precondition: System has known input bytes
+/// Action: Call content_hash on empty input and on "a"
+/// Result: Function returns the FNV-1a 64-bit reference values
+/// Covers Requirement: G.CA.2
+#[test]
+fn test_content_hash_fnv1a() {
+    assert_eq!(RequirementsServer::content_hash(b""), 0xcbf29ce484222325);
+    assert_eq!(RequirementsServer::content_hash(b"a"), 0xaf63dc4c8601ec8c);
+}
+
+// =============================================================================
+// Tests for G.CA.3: Cache invalidation
+// =============================================================================
+
+/// Test: load_category serves cached data when fingerprint matches
+/// Precondition: System has populated cache whose stored data was altered without touching the category file
+/// Action: Call load_category again
+/// Result: Function returns the altered cached data, proving the file was not re-parsed
+/// Covers Requirement: G.CA.3
+#[test]
+fn test_load_category_uses_valid_cache() {
+    let temp_dir = TempDir::new().unwrap();
+    let req_dir = create_requirements_dir(&temp_dir);
+    create_category_file_in_req_dir(&req_dir, "general", GENERAL_CONTENT);
+    RequirementsServer::load_category(&req_dir, "general").unwrap();
+
+    let cache_path = RequirementsServer::get_cache_path(&req_dir, "general");
+    let cache = fs::read_to_string(&cache_path).unwrap();
+    fs::write(&cache_path, cache.replace("\"Security\"", "\"Cached\"")).unwrap();
+
+    let parsed = RequirementsServer::load_category(&req_dir, "general").unwrap();
+    assert_eq!(parsed.chapter_names(), vec!["Cached".to_string()]);
+}
+
+/// Set the modification time of a file
+fn set_mtime(path: &std::path::Path, mtime: SystemTime) {
+    fs::File::options()
+        .write(true)
+        .open(path)
+        .unwrap()
+        .set_modified(mtime)
+        .unwrap();
+}
+
+/// Test: load_category detects edit with same size and new modification time
+/// Precondition: System has populated cache; category file is rewritten with same-size content
+/// Action: Call load_category again
+/// Result: Function returns the new content (hash mismatch invalidates cache)
+/// Covers Requirement: G.CA.3
+#[test]
+fn test_load_category_detects_same_size_edit() {
+    let temp_dir = TempDir::new().unwrap();
+    let req_dir = create_requirements_dir(&temp_dir);
+    create_category_file_in_req_dir(&req_dir, "general", GENERAL_CONTENT);
+    let path = req_dir.join("general.md");
+    let mtime = SystemTime::now() - Duration::from_secs(60);
+    set_mtime(&path, mtime);
+    RequirementsServer::load_category(&req_dir, "general").unwrap();
+
+    let edited = GENERAL_CONTENT.replace("Logout", "Signup");
+    assert_eq!(edited.len(), GENERAL_CONTENT.len());
+    fs::write(&path, &edited).unwrap();
+    set_mtime(&path, mtime + Duration::from_secs(1));
+
+    let parsed = RequirementsServer::load_category(&req_dir, "general").unwrap();
+    assert_eq!(
+        parsed.chapter_requirements("Security").unwrap()[1].title,
+        "Signup"
+    );
+}
+
+/// Test: a touched file with unchanged content keeps the cached data
+/// Precondition: System has populated cache whose stored data was altered; the category file is only touched
+/// Action: Call load_category twice
+/// Result: Both calls return the altered cached data (hash match, no re-parse, fingerprint updated)
+/// Covers Requirement: G.CA.3
+#[test]
+fn test_load_category_touched_file_keeps_cache() {
+    let temp_dir = TempDir::new().unwrap();
+    let req_dir = create_requirements_dir(&temp_dir);
+    create_category_file_in_req_dir(&req_dir, "general", GENERAL_CONTENT);
+    let path = req_dir.join("general.md");
+    let mtime = SystemTime::now() - Duration::from_secs(60);
+    set_mtime(&path, mtime);
+    RequirementsServer::load_category(&req_dir, "general").unwrap();
+
+    let cache_path = RequirementsServer::get_cache_path(&req_dir, "general");
+    let cache = fs::read_to_string(&cache_path).unwrap();
+    fs::write(&cache_path, cache.replace("\"Security\"", "\"Cached\"")).unwrap();
+    set_mtime(&path, mtime + Duration::from_secs(1));
+
+    let first = RequirementsServer::load_category(&req_dir, "general").unwrap();
+    let second = RequirementsServer::load_category(&req_dir, "general").unwrap();
+    assert_eq!(first.chapter_names(), vec!["Cached".to_string()]);
+    assert_eq!(second, first);
+}
+
+/// Test: invalidation is per category file
+/// Precondition: System has two cached categories; only one file is edited
+/// Action: Call load_category for the untouched category
+/// Result: Untouched category is still served from its cache file
+/// Covers Requirement: G.CA.3
+#[test]
+fn test_load_category_invalidation_per_file() {
+    let temp_dir = TempDir::new().unwrap();
+    let req_dir = create_requirements_dir(&temp_dir);
+    create_category_file_in_req_dir(&req_dir, "general", GENERAL_CONTENT);
+    create_category_file_in_req_dir(
+        &req_dir,
+        "testing",
+        "# Unit Tests\n\n## T.U.1: Run\n\nRun.\n",
+    );
+    RequirementsServer::load_category(&req_dir, "general").unwrap();
+    RequirementsServer::load_category(&req_dir, "testing").unwrap();
+
+    let testing_cache = RequirementsServer::get_cache_path(&req_dir, "testing");
+    let cache = fs::read_to_string(&testing_cache).unwrap();
+    fs::write(
+        &testing_cache,
+        cache.replace("\"Unit Tests\"", "\"Cached\""),
+    )
+    .unwrap();
+    create_category_file_in_req_dir(&req_dir, "general", "# Other\n");
+
+    let general = RequirementsServer::load_category(&req_dir, "general").unwrap();
+    let testing = RequirementsServer::load_category(&req_dir, "testing").unwrap();
+    assert_eq!(general.chapter_names(), vec!["Other".to_string()]);
+    assert_eq!(testing.chapter_names(), vec!["Cached".to_string()]);
+}
+
+// =============================================================================
+// Tests for G.CA.4: Cache failure handling
+// =============================================================================
+
+/// Test: corrupt cache file is rebuilt
+/// Precondition: System has cache file with invalid JSON
+/// Action: Call load_category
+/// Result: Function returns freshly parsed data and rewrites the cache file
+/// Covers Requirement: G.CA.4
+#[test]
+fn test_load_category_corrupt_cache() {
+    let temp_dir = TempDir::new().unwrap();
+    let req_dir = create_requirements_dir(&temp_dir);
+    create_category_file_in_req_dir(&req_dir, "general", GENERAL_CONTENT);
+    RequirementsServer::load_category(&req_dir, "general").unwrap();
+    let cache_path = RequirementsServer::get_cache_path(&req_dir, "general");
+    fs::write(&cache_path, "not json").unwrap();
+
+    let parsed = RequirementsServer::load_category(&req_dir, "general").unwrap();
+    assert_eq!(parsed.search_index.len(), 2);
+    let cache = fs::read_to_string(&cache_path).unwrap();
+    assert!(serde_json::from_str::<serde_json::Value>(&cache).is_ok());
+}
+
+/// Test: unwritable cache location does not cause error
+/// Precondition: System has a regular file where the .reqlix directory should be
+/// Action: Call load_category
+/// Result: Function returns freshly parsed data without error
+/// Covers Requirement: G.CA.4
+#[test]
+fn test_load_category_cache_write_failure() {
+    let temp_dir = TempDir::new().unwrap();
+    let req_dir = create_requirements_dir(&temp_dir);
+    create_category_file_in_req_dir(&req_dir, "general", GENERAL_CONTENT);
+    fs::write(req_dir.join(".reqlix"), "blocker").unwrap();
+
+    let parsed = RequirementsServer::load_category(&req_dir, "general").unwrap();
+    assert_eq!(parsed.search_index.len(), 2);
+}
+
+/// Test: concurrent loads in one process do not collide on temporary files
+/// Precondition: System has a category file without cache
+/// Action: Call load_category from several threads at once
+/// Result: Every call succeeds and no temporary file is left behind
+/// Covers Requirement: G.CA.4
+#[test]
+fn test_load_category_concurrent_writes() {
+    let temp_dir = TempDir::new().unwrap();
+    let req_dir = create_requirements_dir(&temp_dir);
+    create_category_file_in_req_dir(&req_dir, "general", GENERAL_CONTENT);
+
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = (0..8)
+            .map(|_| scope.spawn(|| RequirementsServer::load_category(&req_dir, "general")))
+            .collect();
+        for handle in handles {
+            assert_eq!(handle.join().unwrap().unwrap().search_index.len(), 2);
+        }
+    });
+
+    let leftovers: Vec<_> = fs::read_dir(req_dir.join(".reqlix/cache"))
+        .unwrap()
+        .flatten()
+        .filter(|e| e.file_name().to_string_lossy().contains(".tmp"))
+        .collect();
+    assert!(leftovers.is_empty());
+}
+
+// =============================================================================
+// Tests for G.CA.5: Cache usage
+// =============================================================================
+
+/// Test: search returns fresh data after external edit
+/// Precondition: System has searched once (cache populated), then category file is edited externally
+/// Action: Call handle_search_requirements with keyword present only in the new content
+/// Result: Function finds the newly added requirement
+/// Covers Requirement: G.CA.3, G.CA.5
+#[test]
+fn test_search_after_external_edit() {
+    let temp_dir = TempDir::new().unwrap();
+    let req_dir = create_requirements_dir(&temp_dir);
+    create_agents_file_in_req_dir(&req_dir, "# Instructions\n");
+    create_category_file_in_req_dir(&req_dir, "general", GENERAL_CONTENT);
+
+    let search = |keyword: &str| {
+        parse_response(&RequirementsServer::handle_search_requirements(
+            SearchRequirementsParams {
+                project_root: temp_dir.path().to_string_lossy().to_string(),
+                operation_description: "test".to_string(),
+                keywords: KeywordsParam::Single(keyword.to_string()),
+            },
+        ))
+    };
+
+    let json = search("password");
+    assert_eq!(json["data"]["results"].as_array().unwrap().len(), 0);
+    assert!(RequirementsServer::get_cache_path(&req_dir, "general").exists());
+
+    let edited = format!(
+        "{}\n## G.S.3: Password\n\nPasswords are hashed.\n",
+        GENERAL_CONTENT
+    );
+    create_category_file_in_req_dir(&req_dir, "general", &edited);
+
+    let json = search("password");
+    assert_eq!(json["data"]["results"].as_array().unwrap().len(), 1);
+    assert_eq!(json["data"]["results"][0]["index"], "G.S.3");
+}
@@ -1,5 +1,8 @@
 // Common helper functions for unit tests
 
+use reqlix::RequirementsServer;
+use rmcp::service::{RoleClient, RunningService};
+use rmcp::{ClientHandler, ServiceExt};
 use std::fs;
 use std::path::Path;
 use tempfile::TempDir;
@@ -39,3 +42,48 @@ pub fn create_agents_file_in_req_dir(req_dir: &Path, content: &str) {
 pub fn parse_response(response: &str) -> serde_json::Value {
     serde_json::from_str(response).unwrap()
 }
+
+/// Connect an MCP client to an in-process server
+pub async fn connect_client() -> RunningService<RoleClient, ()> {
+    connect_with(RequirementsServer::new(), ()).await
+}
+
+/// Connect a client handler to an in-process server
+pub async fn connect_with<C: ClientHandler>(
+    server: RequirementsServer,
+    client: C,
+) -> RunningService<RoleClient, C> {
+    let (server_io, client_io) = tokio::io::duplex(64 * 1024);
+    tokio::spawn(async move {
+        let server = server.serve(server_io).await.unwrap();
+        let _ = server.waiting().await;
+    });
+    client.serve(client_io).await.unwrap()
+}
+
+/// Commit all files in the temp directory to a git repository, initializing it if needed
+pub fn git_commit_all(temp_dir: &TempDir, message: &str) {
+    let git = |args: &[&str]| {
+        let status = std::process::Command::new("git")
+            .arg("-C")
+            .arg(temp_dir.path())
+            .args([
+                "-c",
+                "user.name=Test",
+                "-c",
+                "user.email=test@example.com",
+                "-c",
+                "commit.gpgsign=false",
+            ])
+            .args(args)
+            .output()
+            .unwrap()
+            .status;
+        assert!(status.success(), "git {:?} failed", args);
+    };
+    if !temp_dir.path().join(".git").exists() {
+        git(&["init", "-q"]);
+    }
+    git(&["add", "-A"]);
+    git(&["commit", "-q", "-m", message]);
+}
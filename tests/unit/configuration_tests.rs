@@ -1,10 +1,13 @@
 // Tests for Configuration (G.C.*)
-// Covers Requirements: G.C.1, G.C.2, G.C.7
+// Covers Requirements: G.C.1, G.C.2, G.C.7, G.C.8
 
-use reqlix::RequirementsServer;
-use std::path::PathBuf;
+use reqlix::{GetCategoriesParams, GetInstructionsParams, RequirementsServer};
+use std::fs;
+use std::path::{Path, PathBuf};
 use tempfile::TempDir;
 
+use super::common::{create_category_file_in_req_dir, create_requirements_dir, parse_response};
+
 // =============================================================================
 // Tests for G.C.1: Requirements directory location
 // =============================================================================
@@ -544,13 +547,13 @@ fn test_calculate_chapter_prefix_with_hyphens() {
 /// Test: calculate_unique_prefix with mixed characters
 /// Precondition: System has name with letters, numbers, and special chars
 /// Action: Call calculate_unique_prefix with "test123-category_456"
-/// Result: Function uses only letters, returns "T"
+/// Result: Function uses only letters and digits, returns "T"
 /// Covers Requirement: G.R.4
 #[test]
 fn test_calculate_unique_prefix_mixed_characters() {
     let names = vec!["test123-category_456".to_string()];
     let result = RequirementsServer::calculate_unique_prefix("test123-category_456", &names);
-    // Should return "T" (first letter, ignoring numbers, hyphens, underscores)
+    // Should return "T" (first letter, ignoring hyphens and underscores)
     assert_eq!(result, "T");
 }
 
@@ -624,14 +627,14 @@ fn test_calculate_unique_prefix_starts_with_number() {
 /// Test: calculate_chapter_prefix with complex name containing all allowed non-letter characters
 /// Precondition: System has chapter name with spaces, colons, hyphens, and numbers
 /// Action: Call calculate_chapter_prefix with "Chapter: Sub-Chapter 123 Name"
-/// Result: Function uses only letters "ChapterSubChapterName", returns appropriate prefix
+/// Result: Function uses only letters and digits "ChapterSubChapter123Name", returns appropriate prefix
 /// Covers Requirement: G.R.4
 #[test]
 fn test_calculate_chapter_prefix_complex_non_letters() {
     let chapters = vec!["Chapter: Sub-Chapter 123 Name".to_string()];
     let result =
         RequirementsServer::calculate_chapter_prefix("Chapter: Sub-Chapter 123 Name", &chapters);
-    // Should return "C" (first letter, ignoring spaces, colons, hyphens, numbers)
+    // Should return "C" (first letter, ignoring spaces, colons, hyphens)
     assert_eq!(result, "C");
 }
 
@@ -656,6 +659,65 @@ fn test_calculate_unique_prefix_same_letters_different_non_letters() {
     assert_eq!(result2, "TESTCATEGORY");
 }
 
+/// Test: calculate_chapter_prefix distinguishes chapters by digits
+/// Precondition: System has chapters "HTTP2 Transport", "HTTP Transport", "Phase 1" and "Phase 2"
+/// Action: Call calculate_chapter_prefix for each chapter
+/// Result: Digits count like letters: "HTTP2", "HTTPT", "PHASE1" and "PHASE2"
+/// Covers Requirement: G.R.4
+#[test]
+fn test_calculate_chapter_prefix_with_digits() {
+    let chapters = vec![
+        "HTTP2 Transport".to_string(),
+        "HTTP Transport".to_string(),
+        "Phase 1".to_string(),
+        "Phase 2".to_string(),
+    ];
+
+    assert_eq!(
+        RequirementsServer::calculate_chapter_prefix("HTTP2 Transport", &chapters),
+        "HTTP2"
+    );
+    assert_eq!(
+        RequirementsServer::calculate_chapter_prefix("HTTP Transport", &chapters),
+        "HTTPT"
+    );
+    assert_eq!(
+        RequirementsServer::calculate_chapter_prefix("Phase 1", &chapters),
+        "PHASE1"
+    );
+    assert_eq!(
+        RequirementsServer::calculate_chapter_prefix("Phase 2", &chapters),
+        "PHASE2"
+    );
+}
+
+/// Test: calculate_unique_prefix skips leading digits and ignores other characters
+/// Precondition: System has categories "v1_api", "v2_api" and "2fa.codes"
+/// Action: Call calculate_unique_prefix for each category
+/// Result: "V1", "V2" and "F" (the prefix starts with the first letter)
+/// Covers Requirement: G.R.4
+#[test]
+fn test_calculate_unique_prefix_with_digits() {
+    let names = vec![
+        "v1_api".to_string(),
+        "v2_api".to_string(),
+        "2fa.codes".to_string(),
+    ];
+
+    assert_eq!(
+        RequirementsServer::calculate_unique_prefix("v1_api", &names),
+        "V1"
+    );
+    assert_eq!(
+        RequirementsServer::calculate_unique_prefix("v2_api", &names),
+        "V2"
+    );
+    assert_eq!(
+        RequirementsServer::calculate_unique_prefix("2fa.codes", &names),
+        "F"
+    );
+}
+
 /// Test: get_search_paths with default path
 /// Precondition: System has no REQLIX_REQ_REL_PATH environment variable set
 /// Action: Call get_search_paths with project_root "/test/project"
@@ -883,7 +945,7 @@ fn test_get_create_path_relative_root() {
 
     // Verify result matches expected default path (without custom env var)
     // If result doesn't match, it means another test set the env var between removal and call
-    if result != PathBuf::from("project/docs/development/requirements/AGENTS.md") {
+    if result != std::path::Path::new("project/docs/development/requirements/AGENTS.md") {
         // Retry: remove again and call function
         std::env::remove_var("REQLIX_REQ_REL_PATH");
         let retry_result = RequirementsServer::get_create_path("project");
@@ -931,3 +993,139 @@ fn test_get_create_path_nested_custom() {
         None => std::env::remove_var("REQLIX_REQ_REL_PATH"),
     }
 }
+
+// =============================================================================
+// Tests for G.C.8: Requirements directory discovery
+// =============================================================================
+
+/// Create an empty subdirectory of the temp directory
+fn create_subdir(temp_dir: &TempDir, path: &str) -> PathBuf {
+    let dir = temp_dir.path().join(path);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+/// Call reqlix_get_instructions for a directory
+fn get_instructions(project_root: &Path, nested: Option<bool>) -> serde_json::Value {
+    parse_response(&RequirementsServer::handle_get_instructions(
+        GetInstructionsParams {
+            project_root: project_root.to_string_lossy().to_string(),
+            operation_description: "Reading instructions".to_string(),
+            nested,
+        },
+    ))
+}
+
+/// Test: discovery directories stop at the repository root
+/// Precondition: System has a git repository with a nested directory
+/// Action: Call get_discovery_dirs for the nested directory
+/// Result: The nested directory, its parent and the repository root
+/// Covers Requirement: G.C.8
+#[test]
+fn test_discovery_dirs_stop_at_repository_root() {
+    let temp_dir = TempDir::new().unwrap();
+    create_subdir(&temp_dir, ".git");
+    let nested = create_subdir(&temp_dir, "crates/core");
+
+    let dirs = RequirementsServer::get_discovery_dirs(&nested.to_string_lossy());
+
+    assert_eq!(
+        dirs,
+        vec![
+            nested.clone(),
+            temp_dir.path().join("crates"),
+            temp_dir.path().to_path_buf()
+        ]
+    );
+}
+
+/// Test: a subdirectory uses the requirements tree of a parent
+/// Precondition: System has a project with category general and a subdirectory src/module
+/// Action: Call reqlix_get_categories with the subdirectory
+/// Result: Category general; nothing is created in the subdirectory
+/// Covers Requirement: G.C.8
+#[test]
+fn test_subdirectory_finds_parent_tree() {
+    let temp_dir = TempDir::new().unwrap();
+    let req_dir = create_requirements_dir(&temp_dir);
+    create_category_file_in_req_dir(&req_dir, "general", "# Rules\n\n## G.R.1: One\n\nText.\n");
+    fs::write(req_dir.join("AGENTS.md"), "# Instructions\n").unwrap();
+    let nested = create_subdir(&temp_dir, "src/module");
+
+    let response = parse_response(&RequirementsServer::handle_get_categories(
+        GetCategoriesParams {
+            project_root: nested.to_string_lossy().to_string(),
+            operation_description: "Listing categories".to_string(),
+        },
+    ));
+
+    assert_eq!(response["success"], true);
+    assert_eq!(
+        response["data"]["categories"],
+        serde_json::json!(["general"])
+    );
+    assert_eq!(fs::read_dir(&nested).unwrap().count(), 0);
+}
+
+/// Test: a missing tree is created in the repository root, not the nested directory
+/// Precondition: System has a git repository without requirements and a nested directory
+/// Action: Call reqlix_get_instructions with the nested directory
+/// Result: The nested directory stays empty; the tree is created next to .git
+/// Covers Requirement: G.C.8
+#[test]
+fn test_subdirectory_creates_in_repository_root() {
+    let temp_dir = TempDir::new().unwrap();
+    create_subdir(&temp_dir, ".git");
+    let nested = create_subdir(&temp_dir, "crates/core");
+
+    let response = get_instructions(&nested, None);
+
+    assert_eq!(response["success"], true);
+    assert_eq!(fs::read_dir(&nested).unwrap().count(), 0);
+    assert!(fs::read_dir(temp_dir.path()).unwrap().count() > 2);
+}
+
+/// Test: nested creates the tree in project_root
+/// Precondition: System has a git repository with a nested directory
+/// Action: Call reqlix_get_instructions with the nested directory and nested true
+/// Result: The requirements tree is created in the nested directory
+/// Covers Requirement: G.C.8
+#[test]
+fn test_nested_creates_in_project_root() {
+    let temp_dir = TempDir::new().unwrap();
+    create_subdir(&temp_dir, ".git");
+    let nested = create_subdir(&temp_dir, "crates/core");
+
+    let response = get_instructions(&nested, Some(true));
+
+    assert_eq!(response["success"], true);
+    assert_eq!(fs::read_dir(&nested).unwrap().count(), 1);
+    assert_eq!(fs::read_dir(temp_dir.path()).unwrap().count(), 2);
+}
+
+/// Test: the search does not leave the repository
+/// Precondition: System has a requirements tree above a git repository without one
+/// Action: Call reqlix_get_categories in the repository
+/// Result: "Requirements directory not found" instead of the outer tree, and nothing is created
+/// Covers Requirement: G.C.8, G.S.3
+#[test]
+fn test_search_stops_at_repository_root() {
+    let temp_dir = TempDir::new().unwrap();
+    let req_dir = create_requirements_dir(&temp_dir);
+    create_category_file_in_req_dir(&req_dir, "general", "# Rules\n\n## G.R.1: One\n\nText.\n");
+    fs::write(req_dir.join("AGENTS.md"), "# Instructions\n").unwrap();
+    let repository = create_subdir(&temp_dir, "repo");
+    create_subdir(&temp_dir, "repo/.git");
+    let nested = create_subdir(&temp_dir, "repo/src");
+
+    let response = parse_response(&RequirementsServer::handle_get_categories(
+        GetCategoriesParams {
+            project_root: nested.to_string_lossy().to_string(),
+            operation_description: "Listing categories".to_string(),
+        },
+    ));
+
+    assert_eq!(response["error"], "Requirements directory not found");
+    assert_eq!(fs::read_dir(&nested).unwrap().count(), 0);
+    assert_eq!(fs::read_dir(&repository).unwrap().count(), 2);
+}
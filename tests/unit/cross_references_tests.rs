@@ -0,0 +1,397 @@
+// Tests for Cross-References (G.CR.*)
+// Covers Requirements: G.CR.1, G.CR.2, G.CR.3, G.CR.4, G.CR.5
+
+use reqlix::{
+    github_slug, heading_anchors, parse_link_destination, requirement_anchor, ReferenceGraph,
+    RequirementsServer, UpdateRequirementParams,
+};
+use std::fs;
+use tempfile::TempDir;
+
+use super::common::{
+    create_agents_file_in_req_dir, create_category_file_in_req_dir, create_requirements_dir,
+    parse_response,
+};
+
+/// Build the reference graph for a project with the given category files
+fn build_graph(categories: &[(&str, &str)]) -> ReferenceGraph {
+    let temp_dir = TempDir::new().unwrap();
+    let req_dir = create_requirements_dir(&temp_dir);
+    for (category, content) in categories {
+        create_category_file_in_req_dir(&req_dir, category, content);
+    }
+    ReferenceGraph::build(&req_dir).unwrap()
+}
+
+/// Indices of the given requirements
+fn indices(requirements: Vec<&reqlix::RequirementLocation>) -> Vec<&str> {
+    requirements.into_iter().map(|r| r.index.as_str()).collect()
+}
+
+// =============================================================================
+// Tests for G.CR.1: Requirement links
+// =============================================================================
+
+/// Test: find_anchor_links extracts anchors of inline links
+/// Precondition: System has lines with anchor links, external links and plain text
+/// Action: Call find_anchor_links
+/// Result: Only anchors of `](#...)` links are returned, in order
+/// Covers Requirement: G.CR.1
+#[test]
+fn test_find_anchor_links() {
+    assert_eq!(
+        RequirementsServer::find_anchor_links(
+            "See [G.C.2](#gc2-directory-creation) and [docs](https://example.com/#x)."
+        ),
+        vec!["gc2-directory-creation"]
+    );
+    assert_eq!(
+        RequirementsServer::find_anchor_links("[a](#one), [b](#two)"),
+        vec!["one", "two"]
+    );
+    assert!(RequirementsServer::find_anchor_links("#gc2 (#gc2)").is_empty());
+}
+
+/// Test: links by index and by anchor are found and deduplicated
+/// Precondition: System has G.A.1 linking to G.A.2 by index and anchor, and to G.A.3 by anchor only
+/// Action: Build the graph and get outgoing links of G.A.1
+/// Result: G.A.2 and G.A.3, each once, in order of first link
+/// Covers Requirement: G.CR.1, G.CR.3
+#[test]
+fn test_graph_links_by_index_and_anchor() {
+    let graph = build_graph(&[(
+        "general",
+        "# Alpha\n\n## G.A.1: Source\n\nSee [G.A.2](#ga2-second), then [third](#ga3-third) and G.A.2 again.\n\n\
+## G.A.2: Second\n\nText.\n\n## G.A.3: Third\n\nText.\n",
+    )]);
+
+    assert_eq!(indices(graph.outgoing("G.A.1")), vec!["G.A.2", "G.A.3"]);
+}
+
+/// Test: links in code blocks, self links and unknown targets are ignored
+/// Precondition: System has G.A.1 citing itself, an unknown index, an unknown anchor and G.A.2 inside a code block
+/// Action: Build the graph
+/// Result: G.A.1 has no outgoing links and G.A.2 has no backlinks
+/// Covers Requirement: G.CR.1
+#[test]
+fn test_graph_ignores_code_blocks_self_and_unknown() {
+    let graph = build_graph(&[(
+        "general",
+        "# Alpha\n\n## G.A.1: Source\n\nThis is G.A.1, see G.A.9 and [x](#missing).\n\n```\nG.A.2\n```\n\n\
+## G.A.2: Second\n\nText.\n",
+    )]);
+
+    assert!(graph.outgoing("G.A.1").is_empty());
+    assert!(graph.backlinks("G.A.2").is_empty());
+}
+
+// =============================================================================
+// Tests for G.CR.2: Anchors
+// =============================================================================
+
+/// Test: github_slug and requirement_anchor follow GitHub heading slugs
+/// Precondition: System has headings with dots, colons, underscores and hyphens
+/// Action: Call github_slug and requirement_anchor
+/// Result: Slugs match the anchors GitHub generates
+/// Covers Requirement: G.CR.2
+#[test]
+fn test_github_slug() {
+    assert_eq!(
+        requirement_anchor("G.C.2", "Directory creation"),
+        "gc2-directory-creation"
+    );
+    assert_eq!(
+        requirement_anchor("G.REQLIX_I.7", "Similar chapter warning"),
+        "greqlix_i7-similar-chapter-warning"
+    );
+    assert_eq!(
+        github_slug("G.R.1: Read-only (v2) mode!"),
+        "gr1-read-only-v2-mode"
+    );
+    assert_eq!(github_slug("A  B"), "a--b");
+}
+
+// =============================================================================
+// Tests for G.CR.3: Reference graph
+// =============================================================================
+
+/// Test: backlinks are listed once in requirement order across categories
+/// Precondition: System has G.A.2 and T.U.1 linking to G.A.1, G.A.2 twice
+/// Action: Get backlinks of G.A.1
+/// Result: G.A.2, then T.U.1 (categories alphabetically)
+/// Covers Requirement: G.CR.3
+#[test]
+fn test_graph_backlinks_order() {
+    let graph = build_graph(&[
+        (
+            "testing",
+            "# Unit\n\n## T.U.1: Tests\n\nCovers [G.A.1](#ga1-target).\n",
+        ),
+        (
+            "general",
+            "# Alpha\n\n## G.A.1: Target\n\nText.\n\n## G.A.2: Linker\n\nG.A.1 and G.A.1.\n",
+        ),
+    ]);
+
+    assert_eq!(indices(graph.backlinks("G.A.1")), vec!["G.A.2", "T.U.1"]);
+    assert_eq!(graph.requirement("T.U.1").unwrap().category, "testing");
+    assert!(graph.requirement("X.Y.1").is_none());
+    assert!(graph.backlinks("X.Y.1").is_empty());
+}
+
+// =============================================================================
+// Tests for G.CR.4: Heading anchors
+// =============================================================================
+
+/// Test: heading_anchors slugs all heading levels and suffixes repeated slugs
+/// Precondition: System has a file with two "Notes" headings, a level 3 heading and a heading in a code block
+/// Action: Call heading_anchors
+/// Result: Anchors in file order, the repeated one with suffix -1, the code block heading skipped
+/// Covers Requirement: G.CR.4
+#[test]
+fn test_heading_anchors() {
+    let content = "# Notes\n\n## G.C.2: Directory creation\n\n```\n# Skipped\n```\n\n\
+### Notes\n\n#NoSpace\n";
+
+    assert_eq!(
+        heading_anchors(content),
+        vec!["notes", "gc2-directory-creation", "notes-1"]
+    );
+}
+
+/// Test: parse_link_destination recognizes anchor and markdown file links only
+/// Precondition: System has anchor, cross-file, file-only, URL and image destinations
+/// Action: Call parse_link_destination
+/// Result: Anchor and markdown links are split into path and anchor, others return None
+/// Covers Requirement: G.CR.4
+#[test]
+fn test_parse_link_destination() {
+    assert_eq!(
+        parse_link_destination("#gc2-directory-creation"),
+        Some(("", Some("gc2-directory-creation")))
+    );
+    assert_eq!(
+        parse_link_destination("testing.md#tu1-tests"),
+        Some(("testing.md", Some("tu1-tests")))
+    );
+    assert_eq!(
+        parse_link_destination("../README.md"),
+        Some(("../README.md", None))
+    );
+    assert_eq!(parse_link_destination("https://example.com/a.md#x"), None);
+    assert_eq!(parse_link_destination("mailto:someone@example.com"), None);
+    assert_eq!(parse_link_destination("diagram.png"), None);
+}
+
+// =============================================================================
+// Tests for G.CR.5: Anchor link rewriting
+// =============================================================================
+
+/// Rename G.C.2 in a project where general.md and testing.md link to it
+fn rename_linked_requirement(rewrite_links: Option<bool>) -> (String, String) {
+    let temp_dir = TempDir::new().unwrap();
+    let req_dir = create_requirements_dir(&temp_dir);
+    create_agents_file_in_req_dir(&req_dir, "# Instructions\n");
+    create_category_file_in_req_dir(
+        &req_dir,
+        "general",
+        "# Configuration\n\n## G.C.1: Location\n\nSee [G.C.2](#gc2-old-title).\n\n\
+```\n[G.C.2](#gc2-old-title)\n```\n\n## G.C.2: Old title\n\nText.\n",
+    );
+    create_category_file_in_req_dir(
+        &req_dir,
+        "testing",
+        "# Unit\n\n## T.U.1: Tests\n\nCovers [G.C.2](general.md#gc2-old-title) and [x](#gc2-old-title).\n",
+    );
+
+    let params = UpdateRequirementParams {
+        project_root: temp_dir.path().to_string_lossy().to_string(),
+        operation_description: "test".to_string(),
+        index: Some("G.C.2".to_string()),
+        text: Some("Text.".to_string()),
+        title: Some("New title".to_string()),
+        relations: None,
+        items: None,
+        rewrite_links,
+    };
+    let json = parse_response(&RequirementsServer::handle_update_requirement(params));
+    assert!(json["success"].as_bool().unwrap());
+
+    (
+        fs::read_to_string(req_dir.join("general.md")).unwrap(),
+        fs::read_to_string(req_dir.join("testing.md")).unwrap(),
+    )
+}
+
+/// Test: title change rewrites links to the old anchor in the category file
+/// Precondition: System has links to G.C.2's anchor in general.md, in a code block, and from testing.md;
+///               testing.md also has a same-file link with the same anchor
+/// Action: Update the title of G.C.2 with rewrite_links true
+/// Result: Links to general.md's anchor are rewritten, the code block and the same-file link in testing.md are not
+/// Covers Requirement: G.CR.5
+#[test]
+fn test_update_title_rewrites_links() {
+    let (general, testing) = rename_linked_requirement(Some(true));
+
+    assert!(general.contains("See [G.C.2](#gc2-new-title)."));
+    assert!(general.contains("```\n[G.C.2](#gc2-old-title)\n```"));
+    assert!(general.contains("## G.C.2: New title"));
+    assert_eq!(
+        testing,
+        "# Unit\n\n## T.U.1: Tests\n\nCovers [G.C.2](general.md#gc2-new-title) and [x](#gc2-old-title).\n"
+    );
+}
+
+/// Test: rewrite_links false keeps links unchanged
+/// Precondition: System has links to G.C.2's anchor
+/// Action: Update the title of G.C.2 with rewrite_links false
+/// Result: Links still point to the old anchor
+/// Covers Requirement: G.CR.5
+#[test]
+fn test_update_title_without_rewriting_links() {
+    let (general, testing) = rename_linked_requirement(Some(false));
+
+    assert!(general.contains("See [G.C.2](#gc2-old-title)."));
+    assert!(testing.contains("(general.md#gc2-old-title)"));
+}
+
+/// Test: links are not rewritten by default
+/// Precondition: System has links to G.C.2's anchor
+/// Action: Update the title of G.C.2 without rewrite_links
+/// Result: Links still point to the old anchor, the heading has the new title
+/// Covers Requirement: G.CR.5
+#[test]
+fn test_update_title_keeps_links_by_default() {
+    let (general, testing) = rename_linked_requirement(None);
+
+    assert!(general.contains("See [G.C.2](#gc2-old-title)."));
+    assert!(general.contains("## G.C.2: New title"));
+    assert!(testing.contains("(general.md#gc2-old-title)"));
+}
+
+/// Test: a failing link rewrite leaves the requirement unchanged
+/// Precondition: System has a markdown file in the requirements directory that is not valid UTF-8
+/// Action: Update the title of G.C.2 with rewrite_links true
+/// Result: Error is returned and general.md is not modified
+/// Covers Requirement: G.CR.5
+#[test]
+fn test_update_title_rewrite_failure_keeps_requirement() {
+    let temp_dir = TempDir::new().unwrap();
+    let req_dir = create_requirements_dir(&temp_dir);
+    create_agents_file_in_req_dir(&req_dir, "# Instructions\n");
+    let general = "# Configuration\n\n## G.C.2: Old title\n\nText.\n";
+    create_category_file_in_req_dir(&req_dir, "general", general);
+    fs::write(req_dir.join("broken.md"), [0xff, 0xfe, 0x00]).unwrap();
+
+    let params = UpdateRequirementParams {
+        project_root: temp_dir.path().to_string_lossy().to_string(),
+        operation_description: "test".to_string(),
+        index: Some("G.C.2".to_string()),
+        text: Some("Text.".to_string()),
+        title: Some("New title".to_string()),
+        relations: None,
+        items: None,
+        rewrite_links: Some(true),
+    };
+    let json = parse_response(&RequirementsServer::handle_update_requirement(params));
+    assert!(!json["success"].as_bool().unwrap());
+    assert_eq!(
+        fs::read_to_string(req_dir.join("general.md")).unwrap(),
+        general
+    );
+}
+
+/// Make a file read-only; false if the process can still write it (e.g. as root)
+#[cfg(unix)]
+fn make_read_only(path: &std::path::Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    fs::set_permissions(path, fs::Permissions::from_mode(0o444)).unwrap();
+    fs::OpenOptions::new().append(true).open(path).is_err()
+}
+
+/// Rename G.C.2 with rewrite_links true, linked from testing.md
+#[cfg(unix)]
+fn rename_with_rewrite(temp_dir: &TempDir) -> serde_json::Value {
+    let params = UpdateRequirementParams {
+        project_root: temp_dir.path().to_string_lossy().to_string(),
+        operation_description: "test".to_string(),
+        index: Some("G.C.2".to_string()),
+        text: Some("Text.".to_string()),
+        title: Some("New title".to_string()),
+        relations: None,
+        items: None,
+        rewrite_links: Some(true),
+    };
+    parse_response(&RequirementsServer::handle_update_requirement(params))
+}
+
+/// Test: a failing category write leaves the linking files unchanged
+/// Precondition: testing.md links to G.C.2's anchor; general.md cannot be written
+/// Action: Update the title of G.C.2 with rewrite_links true
+/// Result: Error is returned and testing.md still links to the old anchor
+/// Covers Requirement: G.CR.5
+#[cfg(unix)]
+#[test]
+fn test_update_title_category_write_failure_keeps_links() {
+    let temp_dir = TempDir::new().unwrap();
+    let req_dir = create_requirements_dir(&temp_dir);
+    create_agents_file_in_req_dir(&req_dir, "# Instructions\n");
+    create_category_file_in_req_dir(
+        &req_dir,
+        "general",
+        "# Configuration\n\n## G.C.2: Old title\n\nText.\n",
+    );
+    let testing = "# Unit\n\n## T.U.1: Tests\n\nCovers [G.C.2](general.md#gc2-old-title).\n";
+    create_category_file_in_req_dir(&req_dir, "testing", testing);
+    if !make_read_only(&req_dir.join("general.md")) {
+        return;
+    }
+
+    let json = rename_with_rewrite(&temp_dir);
+
+    assert!(!json["success"].as_bool().unwrap());
+    assert!(json["error"]
+        .as_str()
+        .unwrap()
+        .starts_with("Failed to write category file"));
+    assert_eq!(
+        fs::read_to_string(req_dir.join("testing.md")).unwrap(),
+        testing
+    );
+}
+
+/// Test: a failing linking file write is reported as a warning
+/// Precondition: testing.md links to G.C.2's anchor and cannot be written
+/// Action: Update the title of G.C.2 with rewrite_links true
+/// Result: The requirement is updated and a warning names testing.md
+/// Covers Requirement: G.CR.5
+#[cfg(unix)]
+#[test]
+fn test_update_title_link_write_failure_warns() {
+    let temp_dir = TempDir::new().unwrap();
+    let req_dir = create_requirements_dir(&temp_dir);
+    create_agents_file_in_req_dir(&req_dir, "# Instructions\n");
+    create_category_file_in_req_dir(
+        &req_dir,
+        "general",
+        "# Configuration\n\n## G.C.2: Old title\n\nText.\n",
+    );
+    create_category_file_in_req_dir(
+        &req_dir,
+        "testing",
+        "# Unit\n\n## T.U.1: Tests\n\nCovers [G.C.2](general.md#gc2-old-title).\n",
+    );
+    if !make_read_only(&req_dir.join("testing.md")) {
+        return;
+    }
+
+    let json = rename_with_rewrite(&temp_dir);
+
+    assert!(json["success"].as_bool().unwrap());
+    assert_eq!(json["data"]["title"], "New title");
+    let warning = json["data"]["warnings"][0].as_str().unwrap();
+    assert!(warning.starts_with("Links in testing.md were not rewritten"));
+    assert!(fs::read_to_string(req_dir.join("general.md"))
+        .unwrap()
+        .contains("## G.C.2: New title"));
+}
@@ -0,0 +1,269 @@
+// Tests for HTTP Transport (G.H.*)
+// Covers Requirements: G.H.1, G.H.2, G.H.3
+
+use reqlix::serve_http;
+use rmcp::model::CallToolRequestParam;
+use rmcp::service::{RoleClient, RunningService};
+use rmcp::transport::streamable_http_client::StreamableHttpClientTransportConfig;
+use rmcp::transport::StreamableHttpClientTransport;
+use rmcp::ServiceExt;
+use std::io::{BufRead, BufReader};
+use std::process::{Child, Command, Stdio};
+use std::sync::mpsc;
+use std::time::Duration;
+use tempfile::TempDir;
+use tokio::net::TcpListener;
+use tokio::sync::oneshot;
+
+use super::common::{
+    create_agents_file_in_req_dir, create_category_file_in_req_dir, create_requirements_dir,
+    parse_response,
+};
+
+/// Child process that is killed when dropped, also when a test fails
+struct ChildGuard(Child);
+
+impl Drop for ChildGuard {
+    fn drop(&mut self) {
+        let _ = self.0.kill();
+        let _ = self.0.wait();
+    }
+}
+
+/// Running HTTP server on a free local port; stops when dropped
+struct TestServer {
+    url: String,
+    _shutdown: oneshot::Sender<()>,
+}
+
+/// Start an HTTP server with an optional token
+async fn start_server(token: Option<&str>) -> TestServer {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/mcp", listener.local_addr().unwrap());
+    let (shutdown, stopped) = oneshot::channel::<()>();
+    tokio::spawn(serve_http(listener, None, token.map(String::from), async {
+        let _ = stopped.await;
+    }));
+    TestServer {
+        url,
+        _shutdown: shutdown,
+    }
+}
+
+/// Connect an MCP client, optionally with a bearer token
+async fn connect(
+    url: &str,
+    token: Option<&str>,
+) -> Result<RunningService<RoleClient, ()>, rmcp::service::ClientInitializeError> {
+    let mut config = StreamableHttpClientTransportConfig::with_uri(url);
+    config.auth_header = token.map(String::from);
+    ().serve(StreamableHttpClientTransport::from_config(config))
+        .await
+}
+
+/// Call reqlix_get_categories for a project and return the parsed response
+async fn get_categories(
+    client: &RunningService<RoleClient, ()>,
+    project_root: &str,
+) -> serde_json::Value {
+    let arguments = serde_json::json!({
+        "project_root": project_root,
+        "operation_description": "Listing categories over HTTP",
+    });
+    let result = client
+        .call_tool(CallToolRequestParam {
+            name: "reqlix_get_categories".into(),
+            arguments: arguments.as_object().cloned(),
+        })
+        .await
+        .unwrap();
+    parse_response(&result.content[0].as_text().unwrap().text)
+}
+
+/// Create a project with category general
+fn create_project() -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    let req_dir = create_requirements_dir(&temp_dir);
+    create_agents_file_in_req_dir(&req_dir, "# Instructions\n");
+    create_category_file_in_req_dir(&req_dir, "general", "# Rules\n\n## G.R.1: One\n\nText.\n");
+    temp_dir
+}
+
+/// POST a tools/list request without going through an MCP client
+async fn post_tools_list(url: &str, headers: &[(&str, &str)]) -> reqwest::Response {
+    let mut request = reqwest::Client::new()
+        .post(url)
+        .header("Content-Type", "application/json")
+        .header("Accept", "application/json, text/event-stream")
+        .body(r#"{"jsonrpc":"2.0","id":1,"method":"tools/list"}"#);
+    for (name, value) in headers {
+        request = request.header(*name, *value);
+    }
+    request.send().await.unwrap()
+}
+
+// =============================================================================
+// Tests for G.H.1: Transports
+// =============================================================================
+
+/// Test: an MCP client works over HTTP
+/// Precondition: System has an HTTP server and a project with category general
+/// Action: Connect a client, list tools, call reqlix_get_categories
+/// Result: Tools are listed and the category is returned
+/// Covers Requirement: G.H.1
+#[tokio::test]
+async fn test_http_client_calls_tool() {
+    let server = start_server(None).await;
+    let temp_dir = create_project();
+
+    let client = connect(&server.url, None).await.unwrap();
+    let tools = client.list_all_tools().await.unwrap();
+    let response = get_categories(&client, &temp_dir.path().to_string_lossy()).await;
+
+    assert!(tools.iter().any(|t| t.name == "reqlix_get_categories"));
+    assert_eq!(response["success"], true);
+    assert_eq!(
+        response["data"]["categories"],
+        serde_json::json!(["general"])
+    );
+    client.cancel().await.unwrap();
+}
+
+/// Test: the binary serves HTTP on the given address
+/// Precondition: System has the reqlix binary
+/// Action: Run `reqlix --transport http --listen 127.0.0.1:0`, connect a client to the logged address
+/// Result: The client lists the tools
+/// Covers Requirement: G.H.1
+#[tokio::test]
+async fn test_binary_serves_http() {
+    let mut child = ChildGuard(
+        Command::new(env!("CARGO_BIN_EXE_reqlix"))
+            .args(["--transport", "http", "--listen", "127.0.0.1:0"])
+            .env_remove("REQLIX_HTTP_TOKEN")
+            .stderr(Stdio::piped())
+            .spawn()
+            .unwrap(),
+    );
+    // Keep draining stderr so later log lines never hit a closed pipe
+    let stderr = BufReader::new(child.0.stderr.take().unwrap());
+    let (sender, receiver) = mpsc::channel();
+    std::thread::spawn(move || {
+        for line in stderr.lines().map_while(Result::ok) {
+            if let Some((_, url)) = line.split_once("Listening on ") {
+                let url = url.split_whitespace().next().unwrap_or_default();
+                let _ = sender.send(url.to_string());
+            }
+        }
+    });
+    let url = receiver.recv_timeout(Duration::from_secs(30)).unwrap();
+
+    let client = connect(&url, None).await.unwrap();
+    let tools = client.list_all_tools().await.unwrap();
+    client.cancel().await.unwrap();
+
+    assert!(tools.iter().any(|t| t.name == "reqlix_get_version"));
+}
+
+/// Test: invalid options are rejected with exit status 2
+/// Precondition: System has the reqlix binary
+/// Action: Run with an unknown transport, --listen without http, an invalid address and an empty token
+/// Result: Exit status 2 and the usage text
+/// Covers Requirement: G.H.1, G.H.3
+#[test]
+fn test_binary_rejects_invalid_options() {
+    let cases: [&[&str]; 4] = [
+        &["--transport", "tcp"],
+        &["--listen", "127.0.0.1:0"],
+        &["--transport", "http", "--listen", "localhost"],
+        &["--transport", "http", "--token", ""],
+    ];
+    for args in cases {
+        let output = Command::new(env!("CARGO_BIN_EXE_reqlix"))
+            .args(args)
+            .output()
+            .unwrap();
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert_eq!(output.status.code(), Some(2), "{:?}", args);
+        assert!(stderr.contains("Usage: reqlix"), "{:?}", args);
+    }
+}
+
+// =============================================================================
+// Tests for G.H.2: HTTP sessions
+// =============================================================================
+
+/// Test: several clients share one server
+/// Precondition: System has an HTTP server and a project
+/// Action: Connect two clients and call a tool from both, then close the first
+/// Result: Both get the categories; the second still works after the first closed
+/// Covers Requirement: G.H.2
+#[tokio::test]
+async fn test_http_sessions_are_independent() {
+    let server = start_server(None).await;
+    let temp_dir = create_project();
+    let project_root = temp_dir.path().to_string_lossy().to_string();
+
+    let first = connect(&server.url, None).await.unwrap();
+    let second = connect(&server.url, None).await.unwrap();
+    assert_eq!(get_categories(&first, &project_root).await["success"], true);
+    first.cancel().await.unwrap();
+
+    assert_eq!(
+        get_categories(&second, &project_root).await["success"],
+        true
+    );
+    second.cancel().await.unwrap();
+}
+
+/// Test: requests with an unknown session id are rejected
+/// Precondition: System has an HTTP server
+/// Action: POST tools/list with Mcp-Session-Id "unknown"
+/// Result: HTTP status 401
+/// Covers Requirement: G.H.2
+#[tokio::test]
+async fn test_http_unknown_session() {
+    let server = start_server(None).await;
+
+    let response = post_tools_list(&server.url, &[("Mcp-Session-Id", "unknown")]).await;
+
+    assert_eq!(response.status(), 401);
+}
+
+// =============================================================================
+// Tests for G.H.3: HTTP bearer token
+// =============================================================================
+
+/// Test: a client with the token is served
+/// Precondition: System has an HTTP server with token "secret"
+/// Action: Connect with token "secret" and call a tool
+/// Result: The call succeeds
+/// Covers Requirement: G.H.3
+#[tokio::test]
+async fn test_http_token_accepted() {
+    let server = start_server(Some("secret")).await;
+    let temp_dir = create_project();
+
+    let client = connect(&server.url, Some("secret")).await.unwrap();
+    let response = get_categories(&client, &temp_dir.path().to_string_lossy()).await;
+
+    assert_eq!(response["success"], true);
+    client.cancel().await.unwrap();
+}
+
+/// Test: requests without the right token are rejected
+/// Precondition: System has an HTTP server with token "secret"
+/// Action: POST without Authorization, POST with a wrong token, connect a client with a wrong token
+/// Result: HTTP status 401 with WWW-Authenticate: Bearer; the client fails to initialize
+/// Covers Requirement: G.H.3
+#[tokio::test]
+async fn test_http_token_rejected() {
+    let server = start_server(Some("secret")).await;
+
+    let missing = post_tools_list(&server.url, &[]).await;
+    let wrong = post_tools_list(&server.url, &[("Authorization", "Bearer other")]).await;
+
+    assert_eq!(missing.status(), 401);
+    assert_eq!(missing.headers()["WWW-Authenticate"], "Bearer");
+    assert_eq!(wrong.status(), 401);
+    assert!(connect(&server.url, Some("other")).await.is_err());
+}
@@ -0,0 +1,297 @@
+// Tests for Instructions Template (G.I.*)
+// Covers Requirements: G.I.1, G.I.2, G.I.3, G.I.4
+
+use reqlix::{parse_config, read_resource, GetInstructionsParams, RequirementsServer};
+use std::fs;
+use tempfile::TempDir;
+
+use super::common::{
+    create_agents_file_in_req_dir, create_category_file_in_req_dir, create_requirements_dir,
+    parse_response,
+};
+
+/// Create a project with AGENTS.md, reqlix.toml and two categories
+fn create_project(agents: &str, settings: &str) -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("reqlix.toml"), settings).unwrap();
+    let req_dir = create_requirements_dir(&temp_dir);
+    create_agents_file_in_req_dir(&req_dir, agents);
+    create_category_file_in_req_dir(
+        &req_dir,
+        "general",
+        "# Parameter Constraints\n\n## G.P.1: First\n\nOne.\n\n## G.P.2: Second\n\nTwo.\n",
+    );
+    create_category_file_in_req_dir(
+        &req_dir,
+        "testing",
+        "# Unit Tests\n\n## T.U.1: Unit\n\nU.\n",
+    );
+    temp_dir
+}
+
+/// Write a fragment below the requirements directory
+fn create_fragment(temp_dir: &TempDir, path: &str, content: &str) {
+    let fragment = temp_dir
+        .path()
+        .join("docs/development/requirements")
+        .join(path);
+    fs::create_dir_all(fragment.parent().unwrap()).unwrap();
+    fs::write(fragment, content).unwrap();
+}
+
+/// Call reqlix_get_instructions
+fn get_instructions(temp_dir: &TempDir) -> serde_json::Value {
+    parse_response(&RequirementsServer::handle_get_instructions(
+        GetInstructionsParams {
+            project_root: temp_dir.path().to_string_lossy().to_string(),
+            operation_description: "Reading instructions".to_string(),
+            nested: None,
+        },
+    ))
+}
+
+/// Rendered instructions of a project
+fn content(temp_dir: &TempDir) -> String {
+    let response = get_instructions(temp_dir);
+    assert_eq!(response["success"], true, "{}", response);
+    response["data"]["content"].as_str().unwrap().to_string()
+}
+
+// =============================================================================
+// Tests for G.I.1: Template rendering
+// =============================================================================
+
+/// Test: known placeholders are rendered at call time and other braces are kept
+/// Precondition: System has AGENTS.md with known and unknown placeholders
+/// Action: Call reqlix_get_instructions
+/// Result: Known placeholders replaced, other text kept, Categories chapter appended
+/// Covers Requirement: G.I.1, G.I.2
+#[test]
+fn test_placeholders_rendered() {
+    let temp_dir = create_project(
+        "# {project_name}\n\nFiles in {requirements_directory}.\nIndex `{CATEGORY}.{NUMBER}`, {unknown}.\n",
+        "",
+    );
+    let name = temp_dir.path().file_name().unwrap().to_string_lossy();
+
+    assert_eq!(
+        content(&temp_dir),
+        format!(
+            "# {}\n\nFiles in docs/development/requirements.\nIndex `{{CATEGORY}}.{{NUMBER}}`, {{unknown}}.\n\
+             \n# Categories\n\n- general\n- testing\n",
+            name
+        )
+    );
+}
+
+/// Test: the built-in placeholder content is written unrendered and rendered on every call
+/// Precondition: System has a repository without requirements
+/// Action: Call reqlix_get_instructions
+/// Result: AGENTS.md keeps {requirements_directory}, the response contains the path
+/// Covers Requirement: G.I.1
+#[test]
+fn test_created_file_keeps_placeholders() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::create_dir(temp_dir.path().join(".git")).unwrap();
+
+    let rendered = content(&temp_dir);
+    let agents = fs::read_to_string(
+        temp_dir
+            .path()
+            .join("docs/development/requirements/AGENTS.md"),
+    )
+    .unwrap();
+
+    assert!(agents.contains("Never edit files in {requirements_directory} directly"));
+    assert!(rendered.contains("Never edit files in docs/development/requirements directly"));
+}
+
+/// Test: the instructions resource is rendered without the Categories chapter
+/// Precondition: System has AGENTS.md with {project_name}
+/// Action: Read reqlix://instructions
+/// Result: Rendered content without the generated chapter
+/// Covers Requirement: G.I.1
+#[test]
+fn test_resource_rendered() {
+    let temp_dir = create_project(
+        "# {project_name}\n",
+        "[instructions]\nproject_name = \"Payments\"\n",
+    );
+
+    let resource =
+        read_resource(&temp_dir.path().to_string_lossy(), "reqlix://instructions").unwrap();
+
+    assert_eq!(resource.text, "# Payments\n");
+}
+
+// =============================================================================
+// Tests for G.I.2: Placeholders
+// =============================================================================
+
+/// Test: {categories} lists the categories with counts instead of the generated chapter
+/// Precondition: System has categories with two and one requirements
+/// Action: Call reqlix_get_instructions with AGENTS.md containing {categories}
+/// Result: Counted list and no generated Categories chapter
+/// Covers Requirement: G.I.2
+#[test]
+fn test_categories_with_counts() {
+    let temp_dir = create_project("# Categories\n\n{categories}\n", "");
+
+    assert_eq!(
+        content(&temp_dir),
+        "# Categories\n\n- general (2 requirements)\n- testing (1 requirement)\n"
+    );
+}
+
+/// Test: {key_chapters} links the configured chapters
+/// Precondition: System has reqlix.toml with two key chapters
+/// Action: Call reqlix_get_instructions with AGENTS.md containing {key_chapters}
+/// Result: Links with GitHub anchors in configured order
+/// Covers Requirement: G.I.2, G.I.4
+#[test]
+fn test_key_chapters_links() {
+    let temp_dir = create_project(
+        "{categories}\n\n{key_chapters}\n",
+        "[instructions]\nkey_chapters = [\"testing/Unit Tests\", \"general/Parameter Constraints\"]\n",
+    );
+
+    assert!(content(&temp_dir).ends_with(
+        "\n\n- [Unit Tests](testing.md#unit-tests) (testing)\n\
+         - [Parameter Constraints](general.md#parameter-constraints) (general)\n"
+    ));
+}
+
+/// Test: a missing key chapter is marked instead of failing the call
+/// Precondition: System has reqlix.toml naming an existing chapter, a missing chapter and a missing category
+/// Action: Call reqlix_get_instructions with AGENTS.md containing {key_chapters}
+/// Result: Success; the existing chapter is linked, the others are listed as "(not found)"
+/// Covers Requirement: G.I.2
+#[test]
+fn test_missing_key_chapter() {
+    let temp_dir = create_project(
+        "{key_chapters}\n",
+        "[instructions]\nkey_chapters = [\"general/Missing\", \"general/Parameter Constraints\", \"legacy/Old\"]\n",
+    );
+
+    assert!(content(&temp_dir).starts_with(
+        "- general/Missing (not found)\n\
+- [Parameter Constraints](general.md#parameter-constraints) (general)\n\
+- legacy/Old (not found)\n\n"
+    ));
+}
+
+// =============================================================================
+// Tests for G.I.3: Fragments
+// =============================================================================
+
+/// Test: fragments are included and rendered, also when nested
+/// Precondition: System has AGENTS.md including a fragment that includes another one
+/// Action: Call reqlix_get_instructions
+/// Result: Both fragments rendered in place without their final line breaks
+/// Covers Requirement: G.I.3
+#[test]
+fn test_fragments_included() {
+    let temp_dir = create_project(
+        "# Rules\n\n{include:fragments/review.md}\n\n{categories}\n",
+        "[instructions]\nproject_name = \"Payments\"\n",
+    );
+    create_fragment(
+        &temp_dir,
+        "fragments/review.md",
+        "Review {project_name} changes.\n{include:fragments/style.md}\n",
+    );
+    create_fragment(&temp_dir, "fragments/style.md", "Keep it short.\n");
+
+    assert!(content(&temp_dir)
+        .starts_with("# Rules\n\nReview Payments changes.\nKeep it short.\n\n- general"));
+}
+
+/// Test: invalid fragments fail the call
+/// Precondition: System has fragments that are missing, outside the directory or include themselves
+/// Action: Call reqlix_get_instructions
+/// Result: The error of each fragment problem
+/// Covers Requirement: G.I.3
+#[test]
+fn test_fragment_errors() {
+    let cases = [
+        (
+            "{include:fragments/missing.md}",
+            "Instruction fragment not found: fragments/missing.md",
+        ),
+        (
+            "{include:../secret.md}",
+            "Instruction fragment must be a relative path inside the requirements directory: ../secret.md",
+        ),
+        (
+            "{include:fragments/loop.md}",
+            "Instruction fragment includes itself: fragments/loop.md",
+        ),
+    ];
+    for (agents, error) in cases {
+        let temp_dir = create_project(agents, "");
+        create_fragment(
+            &temp_dir,
+            "fragments/loop.md",
+            "{include:fragments/other.md}",
+        );
+        create_fragment(
+            &temp_dir,
+            "fragments/other.md",
+            "{include:fragments/loop.md}",
+        );
+
+        assert_eq!(get_instructions(&temp_dir)["error"], error);
+    }
+}
+
+// =============================================================================
+// Tests for G.I.4: Instructions settings
+// =============================================================================
+
+/// Test: a configured template is used for new AGENTS.md files
+/// Precondition: System has reqlix.toml with instructions.template and no requirements
+/// Action: Call reqlix_get_instructions
+/// Result: AGENTS.md has the template content, the response renders it
+/// Covers Requirement: G.I.4
+#[test]
+fn test_template_used_for_creation() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join("reqlix.toml"),
+        "[instructions]\ntemplate = \"AGENTS.template.md\"\nproject_name = \"Payments\"\n",
+    )
+    .unwrap();
+    fs::write(
+        temp_dir.path().join("AGENTS.template.md"),
+        "# {project_name} instructions\n",
+    )
+    .unwrap();
+
+    let rendered = content(&temp_dir);
+    let agents = fs::read_to_string(
+        temp_dir
+            .path()
+            .join("docs/development/requirements/AGENTS.md"),
+    )
+    .unwrap();
+
+    assert_eq!(agents, "# {project_name} instructions\n");
+    assert!(rendered.starts_with("# Payments instructions\n"));
+}
+
+/// Test: invalid instructions settings name the key
+/// Precondition: System has reqlix.toml content with an outside template and a malformed key chapter
+/// Action: Call parse_config
+/// Result: Errors naming instructions.template and instructions.key_chapters
+/// Covers Requirement: G.I.4
+#[test]
+fn test_invalid_instructions_settings() {
+    assert_eq!(
+        parse_config("[instructions]\ntemplate = \"../AGENTS.md\"\n").unwrap_err(),
+        "instructions.template must be a relative path inside the project: ../AGENTS.md"
+    );
+    assert_eq!(
+        parse_config("[instructions]\nkey_chapters = [\"Parameter Constraints\"]\n").unwrap_err(),
+        "instructions.key_chapters entries must have the form category/Chapter: Parameter Constraints"
+    );
+}
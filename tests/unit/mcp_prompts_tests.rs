@@ -0,0 +1,368 @@
+// Tests for MCP Prompts (G.MCPP.*)
+// Covers Requirements: G.MCPP.1, G.MCPP.2, G.MCPP.3, G.MCPP.4
+
+use reqlix::{get_prompt, list_prompts, validate_prompt_arguments};
+use std::collections::HashMap;
+use std::fs;
+use tempfile::TempDir;
+
+use super::common::{
+    create_agents_file_in_req_dir, create_category_file_in_req_dir, create_requirements_dir,
+};
+
+/// Create a project with categories general and testing and a source file citing G.R.2
+fn create_project() -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    let req_dir = create_requirements_dir(&temp_dir);
+    create_agents_file_in_req_dir(&req_dir, "# Instructions\n");
+    create_category_file_in_req_dir(
+        &req_dir,
+        "general",
+        "# Rules\n\n## G.R.1: One\n\nFirst text.\n\n## G.R.2: Two\n\nSecond text.\n",
+    );
+    create_category_file_in_req_dir(
+        &req_dir,
+        "testing",
+        "# Unit\n\n## T.U.1: Tests\n\nTest text.\n",
+    );
+    fs::create_dir_all(temp_dir.path().join("src")).unwrap();
+    fs::write(
+        temp_dir.path().join("src/lib.rs"),
+        "// G.R.2, G.R.9\nfn two() {}\n",
+    )
+    .unwrap();
+    temp_dir
+}
+
+/// Project root of a temp project
+fn root(temp_dir: &TempDir) -> String {
+    temp_dir.path().to_string_lossy().to_string()
+}
+
+/// Prompt arguments from name-value pairs
+fn arguments(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+    pairs
+        .iter()
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .collect()
+}
+
+// =============================================================================
+// Tests for G.MCPP.1: Prompt list
+// =============================================================================
+
+/// Test: all prompts are listed in order with their arguments
+/// Precondition: None
+/// Action: Call list_prompts
+/// Result: review_diff, draft_requirements, check_discrepancies, write_tests with required flags
+/// Covers Requirement: G.MCPP.1
+#[test]
+fn test_list_prompts() {
+    let prompts = list_prompts();
+
+    let names: Vec<&str> = prompts.iter().map(|p| p.name.as_str()).collect();
+    assert_eq!(
+        names,
+        vec![
+            "review_diff",
+            "draft_requirements",
+            "check_discrepancies",
+            "write_tests"
+        ]
+    );
+    let arguments: Vec<(&str, bool)> = prompts[3]
+        .arguments
+        .iter()
+        .map(|a| (a.name.as_str(), a.required))
+        .collect();
+    assert_eq!(arguments, vec![("index", true), ("file", false)]);
+    assert!(prompts
+        .iter()
+        .all(|p| !p.title.is_empty() && !p.description.is_empty()));
+}
+
+// =============================================================================
+// Tests for G.MCPP.2: Prompt arguments
+// =============================================================================
+
+/// Test: arguments are validated like tool parameters
+/// Precondition: None
+/// Action: Validate an invalid category, an empty diff and an unknown argument
+/// Result: Constraint violations are reported, unknown arguments are ignored
+/// Covers Requirement: G.MCPP.2
+#[test]
+fn test_validate_prompt_arguments() {
+    assert!(validate_prompt_arguments(
+        "review_diff",
+        &arguments(&[("diff", "+x"), ("category", "General")])
+    )
+    .unwrap_err()
+    .contains("lowercase"));
+    assert_eq!(
+        validate_prompt_arguments("review_diff", &arguments(&[("diff", "")])).unwrap_err(),
+        "diff is required"
+    );
+    assert!(validate_prompt_arguments(
+        "write_tests",
+        &arguments(&[("index", "G.R.1"), ("other", "x")])
+    )
+    .is_ok());
+}
+
+/// Test: prompts never create files
+/// Precondition: System has an empty project
+/// Action: Get the draft_requirements prompt
+/// Result: "Requirements directory not found", project stays empty
+/// Covers Requirement: G.MCPP.2
+#[test]
+fn test_prompt_without_requirements_directory() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let result = get_prompt(
+        &root(&temp_dir),
+        "draft_requirements",
+        &arguments(&[("feature", "Login")]),
+    );
+
+    assert_eq!(result.unwrap_err(), "Requirements directory not found");
+    assert_eq!(fs::read_dir(temp_dir.path()).unwrap().count(), 0);
+}
+
+// =============================================================================
+// Tests for G.MCPP.3: Prompt messages
+// =============================================================================
+
+/// Test: review_diff contains the diff and the referenced requirements
+/// Precondition: System has a project
+/// Action: Get review_diff for a diff citing G.R.1 twice and G.R.9, with category general
+/// Result: Diff in a fence, G.R.1 once under referenced requirements, G.R.2 under the category
+/// Covers Requirement: G.MCPP.3
+#[test]
+fn test_review_diff_prompt() {
+    let temp_dir = create_project();
+    let diff = "+// G.R.1\n+// G.R.1, G.R.9\n";
+
+    let prompt = get_prompt(
+        &root(&temp_dir),
+        "review_diff",
+        &arguments(&[("diff", diff), ("category", "general")]),
+    )
+    .unwrap();
+
+    assert!(prompt.text.contains("reqlix://instructions"));
+    assert!(prompt
+        .text
+        .contains("```diff\n+// G.R.1\n+// G.R.1, G.R.9\n```"));
+    assert!(prompt.text.ends_with(concat!(
+        "# Referenced requirements\n\n",
+        "## G.R.1: One\n\nFirst text.\n\n",
+        "# Requirements of category general\n\n",
+        "## G.R.2: Two\n\nSecond text.\n"
+    )));
+    assert!(!prompt.description.is_empty());
+}
+
+/// Test: empty lists are stated
+/// Precondition: System has a project
+/// Action: Get review_diff for a diff without references
+/// Result: The text says that the diff references no requirements
+/// Covers Requirement: G.MCPP.3
+#[test]
+fn test_review_diff_prompt_without_references() {
+    let temp_dir = create_project();
+
+    let prompt = get_prompt(
+        &root(&temp_dir),
+        "review_diff",
+        &arguments(&[("diff", "+fn x() {}\n")]),
+    )
+    .unwrap();
+
+    assert!(prompt
+        .text
+        .ends_with("# Referenced requirements\n\nThe diff references no requirements.\n"));
+}
+
+/// Test: draft_requirements lists categories and chapters
+/// Precondition: System has a project
+/// Action: Get draft_requirements for a feature
+/// Result: Feature text and "- general: Rules", "- testing: Unit"
+/// Covers Requirement: G.MCPP.3
+#[test]
+fn test_draft_requirements_prompt() {
+    let temp_dir = create_project();
+
+    let prompt = get_prompt(
+        &root(&temp_dir),
+        "draft_requirements",
+        &arguments(&[("feature", "Login with tokens")]),
+    )
+    .unwrap();
+
+    assert!(prompt.text.contains("# Feature\n\nLogin with tokens\n"));
+    assert!(prompt
+        .text
+        .ends_with("# Categories and chapters\n\n- general: Rules\n- testing: Unit\n"));
+}
+
+/// Test: check_discrepancies contains the file and its referenced requirements
+/// Precondition: System has a project with src/lib.rs citing G.R.2 and the missing G.R.9
+/// Action: Get check_discrepancies for src/lib.rs
+/// Result: File in a rust fence, G.R.2 only
+/// Covers Requirement: G.MCPP.3
+#[test]
+fn test_check_discrepancies_prompt() {
+    let temp_dir = create_project();
+
+    let prompt = get_prompt(
+        &root(&temp_dir),
+        "check_discrepancies",
+        &arguments(&[("file", "src/lib.rs")]),
+    )
+    .unwrap();
+
+    assert!(prompt
+        .text
+        .contains("# File src/lib.rs\n\n```rs\n// G.R.2, G.R.9\nfn two() {}\n```"));
+    assert!(prompt
+        .text
+        .ends_with("# Referenced requirements\n\n## G.R.2: Two\n\nSecond text.\n"));
+}
+
+/// Test: write_tests contains the requirement and the test file
+/// Precondition: System has a project
+/// Action: Get write_tests for T.U.1 with file src/lib.rs
+/// Result: Covers Requirement marker, requirement, then the file
+/// Covers Requirement: G.MCPP.3
+#[test]
+fn test_write_tests_prompt() {
+    let temp_dir = create_project();
+
+    let prompt = get_prompt(
+        &root(&temp_dir),
+        "write_tests",
+        &arguments(&[("index", "T.U.1"), ("file", "src/lib.rs")]),
+    )
+    .unwrap();
+
+    assert!(prompt.text.contains("`Covers Requirement: T.U.1`"));
+    assert!(prompt.text.ends_with(concat!(
+        "# Requirement\n\n## T.U.1: Tests\n\nTest text.\n\n",
+        "# Test file src/lib.rs\n\n```rs\n// G.R.2, G.R.9\nfn two() {}\n```\n"
+    )));
+}
+
+/// Test: the fence is longer than backtick runs in the content
+/// Precondition: System has a project
+/// Action: Get review_diff for a diff containing a code fence
+/// Result: The diff is fenced with four backticks
+/// Covers Requirement: G.MCPP.3
+#[test]
+fn test_prompt_fence_longer_than_content() {
+    let temp_dir = create_project();
+
+    let prompt = get_prompt(
+        &root(&temp_dir),
+        "review_diff",
+        &arguments(&[("diff", "+```\n")]),
+    )
+    .unwrap();
+
+    assert!(prompt.text.contains("````diff\n+```\n````"));
+}
+
+// =============================================================================
+// Tests for G.MCPP.4: Prompt errors
+// =============================================================================
+
+/// Test: unknown prompts and missing arguments are rejected
+/// Precondition: None
+/// Action: Validate an unknown prompt and write_tests without index
+/// Result: "Unknown prompt: other", "index is required"
+/// Covers Requirement: G.MCPP.4
+#[test]
+fn test_prompt_argument_errors() {
+    assert_eq!(
+        validate_prompt_arguments("other", &HashMap::new()).unwrap_err(),
+        "Unknown prompt: other"
+    );
+    assert_eq!(
+        validate_prompt_arguments("write_tests", &HashMap::new()).unwrap_err(),
+        "index is required"
+    );
+}
+
+/// Test: missing requirements, categories and files are reported
+/// Precondition: System has a project
+/// Action: Get prompts for G.R.9, category security and file src/none.rs
+/// Result: "Requirement not found", "Category not found", "File not found: ..."
+/// Covers Requirement: G.MCPP.4
+#[test]
+fn test_prompt_missing_material() {
+    let temp_dir = create_project();
+
+    assert_eq!(
+        get_prompt(
+            &root(&temp_dir),
+            "write_tests",
+            &arguments(&[("index", "G.R.9")])
+        )
+        .unwrap_err(),
+        "Requirement not found"
+    );
+    assert_eq!(
+        get_prompt(
+            &root(&temp_dir),
+            "draft_requirements",
+            &arguments(&[("feature", "x"), ("category", "security")])
+        )
+        .unwrap_err(),
+        "Category not found"
+    );
+    assert!(get_prompt(
+        &root(&temp_dir),
+        "check_discrepancies",
+        &arguments(&[("file", "src/none.rs")])
+    )
+    .unwrap_err()
+    .starts_with("File not found: "));
+}
+
+/// Test: prompt files must stay inside the project
+/// Precondition: System has a project and a file outside it, linked from the project on Unix
+/// Action: Validate file "../secret.rs" and an absolute path, then get check_discrepancies and write_tests for the link
+/// Result: Each is rejected with "file must be a relative path inside the project: {file}"
+/// Covers Requirement: G.MCPP.2, G.P.6
+#[test]
+fn test_prompt_file_outside_project() {
+    let outer = TempDir::new().unwrap();
+    let secret = outer.path().join("secret.rs");
+    fs::write(&secret, "fn secret() {}\n").unwrap();
+    let temp_dir = create_project();
+
+    let absolute = secret.to_string_lossy().to_string();
+    for file in ["../secret.rs", absolute.as_str()] {
+        assert_eq!(
+            validate_prompt_arguments("check_discrepancies", &arguments(&[("file", file)]))
+                .unwrap_err(),
+            format!("file must be a relative path inside the project: {}", file)
+        );
+    }
+
+    #[cfg(unix)]
+    {
+        std::os::unix::fs::symlink(&secret, temp_dir.path().join("link.rs")).unwrap();
+        for (name, args) in [
+            ("check_discrepancies", arguments(&[("file", "link.rs")])),
+            (
+                "write_tests",
+                arguments(&[("index", "T.U.1"), ("file", "link.rs")]),
+            ),
+        ] {
+            assert_eq!(
+                get_prompt(&root(&temp_dir), name, &args).unwrap_err(),
+                "file must be a relative path inside the project: link.rs"
+            );
+        }
+    }
+}
@@ -0,0 +1,470 @@
+// Tests for MCP Resources (G.M.*)
+// Covers Requirements: G.M.1, G.M.2, G.M.3, G.M.4, G.M.5, G.M.6, G.M.7, G.M.8
+
+use reqlix::{
+    encode_uri_segment, list_resources, read_resource, resource_changes, resource_snapshot,
+    RequirementsWatcher, ResourceChanges, ResourceUri,
+};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::mpsc::channel;
+use std::time::Duration;
+use tempfile::TempDir;
+
+use super::common::{
+    create_agents_file_in_req_dir, create_category_file_in_req_dir, create_requirements_dir,
+};
+
+/// Create a project with two chapters in general.md and one in testing.md
+fn create_project() -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    let req_dir = create_requirements_dir(&temp_dir);
+    create_agents_file_in_req_dir(&req_dir, "# Instructions\n\nFollow them.\n");
+    create_category_file_in_req_dir(
+        &req_dir,
+        "general",
+        "# Rules\n\n## G.R.1: One\n\nFirst text.\n\n## G.R.2: Two\n\nSecond text.\n\n\
+# Tool: reqlix_trace\n\n## G.T.1: Trace\n\nTrace text.\n",
+    );
+    create_category_file_in_req_dir(
+        &req_dir,
+        "testing",
+        "# Unit\n\n## T.U.1: Tests\n\nTest text.\n",
+    );
+    temp_dir
+}
+
+/// Project root of a temp project
+fn root(temp_dir: &TempDir) -> String {
+    temp_dir.path().to_string_lossy().to_string()
+}
+
+// =============================================================================
+// Tests for G.M.1: Resource URIs
+// =============================================================================
+
+/// Test: URIs are parsed and printed with percent-encoding
+/// Precondition: System has URIs of every resource type, one with an encoded chapter name
+/// Action: Call ResourceUri::parse and uri
+/// Result: Resources are recognized and the canonical URI round-trips
+/// Covers Requirement: G.M.1
+#[test]
+fn test_parse_resource_uris() {
+    assert_eq!(
+        ResourceUri::parse("reqlix://instructions").unwrap(),
+        ResourceUri::Instructions
+    );
+    assert_eq!(
+        ResourceUri::parse("reqlix://category/general").unwrap(),
+        ResourceUri::Category("general".to_string())
+    );
+    let chapter = ResourceUri::parse("reqlix://chapter/general/Tool%3A%20reqlix_trace").unwrap();
+    assert_eq!(
+        chapter,
+        ResourceUri::Chapter("general".to_string(), "Tool: reqlix_trace".to_string())
+    );
+    assert_eq!(
+        chapter.uri(),
+        "reqlix://chapter/general/Tool%3A%20reqlix_trace"
+    );
+    assert_eq!(
+        ResourceUri::parse("reqlix://G.REQLIX_I.7").unwrap(),
+        ResourceUri::Requirement("G.REQLIX_I.7".to_string())
+    );
+    assert_eq!(encode_uri_segment("a b/ü~"), "a%20b%2F%C3%BC~");
+}
+
+/// Test: malformed URIs are rejected
+/// Precondition: System has URIs with another scheme, unknown paths, bad escapes and empty segments
+/// Action: Call ResourceUri::parse
+/// Result: "Invalid resource URI: {uri}" for each
+/// Covers Requirement: G.M.1, G.M.5
+#[test]
+fn test_parse_invalid_resource_uris() {
+    for uri in [
+        "file:///G.R.1",
+        "reqlix://",
+        "reqlix://general",
+        "reqlix://category/",
+        "reqlix://category/a/b",
+        "reqlix://chapter/general",
+        "reqlix://G.R%2",
+        "reqlix://G.R.%FF",
+    ] {
+        assert_eq!(
+            ResourceUri::parse(uri).unwrap_err(),
+            format!("Invalid resource URI: {}", uri)
+        );
+    }
+}
+
+// =============================================================================
+// Tests for G.M.2: Resource contents
+// =============================================================================
+
+/// Test: requirement and chapter resources are rendered as markdown
+/// Precondition: System has chapter Rules with G.R.1 and G.R.2
+/// Action: Read reqlix://G.R.2 and the chapter Rules
+/// Result: Requirement heading and text; chapter heading and both requirements
+/// Covers Requirement: G.M.2
+#[test]
+fn test_read_requirement_and_chapter() {
+    let temp_dir = create_project();
+
+    let requirement = read_resource(&root(&temp_dir), "reqlix://G.R.2").unwrap();
+    assert_eq!(requirement.uri, "reqlix://G.R.2");
+    assert_eq!(requirement.mime_type, "text/markdown");
+    assert_eq!(requirement.text, "## G.R.2: Two\n\nSecond text.\n");
+
+    let chapter = read_resource(&root(&temp_dir), "reqlix://chapter/general/Rules").unwrap();
+    assert_eq!(
+        chapter.text,
+        "# Rules\n\n## G.R.1: One\n\nFirst text.\n\n## G.R.2: Two\n\nSecond text.\n"
+    );
+}
+
+/// Test: instructions and category resources return the file content
+/// Precondition: System has AGENTS.md and general.md
+/// Action: Read reqlix://instructions and reqlix://category/general
+/// Result: The file contents as is
+/// Covers Requirement: G.M.2
+#[test]
+fn test_read_files() {
+    let temp_dir = create_project();
+    let req_dir = temp_dir.path().join("docs/development/requirements");
+
+    let instructions = read_resource(&root(&temp_dir), "reqlix://instructions").unwrap();
+    assert_eq!(
+        instructions.text,
+        fs::read_to_string(req_dir.join("AGENTS.md")).unwrap()
+    );
+
+    let category = read_resource(&root(&temp_dir), "reqlix://category/general").unwrap();
+    assert_eq!(
+        category.text,
+        fs::read_to_string(req_dir.join("general.md")).unwrap()
+    );
+}
+
+// =============================================================================
+// Tests for G.M.3: Resource listing
+// =============================================================================
+
+/// Test: resources are listed in the specified order with names and titles
+/// Precondition: System has categories general (two chapters) and testing
+/// Action: Call list_resources
+/// Result: Instructions, categories, chapters, then requirements
+/// Covers Requirement: G.M.3
+#[test]
+fn test_list_resources() {
+    let temp_dir = create_project();
+
+    let resources = list_resources(&root(&temp_dir)).unwrap();
+
+    let uris: Vec<&str> = resources.iter().map(|r| r.uri.as_str()).collect();
+    assert_eq!(
+        uris,
+        vec![
+            "reqlix://instructions",
+            "reqlix://category/general",
+            "reqlix://category/testing",
+            "reqlix://chapter/general/Rules",
+            "reqlix://chapter/general/Tool%3A%20reqlix_trace",
+            "reqlix://chapter/testing/Unit",
+            "reqlix://G.R.1",
+            "reqlix://G.R.2",
+            "reqlix://G.T.1",
+            "reqlix://T.U.1",
+        ]
+    );
+    assert_eq!(resources[4].name, "chapter/general/Tool: reqlix_trace");
+    assert_eq!(resources[4].title, "Chapter: Tool: reqlix_trace (general)");
+    assert_eq!(resources[6].name, "G.R.1");
+    assert_eq!(resources[6].title, "G.R.1: One");
+    assert!(resources.iter().all(|r| r.mime_type == "text/markdown"));
+}
+
+/// Test: repeated chapter headings are listed once
+/// Precondition: System has a category where chapter Rules occurs twice
+/// Action: Call list_resources
+/// Result: One resource per URI, the chapter at its first position
+/// Covers Requirement: G.M.3
+#[test]
+fn test_list_resources_unique_uris() {
+    let temp_dir = TempDir::new().unwrap();
+    let req_dir = create_requirements_dir(&temp_dir);
+    create_agents_file_in_req_dir(&req_dir, "# Instructions\n");
+    create_category_file_in_req_dir(
+        &req_dir,
+        "general",
+        "# Rules\n\n## G.R.1: One\n\nText.\n\n# Other\n\n## G.O.1: Other\n\nText.\n\n\
+# Rules\n\n## G.R.2: Two\n\nText.\n",
+    );
+
+    let resources = list_resources(&root(&temp_dir)).unwrap();
+
+    let uris: Vec<&str> = resources.iter().map(|r| r.uri.as_str()).collect();
+    assert_eq!(
+        uris,
+        vec![
+            "reqlix://instructions",
+            "reqlix://category/general",
+            "reqlix://chapter/general/Rules",
+            "reqlix://chapter/general/Other",
+            "reqlix://G.R.1",
+            "reqlix://G.O.1",
+            "reqlix://G.R.2",
+        ]
+    );
+}
+
+// =============================================================================
+// Tests for G.M.4: Resource project root
+// =============================================================================
+
+/// Test: resources never create the requirements directory
+/// Precondition: System has an empty directory
+/// Action: Call list_resources and read_resource
+/// Result: Empty list, "Requirements directory not found", and no files are created
+/// Covers Requirement: G.M.4
+#[test]
+fn test_resources_without_project() {
+    let temp_dir = TempDir::new().unwrap();
+
+    assert!(list_resources(&root(&temp_dir)).unwrap().is_empty());
+    assert_eq!(
+        read_resource(&root(&temp_dir), "reqlix://instructions").unwrap_err(),
+        "Requirements directory not found"
+    );
+    assert_eq!(fs::read_dir(temp_dir.path()).unwrap().count(), 0);
+}
+
+// =============================================================================
+// Tests for G.M.5: Resource errors
+// =============================================================================
+
+/// Test: missing resources are reported
+/// Precondition: System has a project
+/// Action: Read an unknown category, chapter and requirement
+/// Result: "Category not found", "Chapter not found", "Requirement not found"
+/// Covers Requirement: G.M.5
+#[test]
+fn test_read_missing_resources() {
+    let temp_dir = create_project();
+
+    assert_eq!(
+        read_resource(&root(&temp_dir), "reqlix://category/security").unwrap_err(),
+        "Category not found"
+    );
+    assert_eq!(
+        read_resource(&root(&temp_dir), "reqlix://chapter/general/Other").unwrap_err(),
+        "Chapter not found"
+    );
+    assert_eq!(
+        read_resource(&root(&temp_dir), "reqlix://G.R.9").unwrap_err(),
+        "Requirement not found"
+    );
+}
+
+// =============================================================================
+// Tests for G.M.6: Resource subscriptions
+// =============================================================================
+
+/// Requirements directory of a temp project
+fn requirements_dir(temp_dir: &TempDir) -> PathBuf {
+    temp_dir.path().join("docs/development/requirements")
+}
+
+/// Test: the watch reports edits of category files
+/// Precondition: System has a project watched by RequirementsWatcher
+/// Action: Change the text of T.U.1 in testing.md
+/// Result: The callback receives the category, chapter and requirement URIs
+/// Covers Requirement: G.M.6, G.M.8
+#[test]
+fn test_watcher_reports_changes() {
+    let temp_dir = create_project();
+    let (sender, receiver) = channel();
+    let _watcher = RequirementsWatcher::start(requirements_dir(&temp_dir), move |changes| {
+        let _ = sender.send(changes);
+    })
+    .unwrap();
+
+    fs::write(
+        requirements_dir(&temp_dir).join("testing.md"),
+        "# Unit\n\n## T.U.1: Tests\n\nChanged text.\n",
+    )
+    .unwrap();
+
+    let changes = receiver.recv_timeout(Duration::from_secs(5)).unwrap();
+    assert_eq!(
+        changes.updated,
+        vec![
+            "reqlix://category/testing",
+            "reqlix://chapter/testing/Unit",
+            "reqlix://T.U.1",
+        ]
+    );
+    assert!(!changes.list_changed);
+}
+
+/// Test: files other than markdown are ignored
+/// Precondition: System has a project watched by RequirementsWatcher
+/// Action: Write a text file into the requirements directory
+/// Result: The callback is not called
+/// Covers Requirement: G.M.6
+#[test]
+fn test_watcher_ignores_other_files() {
+    let temp_dir = create_project();
+    let (sender, receiver) = channel();
+    let _watcher = RequirementsWatcher::start(requirements_dir(&temp_dir), move |changes| {
+        let _ = sender.send(changes);
+    })
+    .unwrap();
+
+    fs::write(requirements_dir(&temp_dir).join("notes.txt"), "notes").unwrap();
+
+    assert!(receiver.recv_timeout(Duration::from_millis(500)).is_err());
+}
+
+// =============================================================================
+// Tests for G.M.7: Change detection
+// =============================================================================
+
+/// Changes between the project state and the state after `edit`
+fn changes_after(edit: impl FnOnce(&PathBuf)) -> ResourceChanges {
+    let temp_dir = create_project();
+    let dir = requirements_dir(&temp_dir);
+    let before = resource_snapshot(&dir).unwrap();
+    edit(&dir);
+    resource_changes(&before, &resource_snapshot(&dir).unwrap())
+}
+
+/// Test: saving files without changes produces nothing
+/// Precondition: System has a project
+/// Action: Rewrite general.md with the same content
+/// Result: No changed resources and no list change
+/// Covers Requirement: G.M.7
+#[test]
+fn test_changes_none_for_same_content() {
+    let changes = changes_after(|dir| {
+        let content = fs::read_to_string(dir.join("general.md")).unwrap();
+        fs::write(dir.join("general.md"), content).unwrap();
+    });
+    assert_eq!(changes, ResourceChanges::default());
+}
+
+/// Test: a title edit changes the category, its chapter and the requirement only
+/// Precondition: System has a project
+/// Action: Change the title of G.R.2
+/// Result: category/general, chapter/general/Rules and G.R.2 changed
+/// Covers Requirement: G.M.7
+#[test]
+fn test_changes_for_title_edit() {
+    let changes = changes_after(|dir| {
+        let content = fs::read_to_string(dir.join("general.md")).unwrap();
+        fs::write(
+            dir.join("general.md"),
+            content.replace("## G.R.2: Two", "## G.R.2: Second"),
+        )
+        .unwrap();
+    });
+    assert_eq!(
+        changes.updated,
+        vec![
+            "reqlix://category/general",
+            "reqlix://chapter/general/Rules",
+            "reqlix://G.R.2",
+        ]
+    );
+    assert!(!changes.list_changed);
+}
+
+/// Test: a formatting-only edit changes the category but no requirement
+/// Precondition: System has a project
+/// Action: Add a trailing blank line to general.md
+/// Result: Only category/general changed
+/// Covers Requirement: G.M.7
+#[test]
+fn test_changes_for_formatting_edit() {
+    let changes = changes_after(|dir| {
+        let content = fs::read_to_string(dir.join("general.md")).unwrap();
+        fs::write(dir.join("general.md"), content + "\n").unwrap();
+    });
+    assert_eq!(changes.updated, vec!["reqlix://category/general"]);
+    assert!(!changes.list_changed);
+}
+
+/// Test: an edit of AGENTS.md changes the instructions
+/// Precondition: System has a project
+/// Action: Rewrite AGENTS.md
+/// Result: Only reqlix://instructions changed
+/// Covers Requirement: G.M.7
+#[test]
+fn test_changes_for_instructions() {
+    let changes = changes_after(|dir| {
+        fs::write(dir.join("AGENTS.md"), "# Instructions\n\nNew rules.\n").unwrap();
+    });
+    assert_eq!(changes.updated, vec!["reqlix://instructions"]);
+    assert!(!changes.list_changed);
+}
+
+/// Test: added and removed resources change the list only
+/// Precondition: System has a project
+/// Action: Add a requirement to testing.md, remove category file general.md
+/// Result: testing resources changed (not T.U.2), list changed
+/// Covers Requirement: G.M.7
+#[test]
+fn test_changes_for_added_and_removed_resources() {
+    let changes = changes_after(|dir| {
+        fs::remove_file(dir.join("general.md")).unwrap();
+        fs::write(
+            dir.join("testing.md"),
+            "# Unit\n\n## T.U.1: Tests\n\nTest text.\n\n## T.U.2: More\n\nMore text.\n",
+        )
+        .unwrap();
+    });
+    assert_eq!(
+        changes.updated,
+        vec!["reqlix://category/testing", "reqlix://chapter/testing/Unit"]
+    );
+    assert!(changes.list_changed);
+}
+
+// =============================================================================
+// Tests for G.M.8: Change notifications
+// =============================================================================
+
+/// Test: changed resources are reported in listing order
+/// Precondition: System has a project
+/// Action: Change AGENTS.md, G.T.1 and T.U.1 at once
+/// Result: Instructions, categories, chapters, then requirements
+/// Covers Requirement: G.M.8
+#[test]
+fn test_changes_in_listing_order() {
+    let changes = changes_after(|dir| {
+        fs::write(dir.join("AGENTS.md"), "# Instructions\n\nNew rules.\n").unwrap();
+        let content = fs::read_to_string(dir.join("general.md")).unwrap();
+        fs::write(
+            dir.join("general.md"),
+            content.replace("Trace text.", "Trace all."),
+        )
+        .unwrap();
+        fs::write(
+            dir.join("testing.md"),
+            "# Unit\n\n## T.U.1: Tests\n\nOther text.\n",
+        )
+        .unwrap();
+    });
+    assert_eq!(
+        changes.updated,
+        vec![
+            "reqlix://instructions",
+            "reqlix://category/general",
+            "reqlix://category/testing",
+            "reqlix://chapter/general/Tool%3A%20reqlix_trace",
+            "reqlix://chapter/testing/Unit",
+            "reqlix://G.T.1",
+            "reqlix://T.U.1",
+        ]
+    );
+}
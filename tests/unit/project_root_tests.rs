@@ -0,0 +1,360 @@
+// Tests for Project Root (G.PR.*)
+// Covers Requirements: G.PR.1, G.PR.2, G.PR.3
+
+use reqlix::{resolve_project_root, root_uri_path, RequirementsServer};
+use rmcp::model::{
+    CallToolRequestParam, CallToolResult, ClientCapabilities, ClientInfo, ErrorData,
+    ListRootsResult,
+};
+use rmcp::service::{Peer, RequestContext, RoleClient};
+use rmcp::ClientHandler;
+use std::future::Future;
+use std::process::Command;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tempfile::TempDir;
+
+use super::common::{
+    connect_client, connect_with, create_agents_file_in_req_dir, create_category_file_in_req_dir,
+    create_requirements_dir,
+};
+
+/// Client that provides the roots in `roots`
+#[derive(Clone, Default)]
+struct RootsClient {
+    roots: Arc<Mutex<Vec<String>>>,
+}
+
+impl RootsClient {
+    /// Client with file roots of the given directories
+    fn new(dirs: &[&TempDir]) -> Self {
+        let client = Self::default();
+        client.set_roots(dirs);
+        client
+    }
+
+    /// Replace the roots of the client
+    fn set_roots(&self, dirs: &[&TempDir]) {
+        *self.roots.lock().unwrap() = dirs
+            .iter()
+            .map(|dir| format!("file://{}", dir.path().display()))
+            .collect();
+    }
+}
+
+impl ClientHandler for RootsClient {
+    fn list_roots(
+        &self,
+        _context: RequestContext<RoleClient>,
+    ) -> impl Future<Output = Result<ListRootsResult, ErrorData>> + Send + '_ {
+        let roots: Vec<serde_json::Value> = self
+            .roots
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|uri| serde_json::json!({ "uri": uri }))
+            .collect();
+        std::future::ready(Ok(serde_json::from_value(
+            serde_json::json!({ "roots": roots }),
+        )
+        .unwrap()))
+    }
+
+    fn get_info(&self) -> ClientInfo {
+        ClientInfo {
+            capabilities: ClientCapabilities::builder()
+                .enable_roots()
+                .enable_roots_list_changed()
+                .build(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Create a project with one category
+fn create_project(category: &str) -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    let req_dir = create_requirements_dir(&temp_dir);
+    create_agents_file_in_req_dir(&req_dir, "# Instructions\n");
+    create_category_file_in_req_dir(&req_dir, category, "# Rules\n\n## X.R.1: One\n\nText.\n");
+    temp_dir
+}
+
+/// Project root of a temp project
+fn root(temp_dir: &TempDir) -> String {
+    temp_dir.path().to_string_lossy().to_string()
+}
+
+/// Call reqlix_get_categories with optional project_root
+async fn get_categories(client: &Peer<RoleClient>, project_root: Option<&str>) -> CallToolResult {
+    let mut arguments = serde_json::json!({ "operation_description": "Listing categories" });
+    if let Some(project_root) = project_root {
+        arguments["project_root"] = project_root.into();
+    }
+    client
+        .call_tool(CallToolRequestParam {
+            name: "reqlix_get_categories".into(),
+            arguments: arguments.as_object().cloned(),
+        })
+        .await
+        .unwrap()
+}
+
+/// Structured content of a tool result
+fn structured(result: CallToolResult) -> serde_json::Value {
+    result.structured_content.unwrap()
+}
+
+// =============================================================================
+// Tests for G.PR.1: Default project root
+// =============================================================================
+
+/// Test: the server default wins over client roots
+/// Precondition: System has no, one and two roots with and without a default
+/// Action: Call resolve_project_root
+/// Result: Default, single root, None and an error listing the roots
+/// Covers Requirement: G.PR.1
+#[test]
+fn test_resolve_project_root() {
+    let roots = vec!["/a".to_string(), "/b".to_string()];
+
+    assert_eq!(
+        resolve_project_root(Some("/d"), &roots).unwrap(),
+        Some("/d".to_string())
+    );
+    assert_eq!(
+        resolve_project_root(None, &roots[..1]).unwrap(),
+        Some("/a".to_string())
+    );
+    assert_eq!(resolve_project_root(None, &[]).unwrap(), None);
+    assert_eq!(
+        resolve_project_root(None, &roots).unwrap_err(),
+        "project_root is ambiguous; pass one of the client roots: /a, /b"
+    );
+}
+
+/// Test: a call without project_root uses the server default
+/// Precondition: System has a server with a default project root
+/// Action: Call reqlix_get_categories without project_root
+/// Result: Categories of the default project
+/// Covers Requirement: G.PR.1
+#[tokio::test]
+async fn test_call_uses_server_default() {
+    let temp_dir = create_project("general");
+    let server = RequirementsServer::new().with_project_root(Some(root(&temp_dir)));
+    let client = connect_with(server, ()).await;
+
+    let result = structured(get_categories(&client, None).await);
+
+    assert_eq!(result["data"]["categories"], serde_json::json!(["general"]));
+    client.cancel().await.unwrap();
+}
+
+/// Test: a passed project_root wins over the server default
+/// Precondition: System has a server whose default project has another category
+/// Action: Call reqlix_get_categories with project_root
+/// Result: Categories of the passed project
+/// Covers Requirement: G.PR.1
+#[tokio::test]
+async fn test_argument_overrides_default() {
+    let default = create_project("general");
+    let temp_dir = create_project("testing");
+    let server = RequirementsServer::new().with_project_root(Some(root(&default)));
+    let client = connect_with(server, ()).await;
+
+    let result = structured(get_categories(&client, Some(&root(&temp_dir))).await);
+
+    assert_eq!(result["data"]["categories"], serde_json::json!(["testing"]));
+    client.cancel().await.unwrap();
+}
+
+/// Test: a call without project_root fails without default and roots
+/// Precondition: System has a server without default and a client without roots
+/// Action: Call reqlix_get_categories without project_root
+/// Result: Tool error saying that project_root is required
+/// Covers Requirement: G.PR.1
+#[tokio::test]
+async fn test_missing_project_root_is_tool_error() {
+    let client = connect_client().await;
+
+    let result = get_categories(&client, None).await;
+
+    assert_eq!(result.is_error, Some(true));
+    assert!(structured(result)["error"]
+        .as_str()
+        .unwrap()
+        .starts_with("project_root is required"));
+    client.cancel().await.unwrap();
+}
+
+/// Test: the default project root applies to resources
+/// Precondition: System has a server with a default project root
+/// Action: List resources
+/// Result: Resources of the default project
+/// Covers Requirement: G.PR.1
+#[tokio::test]
+async fn test_resources_use_server_default() {
+    let temp_dir = create_project("general");
+    let server = RequirementsServer::new().with_project_root(Some(root(&temp_dir)));
+    let client = connect_with(server, ()).await;
+
+    let resources = client.list_all_resources().await.unwrap();
+
+    assert!(resources
+        .iter()
+        .any(|resource| resource.uri == "reqlix://category/general"));
+    client.cancel().await.unwrap();
+}
+
+// =============================================================================
+// Tests for G.PR.2: Client roots
+// =============================================================================
+
+/// Test: file root URIs are decoded into directories
+/// Precondition: System has file URIs with host, encoding, drive letter and other schemes
+/// Action: Call root_uri_path
+/// Result: Local paths for file URIs, None otherwise
+/// Covers Requirement: G.PR.2
+#[test]
+fn test_root_uri_path() {
+    assert_eq!(
+        root_uri_path("file:///home/user/my%20project/").unwrap(),
+        "/home/user/my project"
+    );
+    assert_eq!(
+        root_uri_path("file://localhost/srv/app").unwrap(),
+        "/srv/app"
+    );
+    assert_eq!(root_uri_path("file:///C:/work").unwrap(), "C:/work");
+    assert_eq!(root_uri_path("file:///").unwrap(), "/");
+    assert_eq!(root_uri_path("file://server/share"), None);
+    assert_eq!(root_uri_path("https://example.com/repo"), None);
+}
+
+/// Test: a call without project_root uses the only client root
+/// Precondition: System has a client with one root
+/// Action: Call reqlix_get_categories without project_root
+/// Result: Categories of the root project
+/// Covers Requirement: G.PR.2
+#[tokio::test]
+async fn test_call_uses_single_client_root() {
+    let temp_dir = create_project("general");
+    let client = connect_with(RequirementsServer::new(), RootsClient::new(&[&temp_dir])).await;
+
+    let result = structured(get_categories(&client, None).await);
+
+    assert_eq!(result["data"]["categories"], serde_json::json!(["general"]));
+    client.cancel().await.unwrap();
+}
+
+/// Test: several client roots are ambiguous
+/// Precondition: System has a client with two roots
+/// Action: Call reqlix_get_categories without project_root
+/// Result: Tool error listing both root directories
+/// Covers Requirement: G.PR.2
+#[tokio::test]
+async fn test_ambiguous_client_roots() {
+    let first = create_project("general");
+    let second = create_project("testing");
+    let client = connect_with(
+        RequirementsServer::new(),
+        RootsClient::new(&[&first, &second]),
+    )
+    .await;
+
+    let result = get_categories(&client, None).await;
+
+    assert_eq!(result.is_error, Some(true));
+    let error = structured(result)["error"].as_str().unwrap().to_string();
+    assert!(error.starts_with("project_root is ambiguous"));
+    assert!(error.contains(&root(&first)) && error.contains(&root(&second)));
+    client.cancel().await.unwrap();
+}
+
+/// Test: several client roots are ambiguous for resources too
+/// Precondition: System has a client with two roots and no default project root
+/// Action: List resources
+/// Result: Request error listing both root directories, no fallback to the current directory
+/// Covers Requirement: G.M.4, G.PR.2
+#[tokio::test]
+async fn test_ambiguous_client_roots_for_resources() {
+    let first = create_project("general");
+    let second = create_project("testing");
+    let client = connect_with(
+        RequirementsServer::new(),
+        RootsClient::new(&[&first, &second]),
+    )
+    .await;
+
+    let error = client.list_all_resources().await.unwrap_err().to_string();
+
+    assert!(error.contains("project_root is ambiguous"));
+    assert!(error.contains(&root(&first)) && error.contains(&root(&second)));
+    client.cancel().await.unwrap();
+}
+
+/// Test: changed roots are requested again
+/// Precondition: System has a client with one root that was used
+/// Action: Replace the root, send notifications/roots/list_changed and call again
+/// Result: Categories of the new root project
+/// Covers Requirement: G.PR.2
+#[tokio::test]
+async fn test_roots_list_changed() {
+    let first = create_project("general");
+    let second = create_project("testing");
+    let roots = RootsClient::new(&[&first]);
+    let client = connect_with(RequirementsServer::new(), roots.clone()).await;
+    let first_result = structured(get_categories(&client, None).await);
+    assert_eq!(
+        first_result["data"]["categories"],
+        serde_json::json!(["general"])
+    );
+
+    roots.set_roots(&[&second]);
+    client.notify_roots_list_changed().await.unwrap();
+
+    let mut categories = serde_json::Value::Null;
+    for _ in 0..50 {
+        categories = structured(get_categories(&client, None).await)["data"]["categories"].clone();
+        if categories == serde_json::json!(["testing"]) {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    assert_eq!(categories, serde_json::json!(["testing"]));
+    client.cancel().await.unwrap();
+}
+
+// =============================================================================
+// Tests for G.PR.3: Project root option
+// =============================================================================
+
+/// Test: a missing project root directory is rejected with exit status 2
+/// Precondition: System has the reqlix binary
+/// Action: Run with --project-root and with REQLIX_PROJECT_ROOT of a missing directory
+/// Result: Exit status 2, the error and the usage text
+/// Covers Requirement: G.PR.3
+#[test]
+fn test_binary_rejects_missing_project_root() {
+    let temp_dir = TempDir::new().unwrap();
+    let missing = temp_dir.path().join("missing");
+
+    let outputs = [
+        Command::new(env!("CARGO_BIN_EXE_reqlix"))
+            .arg("--project-root")
+            .arg(&missing)
+            .output()
+            .unwrap(),
+        Command::new(env!("CARGO_BIN_EXE_reqlix"))
+            .env("REQLIX_PROJECT_ROOT", &missing)
+            .output()
+            .unwrap(),
+    ];
+
+    for output in outputs {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert_eq!(output.status.code(), Some(2));
+        assert!(stderr.contains("Project root is not a directory"));
+        assert!(stderr.contains("Usage: reqlix"));
+    }
+}
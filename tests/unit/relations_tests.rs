@@ -0,0 +1,336 @@
+// Tests for Relations (G.RELA.*)
+// Covers Requirements: G.RELA.1, G.RELA.2, G.RELA.3, G.RELA.4
+
+use reqlix::{
+    apply_relations, parse_relation_line, parse_relations, InsertRequirementParams, RelationsParam,
+    RequirementsServer, UpdateItem, UpdateRequirementParams,
+};
+use serde_json::Value;
+use std::fs;
+use tempfile::TempDir;
+
+use super::common::{
+    create_agents_file_in_req_dir, create_category_file_in_req_dir, create_requirements_dir,
+    parse_response,
+};
+
+/// Create a project with requirements G.C.1, G.C.2 and G.R.1
+fn setup_project(temp_dir: &TempDir) -> std::path::PathBuf {
+    let req_dir = create_requirements_dir(temp_dir);
+    create_agents_file_in_req_dir(&req_dir, "# Instructions\n");
+    create_category_file_in_req_dir(
+        &req_dir,
+        "general",
+        "# Configuration\n\n## G.C.1: Location\n\nText.\n\n\
+## G.C.2: Directory creation\n\nText.\n\n\
+# Requirements\n\n## G.R.1: Format\n\nText.\n",
+    );
+    req_dir
+}
+
+/// Relations with the given depends_on and verifies targets
+fn relations(depends_on: &[&str], verifies: &[&str]) -> RelationsParam {
+    let to_vec = |v: &[&str]| Some(v.iter().map(|s| s.to_string()).collect());
+    RelationsParam {
+        depends_on: to_vec(depends_on),
+        verifies: to_vec(verifies),
+        ..Default::default()
+    }
+}
+
+/// Insert a requirement into chapter "Requirements" and return parsed response
+fn insert(temp_dir: &TempDir, text: &str, relations: Option<RelationsParam>) -> Value {
+    let params = InsertRequirementParams {
+        project_root: temp_dir.path().to_string_lossy().to_string(),
+        operation_description: "test".to_string(),
+        category: "general".to_string(),
+        chapter: "Requirements".to_string(),
+        title: "New".to_string(),
+        text: text.to_string(),
+        warn_similar_chapter: None,
+        relations,
+    };
+    parse_response(&RequirementsServer::handle_insert_requirement(params))
+}
+
+/// Update a requirement text in single mode and return parsed response
+fn update(temp_dir: &TempDir, index: &str, relations: Option<RelationsParam>) -> Value {
+    let params = UpdateRequirementParams {
+        project_root: temp_dir.path().to_string_lossy().to_string(),
+        operation_description: "test".to_string(),
+        index: Some(index.to_string()),
+        text: Some("Updated.".to_string()),
+        title: None,
+        items: None,
+        relations,
+        rewrite_links: None,
+    };
+    parse_response(&RequirementsServer::handle_update_requirement(params))
+}
+
+// =============================================================================
+// Tests for G.RELA.2: Relation storage
+// =============================================================================
+
+/// Test: parse_relation_line recognizes relation lines
+/// Precondition: System has relation lines, a plain list item and an unknown type
+/// Action: Call parse_relation_line
+/// Result: Relation lines return type and trimmed targets, other lines return None
+/// Covers Requirement: G.RELA.1, G.RELA.2
+#[test]
+fn test_parse_relation_line() {
+    assert_eq!(
+        parse_relation_line("- depends_on: G.C.2,  G.C.3 "),
+        Some(("depends_on", vec!["G.C.2".to_string(), "G.C.3".to_string()]))
+    );
+    assert_eq!(
+        parse_relation_line("- verifies: G.R.1"),
+        Some(("verifies", vec!["G.R.1".to_string()]))
+    );
+    assert_eq!(parse_relation_line("- Directories are created"), None);
+    assert_eq!(parse_relation_line("- implements: G.R.1"), None);
+    assert_eq!(parse_relation_line("depends_on: G.R.1"), None);
+    assert_eq!(parse_relation_line("- depends_on:G.R.1"), None);
+}
+
+/// Test: parse_relations skips fenced code blocks
+/// Precondition: System has a text with relation lines inside and outside a code block
+/// Action: Call parse_relations
+/// Result: Only relations outside the code block are returned, in text order
+/// Covers Requirement: G.RELA.2
+#[test]
+fn test_parse_relations_skips_code_blocks() {
+    let text = "Text.\n\n```\n- refines: G.X.1\n```\n\n- depends_on: G.C.2\n- refines: G.R.1\n";
+
+    assert_eq!(
+        parse_relations(text),
+        vec![
+            ("depends_on", "G.C.2".to_string()),
+            ("refines", "G.R.1".to_string())
+        ]
+    );
+}
+
+// =============================================================================
+// Tests for G.RELA.3: Relation validation
+// =============================================================================
+
+/// Test: insert rejects relations to missing requirements
+/// Precondition: System has requirements G.C.1, G.C.2 and G.R.1
+/// Action: Insert a requirement that depends on G.C.9
+/// Result: Error "Related requirement not found: G.C.9", category file unchanged
+/// Covers Requirement: G.RELA.3
+#[test]
+fn test_insert_relation_target_not_found() {
+    let temp_dir = TempDir::new().unwrap();
+    let req_dir = setup_project(&temp_dir);
+    let before = fs::read_to_string(req_dir.join("general.md")).unwrap();
+
+    let json = insert(&temp_dir, "Text.", Some(relations(&["G.C.9"], &[])));
+
+    assert!(!json["success"].as_bool().unwrap());
+    assert_eq!(json["error"], "Related requirement not found: G.C.9");
+    assert_eq!(
+        fs::read_to_string(req_dir.join("general.md")).unwrap(),
+        before
+    );
+}
+
+/// Test: relation lines written directly in text are validated too
+/// Precondition: System has requirements G.C.1, G.C.2 and G.R.1
+/// Action: Insert a requirement whose text contains "- verifies: G.T.1" without relations parameter
+/// Result: Error "Related requirement not found: G.T.1"
+/// Covers Requirement: G.RELA.3, G.RELA.4
+#[test]
+fn test_insert_relation_lines_in_text_validated() {
+    let temp_dir = TempDir::new().unwrap();
+    setup_project(&temp_dir);
+
+    let json = insert(&temp_dir, "Text.\n\n- verifies: G.T.1", None);
+
+    assert_eq!(json["error"], "Related requirement not found: G.T.1");
+}
+
+/// Test: update rejects a relation to the requirement itself
+/// Precondition: System has requirement G.C.2
+/// Action: Update G.C.2 with depends_on [G.C.2]
+/// Result: Error "Requirement cannot be related to itself: G.C.2", requirement unchanged
+/// Covers Requirement: G.RELA.3
+#[test]
+fn test_update_self_relation() {
+    let temp_dir = TempDir::new().unwrap();
+    let req_dir = setup_project(&temp_dir);
+    let before = fs::read_to_string(req_dir.join("general.md")).unwrap();
+
+    let json = update(&temp_dir, "G.C.2", Some(relations(&["G.C.2"], &[])));
+
+    assert_eq!(
+        json["error"],
+        "Requirement cannot be related to itself: G.C.2"
+    );
+    assert_eq!(
+        fs::read_to_string(req_dir.join("general.md")).unwrap(),
+        before
+    );
+}
+
+/// Test: update keeps existing relations to deleted requirements
+/// Precondition: System has G.R.1 with "- depends_on: G.C.9" where G.C.9 does not exist
+/// Action: Update G.R.1 text keeping the relation, then with a new relation to G.C.8
+/// Result: The first update succeeds, the second fails for the added target only
+/// Covers Requirement: G.RELA.3
+#[test]
+fn test_update_keeps_existing_broken_relation() {
+    let temp_dir = TempDir::new().unwrap();
+    let req_dir = setup_project(&temp_dir);
+    let content = fs::read_to_string(req_dir.join("general.md")).unwrap();
+    fs::write(
+        req_dir.join("general.md"),
+        content.replace(
+            "## G.R.1: Format\n\nText.\n",
+            "## G.R.1: Format\n\nText.\n\n- depends_on: G.C.9\n",
+        ),
+    )
+    .unwrap();
+
+    let kept = update(&temp_dir, "G.R.1", Some(relations(&["G.C.9"], &[])));
+    let added = update(
+        &temp_dir,
+        "G.R.1",
+        Some(relations(&["G.C.9", "G.C.8"], &[])),
+    );
+    let removed = update(&temp_dir, "G.R.1", Some(relations(&[], &[])));
+
+    assert_eq!(kept["success"], true, "{}", kept);
+    assert_eq!(added["error"], "Related requirement not found: G.C.8");
+    assert_eq!(removed["data"]["text"], "Updated.");
+}
+
+// =============================================================================
+// Tests for G.RELA.4: Relations parameter
+// =============================================================================
+
+/// Test: apply_relations replaces relation lines in type order without duplicates
+/// Precondition: System has a text with an old relation line and relations with duplicate targets
+/// Action: Call apply_relations
+/// Result: Old line is removed, new lines follow a blank line in G.RELA.1 order
+/// Covers Requirement: G.RELA.1, G.RELA.4
+#[test]
+fn test_apply_relations_order_and_duplicates() {
+    let text = "Text.\n\n- refines: G.X.1\n";
+    let given = RelationsParam {
+        derived_from: Some(vec!["G.A.1".to_string()]),
+        depends_on: Some(vec![
+            "G.C.2".to_string(),
+            "G.C.3".to_string(),
+            "G.C.2".to_string(),
+        ]),
+        verifies: Some(vec![]),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        apply_relations(text, &given),
+        "Text.\n\n- depends_on: G.C.2, G.C.3\n- derived_from: G.A.1"
+    );
+}
+
+/// Test: empty relations object removes all relations, code blocks are kept
+/// Precondition: System has a text with a relation line and a code block containing a relation-like line
+/// Action: Call apply_relations with an empty relations object
+/// Result: Relation line is removed, code block is unchanged
+/// Covers Requirement: G.RELA.4
+#[test]
+fn test_apply_relations_empty_removes() {
+    let text = "Text.\n\n```\n- refines: G.X.1\n```\n\n- depends_on: G.C.2";
+
+    assert_eq!(
+        apply_relations(text, &RelationsParam::default()),
+        "Text.\n\n```\n- refines: G.X.1\n```"
+    );
+}
+
+/// Test: insert and update store relations as metadata lines
+/// Precondition: System has requirements G.C.1, G.C.2 and G.R.1
+/// Action: Insert a requirement with relations, then update it with other relations in a batch item
+/// Result: Returned and stored texts end with the relation lines
+/// Covers Requirement: G.RELA.2, G.RELA.4
+#[test]
+fn test_insert_and_update_store_relations() {
+    let temp_dir = TempDir::new().unwrap();
+    let req_dir = setup_project(&temp_dir);
+
+    let json = insert(
+        &temp_dir,
+        "Text.",
+        Some(relations(&["G.C.2", "G.C.1"], &["G.R.1"])),
+    );
+    assert!(json["success"].as_bool().unwrap());
+    assert_eq!(
+        json["data"]["text"],
+        "Text.\n\n- depends_on: G.C.2, G.C.1\n- verifies: G.R.1"
+    );
+    let index = json["data"]["index"].as_str().unwrap().to_string();
+
+    let params = UpdateRequirementParams {
+        project_root: temp_dir.path().to_string_lossy().to_string(),
+        operation_description: "test".to_string(),
+        index: None,
+        text: None,
+        title: None,
+        items: Some(vec![UpdateItem {
+            index: index.clone(),
+            text: json["data"]["text"].as_str().unwrap().to_string(),
+            title: None,
+            relations: Some(relations(&[], &["G.C.2"])),
+        }]),
+        relations: None,
+        rewrite_links: None,
+    };
+    let json = parse_response(&RequirementsServer::handle_update_requirement(params));
+    assert_eq!(
+        json["data"][0]["data"]["text"],
+        "Text.\n\n- verifies: G.C.2"
+    );
+
+    let content = fs::read_to_string(req_dir.join("general.md")).unwrap();
+    assert!(content.contains(&format!(
+        "## {}: New\n\nText.\n\n- verifies: G.C.2\n",
+        index
+    )));
+}
+
+/// Test: validate_relations enforces array size and index constraints
+/// Precondition: System has relations with 101 targets and with an empty index
+/// Action: Call validate_relations
+/// Result: Errors name the relation type or the index constraint
+/// Covers Requirement: G.RELA.4
+#[test]
+fn test_validate_relations_constraints() {
+    assert!(RequirementsServer::validate_relations(&relations(&["G.C.1"], &[])).is_ok());
+
+    let many: Vec<String> = (0..101).map(|i| format!("G.C.{}", i)).collect();
+    let too_many = RelationsParam {
+        refines: Some(many),
+        ..Default::default()
+    };
+    assert_eq!(
+        RequirementsServer::validate_relations(&too_many).unwrap_err(),
+        "relations.refines exceeds maximum of 100 indices"
+    );
+
+    assert!(RequirementsServer::validate_relations(&relations(&[""], &[])).is_err());
+}
+
+/// Test: unknown relation fields are rejected
+/// Precondition: System has relations JSON with field "implements"
+/// Action: Deserialize RelationsParam
+/// Result: Deserialization fails
+/// Covers Requirement: G.RELA.4
+#[test]
+fn test_relations_param_rejects_unknown_fields() {
+    let result: Result<RelationsParam, _> =
+        serde_json::from_value(serde_json::json!({"implements": ["G.C.1"]}));
+
+    assert!(result.is_err());
+}
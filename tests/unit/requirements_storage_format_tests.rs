@@ -619,6 +619,8 @@ Content.
         text: Some("New content without trailing newline".to_string()),
         title: None,
         items: None,
+        relations: None,
+        rewrite_links: None,
     };
     let result = RequirementsServer::handle_update_requirement(params);
     let parsed: serde_json::Value = serde_json::from_str(&result).unwrap();
@@ -666,6 +668,8 @@ Content.
         text: Some("Updated content".to_string()),
         title: None,
         items: None,
+        relations: None,
+        rewrite_links: None,
     };
     let result = RequirementsServer::handle_update_requirement(params);
     let parsed: serde_json::Value = serde_json::from_str(&result).unwrap();
@@ -711,6 +715,7 @@ Content three.
         project_root: temp_dir.path().to_string_lossy().to_string(),
         operation_description: "Test delete".to_string(),
         index: reqlix::IndexParam::Single("G.T.2".to_string()),
+        force: None,
     };
     let result = RequirementsServer::handle_delete_requirement(params);
     let parsed: serde_json::Value = serde_json::from_str(&result).unwrap();
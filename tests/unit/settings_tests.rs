@@ -0,0 +1,541 @@
+// Tests for Settings (G.SE.*)
+// Covers Requirements: G.SE.1, G.SE.2, G.SE.3, G.SE.4, G.SE.5, G.SE.6
+
+use reqlix::{
+    load_project_config, parse_config, GetCategoriesParams, GetInstructionsParams,
+    GetRequirementParams, IndexMapping, IndexParam, InsertRequirementParams, RelationsParam,
+    RequirementsServer, RewriteReferencesParams, TraceParams,
+};
+use std::fs;
+use tempfile::TempDir;
+
+use super::common::{
+    connect_with, create_agents_file_in_req_dir, create_category_file_in_req_dir,
+    create_requirements_dir, parse_response,
+};
+
+/// Create a project with reqlix.toml and a requirements directory with one category
+fn create_project(settings: &str) -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("reqlix.toml"), settings).unwrap();
+    let req_dir = create_requirements_dir(&temp_dir);
+    create_agents_file_in_req_dir(&req_dir, "# Instructions\n");
+    create_category_file_in_req_dir(
+        &req_dir,
+        "general",
+        "# Rules\n\n## G.R.1: First\n\nOne.\n\n## G.R.2: Second\n\nTwo.\n",
+    );
+    temp_dir
+}
+
+/// Project root of a temp project
+fn root(temp_dir: &TempDir) -> String {
+    temp_dir.path().to_string_lossy().to_string()
+}
+
+/// Call reqlix_insert_requirement
+fn insert(temp_dir: &TempDir, category: &str, chapter: &str, text: &str) -> serde_json::Value {
+    parse_response(&RequirementsServer::handle_insert_requirement(
+        InsertRequirementParams {
+            project_root: root(temp_dir),
+            operation_description: "Adding a requirement".to_string(),
+            category: category.to_string(),
+            chapter: chapter.to_string(),
+            text: text.to_string(),
+            title: "New requirement".to_string(),
+            warn_similar_chapter: None,
+            relations: None,
+        },
+    ))
+}
+
+/// Call reqlix_get_requirement
+fn get_requirement(temp_dir: &TempDir, index: IndexParam) -> serde_json::Value {
+    parse_response(&RequirementsServer::handle_get_requirement(
+        GetRequirementParams {
+            project_root: root(temp_dir),
+            operation_description: "Reading a requirement".to_string(),
+            index,
+        },
+    ))
+}
+
+// =============================================================================
+// Tests for G.SE.1: Configuration file
+// =============================================================================
+
+/// Test: an empty configuration keeps the built-in behavior
+/// Precondition: System has an empty reqlix.toml
+/// Action: Call parse_config
+/// Result: Built-in limits, no paths, patterns or prefixes
+/// Covers Requirement: G.SE.1
+#[test]
+fn test_parse_config_defaults() {
+    let config = parse_config("").unwrap();
+
+    assert_eq!(config.requirements.path, None);
+    assert!(config.requirements.search_paths.is_empty());
+    assert_eq!(config.limits.max_text_len, 10000);
+    assert_eq!(config.limits.max_title_len, 100);
+    assert_eq!(config.limits.max_batch_size, 100);
+    assert_eq!(config.naming.category_pattern, None);
+    assert!(config.index.category_prefixes.is_empty());
+}
+
+/// Test: reqlix.toml is found from a subdirectory of the project
+/// Precondition: System has a project with reqlix.toml and a nested directory
+/// Action: Call load_project_config for the nested directory and for a project without reqlix.toml
+/// Result: The configuration of the project with its directory; defaults without a file
+/// Covers Requirement: G.SE.1
+#[test]
+fn test_load_project_config_from_subdirectory() {
+    let temp_dir = create_project("[limits]\nmax_title_len = 80\n");
+    let nested = temp_dir.path().join("crates/core");
+    fs::create_dir_all(&nested).unwrap();
+    let other = TempDir::new().unwrap();
+    fs::create_dir(other.path().join(".git")).unwrap();
+
+    let config = load_project_config(&nested.to_string_lossy()).unwrap();
+    let default = load_project_config(&root(&other)).unwrap();
+
+    assert_eq!(config.limits.max_title_len, 80);
+    assert_eq!(config.dir.as_deref(), Some(temp_dir.path()));
+    assert_eq!(default.limits.max_title_len, 100);
+    assert_eq!(default.dir, None);
+}
+
+// =============================================================================
+// Tests for G.SE.2: Configuration errors
+// =============================================================================
+
+/// Test: syntax and type errors state the position
+/// Precondition: System has reqlix.toml content with a wrong type and with broken syntax
+/// Action: Call parse_config
+/// Result: Errors start with the line and column of the problem
+/// Covers Requirement: G.SE.2
+#[test]
+fn test_parse_config_error_position() {
+    let wrong_type = parse_config("[limits]\nmax_text_len = \"long\"\n").unwrap_err();
+    let broken = parse_config("[requirements\npath = \"spec\"\n").unwrap_err();
+
+    assert!(wrong_type.starts_with("line 2, column "), "{}", wrong_type);
+    assert!(broken.starts_with("line 1, column "), "{}", broken);
+}
+
+/// Test: unknown keys are rejected
+/// Precondition: System has reqlix.toml content with a misspelled key
+/// Action: Call parse_config
+/// Result: Error with the position that names the unknown key
+/// Covers Requirement: G.SE.2
+#[test]
+fn test_parse_config_unknown_key() {
+    let error = parse_config("[limits]\nmax_length = 5\n").unwrap_err();
+
+    assert!(error.starts_with("line 2, column 1"), "{}", error);
+    assert!(error.contains("unknown field `max_length`"), "{}", error);
+}
+
+/// Test: an invalid reqlix.toml fails tool calls with the file and the key
+/// Precondition: System has a project whose reqlix.toml sets max_batch_size to 0
+/// Action: Call reqlix_get_categories
+/// Result: Error naming reqlix.toml and limits.max_batch_size
+/// Covers Requirement: G.SE.2
+#[test]
+fn test_invalid_config_fails_tool_call() {
+    let temp_dir = create_project("[limits]\nmax_batch_size = 0\n");
+
+    let response = parse_response(&RequirementsServer::handle_get_categories(
+        GetCategoriesParams {
+            project_root: root(&temp_dir),
+            operation_description: "Listing categories".to_string(),
+        },
+    ));
+
+    assert_eq!(response["success"], false);
+    let error = response["error"].as_str().unwrap();
+    assert!(error.starts_with("Invalid "), "{}", error);
+    assert!(
+        error.ends_with("reqlix.toml: limits.max_batch_size must be between 1 and 100"),
+        "{}",
+        error
+    );
+}
+
+// =============================================================================
+// Tests for G.SE.3: Requirements paths
+// =============================================================================
+
+/// Test: requirements paths must stay inside the project
+/// Precondition: System has reqlix.toml content with parent and absolute paths
+/// Action: Call parse_config
+/// Result: Errors naming requirements.path and requirements.search_paths
+/// Covers Requirement: G.SE.3
+#[test]
+fn test_parse_config_rejects_outside_paths() {
+    assert_eq!(
+        parse_config("[requirements]\npath = \"../spec\"\n").unwrap_err(),
+        "requirements.path must be a relative path inside the project: ../spec"
+    );
+    assert_eq!(
+        parse_config("[requirements]\nsearch_paths = [\"/etc\"]\n").unwrap_err(),
+        "requirements.search_paths must be a relative path inside the project: /etc"
+    );
+}
+
+/// Test: requirements.path is used to create the requirements file
+/// Precondition: System has a project with reqlix.toml setting requirements.path and no requirements file
+/// Action: Call reqlix_get_instructions
+/// Result: AGENTS.md is created in the configured directory
+/// Covers Requirement: G.SE.3
+#[test]
+fn test_requirements_path_used_for_creation() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join("reqlix.toml"),
+        "[requirements]\npath = \"spec/requirements\"\n",
+    )
+    .unwrap();
+
+    let response = parse_response(&RequirementsServer::handle_get_instructions(
+        GetInstructionsParams {
+            project_root: root(&temp_dir),
+            operation_description: "Reading instructions".to_string(),
+            nested: None,
+        },
+    ));
+
+    assert_eq!(response["success"], true);
+    assert!(temp_dir
+        .path()
+        .join("spec/requirements/AGENTS.md")
+        .is_file());
+    assert!(!temp_dir.path().join("docs").exists());
+}
+
+/// Test: requirements.search_paths are searched before the built-in paths
+/// Precondition: System has requirements in a configured search path and in the built-in path
+/// Action: Call reqlix_get_categories
+/// Result: Categories of the configured search path
+/// Covers Requirement: G.SE.3
+#[test]
+fn test_search_paths_searched_first() {
+    let temp_dir = create_project("[requirements]\nsearch_paths = [\"spec\"]\n");
+    let spec_dir = temp_dir.path().join("spec");
+    fs::create_dir(&spec_dir).unwrap();
+    create_agents_file_in_req_dir(&spec_dir, "# Instructions\n");
+    create_category_file_in_req_dir(&spec_dir, "product", "# Goals\n");
+
+    let response = parse_response(&RequirementsServer::handle_get_categories(
+        GetCategoriesParams {
+            project_root: root(&temp_dir),
+            operation_description: "Listing categories".to_string(),
+        },
+    ));
+
+    assert_eq!(
+        response["data"]["categories"],
+        serde_json::json!(["product"])
+    );
+}
+
+// =============================================================================
+// Tests for G.SE.4: Limits
+// =============================================================================
+
+/// Test: limits must be between 1 and the built-in limits
+/// Precondition: System has reqlix.toml content with limits above and below the allowed range
+/// Action: Call parse_config
+/// Result: Errors naming the key and the allowed range
+/// Covers Requirement: G.SE.4
+#[test]
+fn test_parse_config_limit_range() {
+    assert_eq!(
+        parse_config("[limits]\nmax_text_len = 20000\n").unwrap_err(),
+        "limits.max_text_len must be between 1 and 10000"
+    );
+    assert_eq!(
+        parse_config("[limits]\nmax_title_len = 0\n").unwrap_err(),
+        "limits.max_title_len must be between 1 and 100"
+    );
+}
+
+/// Test: configured limits apply to text and batches
+/// Precondition: System has a project with max_text_len 10 and max_batch_size 2
+/// Action: Insert a longer text and request three indices
+/// Result: Errors stating the configured limits
+/// Covers Requirement: G.SE.4
+#[test]
+fn test_configured_limits_applied() {
+    let temp_dir = create_project("[limits]\nmax_text_len = 10\nmax_batch_size = 2\n");
+
+    let inserted = insert(&temp_dir, "general", "Rules", "Longer than ten.");
+    let batch = get_requirement(
+        &temp_dir,
+        IndexParam::Batch(vec!["G.R.1".into(), "G.R.2".into(), "G.R.3".into()]),
+    );
+
+    assert_eq!(
+        inserted["error"],
+        "text exceeds maximum length of 10 characters"
+    );
+    assert_eq!(
+        batch["error"],
+        "Batch request exceeds maximum limit of 2 indices"
+    );
+}
+
+/// Test: the configured batch size also limits patterns, relations and mappings
+/// Precondition: System has a project with max_batch_size 2
+/// Action: Trace with three include patterns, insert with three refined indices and
+///         rewrite three mappings
+/// Result: Errors stating the configured limit
+/// Covers Requirement: G.SE.4
+#[test]
+fn test_configured_batch_size_applied_to_lists() {
+    let temp_dir = create_project("[limits]\nmax_batch_size = 2\n");
+    let three = |prefix: &str| {
+        (1..=3)
+            .map(|n| format!("{}{}", prefix, n))
+            .collect::<Vec<_>>()
+    };
+
+    let traced = parse_response(&RequirementsServer::handle_trace(TraceParams {
+        project_root: root(&temp_dir),
+        operation_description: "Tracing requirements".to_string(),
+        include: Some(three("src/*.rs")),
+        exclude: None,
+    }));
+    let inserted = parse_response(&RequirementsServer::handle_insert_requirement(
+        InsertRequirementParams {
+            project_root: root(&temp_dir),
+            operation_description: "Adding a requirement".to_string(),
+            category: "general".to_string(),
+            chapter: "Rules".to_string(),
+            text: "Text.".to_string(),
+            title: "New requirement".to_string(),
+            warn_similar_chapter: None,
+            relations: Some(RelationsParam {
+                refines: Some(three("G.R.")),
+                ..Default::default()
+            }),
+        },
+    ));
+    let rewritten = parse_response(&RequirementsServer::handle_rewrite_references(
+        RewriteReferencesParams {
+            project_root: root(&temp_dir),
+            operation_description: "Renumbering requirements".to_string(),
+            mappings: three("G.R.")
+                .into_iter()
+                .map(|from| IndexMapping {
+                    to: from.replace("G.R.", "G.S."),
+                    from,
+                })
+                .collect(),
+            dry_run: Some(true),
+            include: None,
+            exclude: None,
+        },
+    ));
+
+    assert_eq!(traced["error"], "include exceeds maximum of 2 patterns");
+    assert_eq!(
+        inserted["error"],
+        "relations.refines exceeds maximum of 2 indices"
+    );
+    assert_eq!(rewritten["error"], "mappings exceeds maximum of 2 items");
+}
+
+// =============================================================================
+// Tests for G.SE.5: Naming rules
+// =============================================================================
+
+/// Test: naming patterns must be valid regular expressions
+/// Precondition: System has reqlix.toml content with an unclosed character class
+/// Action: Call parse_config
+/// Result: Error naming naming.category_pattern
+/// Covers Requirement: G.SE.5
+#[test]
+fn test_parse_config_invalid_pattern() {
+    let error = parse_config("[naming]\ncategory_pattern = \"[a-\"\n").unwrap_err();
+
+    assert!(
+        error.starts_with("naming.category_pattern is not a valid regular expression"),
+        "{}",
+        error
+    );
+}
+
+/// Test: configured patterns replace the built-in character sets
+/// Precondition: System has a project whose patterns allow digits
+/// Action: Insert into category "http2" and chapter "Phase 3", and into a category violating the pattern
+/// Result: The first insert succeeds, the second fails with the configured pattern
+/// Covers Requirement: G.SE.5
+#[test]
+fn test_configured_patterns_applied() {
+    let temp_dir = create_project(
+        "[naming]\ncategory_pattern = \"^[a-z][a-z0-9_]*$\"\nchapter_pattern = \"^[A-Za-z0-9 ]+$\"\n",
+    );
+
+    let accepted = insert(&temp_dir, "http2", "Phase 3", "Text.");
+    let rejected = insert(&temp_dir, "2http", "Phase 3", "Text.");
+
+    assert_eq!(accepted["success"], true);
+    assert_eq!(
+        rejected["error"],
+        "category name must match naming.category_pattern: ^[a-z][a-z0-9_]*$"
+    );
+}
+
+/// Test: a configured pattern allowing names without letters fails when a prefix is needed
+/// Precondition: System has a project whose chapter pattern allows digits only
+/// Action: Insert into chapter "2024"
+/// Result: Error that no prefix can be calculated
+/// Covers Requirement: G.SE.5, G.R.4
+#[test]
+fn test_pattern_name_without_letters() {
+    let temp_dir = create_project("[naming]\nchapter_pattern = \"^[0-9]+$\"\n");
+
+    assert_eq!(
+        insert(&temp_dir, "general", "2024", "Text.")["error"],
+        "Cannot calculate a prefix for chapter '2024': the name contains no English letter (A-Z, a-z)"
+    );
+}
+
+/// Test: extra characters extend the built-in character sets
+/// Precondition: System has a project allowing dots and parentheses in chapter names
+/// Action: Insert into chapter "Release 1.2 (draft)" and into chapter "Release [1]"
+/// Result: The first insert succeeds with a prefix of letters only, the second names the extra characters
+/// Covers Requirement: G.SE.5
+#[test]
+fn test_extra_characters_applied() {
+    let temp_dir = create_project("[naming]\nextra_chapter_characters = \".()\"\n");
+
+    let accepted = insert(&temp_dir, "general", "Release 1.2 (draft)", "Text.");
+    let rejected = insert(&temp_dir, "general", "Release [1]", "Text.");
+
+    assert_eq!(accepted["data"]["index"], "G.RE.1");
+    assert_eq!(
+        rejected["error"],
+        "chapter name must contain only uppercase and lowercase English letters (A-Z, a-z), digits (0-9), \
+         spaces, colons (:), hyphens (-), and underscores (_) or characters of naming.extra_chapter_characters (.())"
+    );
+}
+
+/// Test: invalid extra characters name the key
+/// Precondition: System has reqlix.toml content combining extras with a pattern or using invalid characters
+/// Action: Call parse_config
+/// Result: Errors naming the extra characters key
+/// Covers Requirement: G.SE.5
+#[test]
+fn test_parse_config_invalid_extra_characters() {
+    assert_eq!(
+        parse_config(
+            "[naming]\ncategory_pattern = \"^[a-z]+$\"\nextra_category_characters = \".\"\n"
+        )
+        .unwrap_err(),
+        "naming.extra_category_characters cannot be combined with naming.category_pattern"
+    );
+    assert_eq!(
+        parse_config("[naming]\nextra_category_characters = \"./\"\n").unwrap_err(),
+        "naming.extra_category_characters contains an invalid character: '/'"
+    );
+    assert_eq!(
+        parse_config("[naming]\nextra_chapter_characters = \"#\"\n").unwrap_err(),
+        "naming.extra_chapter_characters contains an invalid character: '#'"
+    );
+}
+
+// =============================================================================
+// Tests for G.SE.6: Index prefixes
+// =============================================================================
+
+/// Test: fixed prefixes must be uppercase letters and digits and unique
+/// Precondition: System has reqlix.toml content with a lowercase prefix, a leading digit and a shared prefix
+/// Action: Call parse_config
+/// Result: Errors naming the offending category key
+/// Covers Requirement: G.SE.6
+#[test]
+fn test_parse_config_invalid_prefixes() {
+    let expected = "must start with an uppercase English letter (A-Z) followed by uppercase letters or digits (0-9)";
+    assert_eq!(
+        parse_config("[index]\ncategory_prefixes = { general = \"g\" }\n").unwrap_err(),
+        format!("index.category_prefixes.general {}", expected)
+    );
+    assert_eq!(
+        parse_config("[index]\ncategory_prefixes = { general = \"2G\" }\n").unwrap_err(),
+        format!("index.category_prefixes.general {}", expected)
+    );
+    assert_eq!(
+        parse_config("[index]\ncategory_prefixes = { api = \"A\", auth = \"A\" }\n").unwrap_err(),
+        "index.category_prefixes.auth uses the prefix A of index.category_prefixes.api"
+    );
+}
+
+/// Test: a fixed prefix is used for new categories and for lookup
+/// Precondition: System has a project with the fixed prefix API for category http_api
+/// Action: Insert a requirement into http_api and read it by its index
+/// Result: The index starts with API and the requirement is found
+/// Covers Requirement: G.SE.6
+#[test]
+fn test_fixed_prefix_used_for_insert_and_lookup() {
+    let temp_dir = create_project("[index]\ncategory_prefixes = { http_api = \"API\" }\n");
+
+    let inserted = insert(&temp_dir, "http_api", "Endpoints", "Text.");
+    let found = get_requirement(&temp_dir, IndexParam::Single("API.E.1".to_string()));
+
+    assert_eq!(inserted["data"]["index"], "API.E.1");
+    assert_eq!(found["data"]["category"], "http_api");
+    assert_eq!(found["data"]["title"], "New requirement");
+}
+
+/// Test: a fixed prefix may contain digits after the first letter
+/// Precondition: System has a project with the fixed prefix API2 for category api_v2
+/// Action: Insert a requirement into api_v2 and read it by its index
+/// Result: The index starts with API2 and the requirement is found
+/// Covers Requirement: G.SE.6
+#[test]
+fn test_fixed_prefix_with_digit() {
+    let temp_dir = create_project("[index]\ncategory_prefixes = { api_v2 = \"API2\" }\n");
+
+    let inserted = insert(&temp_dir, "api_v2", "Endpoints", "Text.");
+    let found = get_requirement(&temp_dir, IndexParam::Single("API2.E.1".to_string()));
+
+    assert_eq!(inserted["data"]["index"], "API2.E.1");
+    assert_eq!(found["data"]["category"], "api_v2");
+}
+
+/// Input schema properties of a tool listed by a server with the project as default project root
+async fn schema_properties(temp_dir: &TempDir, tool: &str) -> serde_json::Value {
+    let server = RequirementsServer::new().with_project_root(Some(root(temp_dir)));
+    let client = connect_with(server, ()).await;
+    let tools = client.list_all_tools().await.unwrap();
+    client.cancel().await.unwrap();
+    let tool = tools.into_iter().find(|t| t.name == tool).unwrap();
+    serde_json::Value::Object((*tool.input_schema).clone())["properties"].clone()
+}
+
+/// Test: schema name patterns follow the naming rules of the project
+/// Precondition: System has projects with extra characters and with configured patterns
+/// Action: List the tools with the project as default project root
+/// Result: Extra characters are added to the built-in patterns; configured patterns remove them
+/// Covers Requirement: G.SE.5, G.P.5
+#[tokio::test]
+async fn test_schema_patterns_follow_naming() {
+    let extras = create_project(
+        "[naming]\nextra_category_characters = \".-\"\nextra_chapter_characters = \"&\"\n",
+    );
+    let properties = schema_properties(&extras, "reqlix_insert_requirement").await;
+    let category = regex::Regex::new(properties["category"]["pattern"].as_str().unwrap()).unwrap();
+    let chapter = regex::Regex::new(properties["chapter"]["pattern"].as_str().unwrap()).unwrap();
+    assert!(category.is_match("api.v-2"));
+    assert!(!category.is_match("api/v2"));
+    assert!(chapter.is_match("Tools & Tests"));
+
+    let patterns = create_project(
+        "[naming]\ncategory_pattern = \"^[A-Z]+$\"\nchapter_pattern = \"^[0-9]+$\"\n",
+    );
+    let properties = schema_properties(&patterns, "reqlix_insert_requirement").await;
+    assert!(properties["category"].get("pattern").is_none());
+    assert!(properties["chapter"].get("pattern").is_none());
+    assert_eq!(properties["category"]["maxLength"], 100);
+}
@@ -0,0 +1,248 @@
+// Tests for Structured Tool Results (G.S.*)
+// Covers Requirements: G.S.1, G.S.2, G.S.3
+
+use rmcp::model::{CallToolRequestParam, CallToolResult, Tool};
+use rmcp::service::{RoleClient, RunningService};
+use tempfile::TempDir;
+
+use super::common::{
+    connect_client, create_agents_file_in_req_dir, create_category_file_in_req_dir,
+    create_requirements_dir, parse_response,
+};
+
+/// Call a tool with JSON arguments
+async fn call(
+    client: &RunningService<RoleClient, ()>,
+    name: &str,
+    arguments: serde_json::Value,
+) -> CallToolResult {
+    client
+        .call_tool(CallToolRequestParam {
+            name: name.to_string().into(),
+            arguments: arguments.as_object().cloned(),
+        })
+        .await
+        .unwrap()
+}
+
+/// Find a tool of tools/list by name
+fn tool<'a>(tools: &'a [Tool], name: &str) -> &'a Tool {
+    tools.iter().find(|t| t.name == name).unwrap()
+}
+
+/// Create a project with category general
+fn create_project() -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    let req_dir = create_requirements_dir(&temp_dir);
+    create_agents_file_in_req_dir(&req_dir, "# Instructions\n");
+    create_category_file_in_req_dir(&req_dir, "general", "# Rules\n\n## G.R.1: One\n\nText.\n");
+    temp_dir
+}
+
+// =============================================================================
+// Tests for G.S.1: Structured content
+// =============================================================================
+
+/// Test: a successful call returns text and matching structured content
+/// Precondition: System has a project with category general
+/// Action: Call reqlix_get_categories
+/// Result: isError is false and structuredContent equals the parsed text
+/// Covers Requirement: G.S.1
+#[tokio::test]
+async fn test_success_has_structured_content() {
+    let temp_dir = create_project();
+    let client = connect_client().await;
+
+    let result = call(
+        &client,
+        "reqlix_get_categories",
+        serde_json::json!({
+            "project_root": temp_dir.path().to_string_lossy(),
+            "operation_description": "Listing categories",
+        }),
+    )
+    .await;
+    let text = parse_response(&result.content[0].as_text().unwrap().text);
+
+    assert_eq!(result.is_error, Some(false));
+    assert_eq!(result.structured_content, Some(text));
+    assert_eq!(
+        result.structured_content.unwrap()["data"]["categories"],
+        serde_json::json!(["general"])
+    );
+    client.cancel().await.unwrap();
+}
+
+/// Test: a failed call is a tool error with structured content
+/// Precondition: System has a project with category general
+/// Action: Call reqlix_get_chapters for a missing category
+/// Result: isError is true and structuredContent has success false and the error
+/// Covers Requirement: G.S.1
+#[tokio::test]
+async fn test_error_sets_is_error() {
+    let temp_dir = create_project();
+    let client = connect_client().await;
+
+    let result = call(
+        &client,
+        "reqlix_get_chapters",
+        serde_json::json!({
+            "project_root": temp_dir.path().to_string_lossy(),
+            "operation_description": "Listing chapters",
+            "category": "missing",
+        }),
+    )
+    .await;
+    let structured = result.structured_content.unwrap();
+
+    assert_eq!(result.is_error, Some(true));
+    assert_eq!(structured["success"], false);
+    assert_eq!(structured["error"], "Category not found");
+    client.cancel().await.unwrap();
+}
+
+/// Test: a batch with failed items is not a tool error
+/// Precondition: System has a project with requirement G.R.1
+/// Action: Call reqlix_get_requirement with ["G.R.1", "G.R.9"]
+/// Result: isError is false; the items report success and failure
+/// Covers Requirement: G.S.1
+#[tokio::test]
+async fn test_batch_with_failed_items() {
+    let temp_dir = create_project();
+    let client = connect_client().await;
+
+    let result = call(
+        &client,
+        "reqlix_get_requirement",
+        serde_json::json!({
+            "project_root": temp_dir.path().to_string_lossy(),
+            "operation_description": "Reading requirements",
+            "index": ["G.R.1", "G.R.9"],
+        }),
+    )
+    .await;
+    let structured = result.structured_content.unwrap();
+
+    assert_eq!(result.is_error, Some(false));
+    assert_eq!(structured["data"][0]["success"], true);
+    assert_eq!(structured["data"][1]["success"], false);
+    client.cancel().await.unwrap();
+}
+
+// =============================================================================
+// Tests for G.S.2: Output schemas
+// =============================================================================
+
+/// Test: every tool has an output schema of the response object
+/// Precondition: System has a connected client
+/// Action: List tools
+/// Result: Each output schema is an object schema requiring success, with data and error properties
+/// Covers Requirement: G.S.2
+#[tokio::test]
+async fn test_output_schemas() {
+    let client = connect_client().await;
+
+    let tools = client.list_all_tools().await.unwrap();
+
+    for tool in &tools {
+        let schema = tool.output_schema.as_ref().unwrap();
+        assert_eq!(schema["type"], "object", "{}", tool.name);
+        assert_eq!(
+            schema["required"],
+            serde_json::json!(["success"]),
+            "{}",
+            tool.name
+        );
+        for property in ["success", "data", "error"] {
+            assert!(
+                schema["properties"].get(property).is_some(),
+                "{}",
+                tool.name
+            );
+        }
+    }
+    client.cancel().await.unwrap();
+}
+
+/// Test: the output schema describes the data of the tool
+/// Precondition: System has a connected client
+/// Action: List tools and read the schema of reqlix_get_version and reqlix_export_graph
+/// Result: The data definitions list the response fields
+/// Covers Requirement: G.S.2
+#[tokio::test]
+async fn test_output_schema_lists_data_fields() {
+    let client = connect_client().await;
+
+    let tools = client.list_all_tools().await.unwrap();
+    let version = serde_json::to_string(&tool(&tools, "reqlix_get_version").output_schema).unwrap();
+    let graph = serde_json::to_string(&tool(&tools, "reqlix_export_graph").output_schema).unwrap();
+
+    assert!(version.contains("\"version\""));
+    for field in ["\"format\"", "\"nodes\"", "\"edges\"", "\"rendered\""] {
+        assert!(graph.contains(field), "{}", field);
+    }
+    client.cancel().await.unwrap();
+}
+
+// =============================================================================
+// Tests for G.S.3: Tool annotations
+// =============================================================================
+
+/// Test: tools carry read-only, destructive, idempotent and open-world hints
+/// Precondition: System has a connected client
+/// Action: List tools
+/// Result: Hints match the table of G.S.3
+/// Covers Requirement: G.S.3
+#[tokio::test]
+async fn test_tool_annotations() {
+    let client = connect_client().await;
+
+    let tools = client.list_all_tools().await.unwrap();
+    let hints = |name: &str| {
+        let annotations = tool(&tools, name).annotations.clone().unwrap();
+        (
+            annotations.read_only_hint,
+            annotations.destructive_hint,
+            annotations.idempotent_hint,
+        )
+    };
+
+    assert_eq!(
+        hints("reqlix_get_instructions"),
+        (Some(false), Some(false), Some(true))
+    );
+    assert_eq!(
+        hints("reqlix_insert_requirement"),
+        (Some(false), Some(false), Some(false))
+    );
+    for name in [
+        "reqlix_update_requirement",
+        "reqlix_delete_requirement",
+        "reqlix_rewrite_references",
+        "reqlix_import_test_results",
+        "reqlix_export_matrix",
+    ] {
+        assert_eq!(
+            hints(name),
+            (Some(false), Some(true), Some(true)),
+            "{}",
+            name
+        );
+    }
+    for name in [
+        "reqlix_get_categories",
+        "reqlix_search_requirements",
+        "reqlix_trace",
+    ] {
+        assert_eq!(
+            hints(name),
+            (Some(true), Some(false), Some(true)),
+            "{}",
+            name
+        );
+    }
+    assert!(tools
+        .iter()
+        .all(|t| t.annotations.as_ref().unwrap().open_world_hint == Some(false)));
+    client.cancel().await.unwrap();
+}
@@ -0,0 +1,132 @@
+// Tests for Tool: reqlix_check_links (G.TOOLREQLIXCHECKL.*)
+// Covers Requirements: G.TOOLREQLIXCHECKL.3, G.TOOLREQLIXCHECKL.4, G.TOOLREQLIXCHECKL.5
+
+use reqlix::{CheckLinksParams, RequirementsServer};
+use serde_json::Value;
+use tempfile::TempDir;
+
+use super::common::{
+    create_agents_file_in_req_dir, create_category_file_in_req_dir, create_requirements_dir,
+    parse_response,
+};
+
+/// Run reqlix_check_links against a temp project
+fn check_links(temp_dir: &TempDir) -> Value {
+    let params = CheckLinksParams {
+        project_root: temp_dir.path().to_string_lossy().to_string(),
+        operation_description: "test".to_string(),
+    };
+    parse_response(&RequirementsServer::handle_check_links(params))
+}
+
+// =============================================================================
+// Tests for G.TOOLREQLIXCHECKL.3: Algorithm
+// =============================================================================
+
+/// Test: check_links reports missing anchors and files with suggestions
+/// Precondition: System has a stale same-file anchor for G.C.2, a stale cross-file anchor, a link to a missing file,
+///               valid links, a link in a code block and an external link
+/// Action: Call handle_check_links
+/// Result: Three broken links in file and line order; renamed requirements get a suggestion
+/// Covers Requirement: G.TOOLREQLIXCHECKL.3, G.TOOLREQLIXCHECKL.4
+#[test]
+fn test_check_links_reports_broken() {
+    let temp_dir = TempDir::new().unwrap();
+    let req_dir = create_requirements_dir(&temp_dir);
+    create_agents_file_in_req_dir(
+        &req_dir,
+        "# Instructions\n\nSee [general](general.md) and [missing](missing.md).\n",
+    );
+    create_category_file_in_req_dir(
+        &req_dir,
+        "general",
+        "# Configuration\n\n## G.C.1: Location\n\nSee [G.C.2](#gc2-old-title) and [chapter](#configuration).\n\n\
+```\n[x](#nowhere)\n```\n\n## G.C.2: Directory creation\n\nSee [docs](https://example.com/#x).\n",
+    );
+    create_category_file_in_req_dir(
+        &req_dir,
+        "testing",
+        "# Unit\n\n## T.U.1: Tests\n\nCovers [G.C.1](general.md#gc1-location), [G.C.9](general.md#gc9-gone).\n",
+    );
+
+    let json = check_links(&temp_dir);
+
+    assert!(json["success"].as_bool().unwrap());
+    let data = &json["data"];
+    assert_eq!(data["files_scanned"], 3);
+    assert_eq!(data["links_checked"], 6);
+    assert_eq!(
+        data["broken"],
+        serde_json::json!([
+            {
+                "file": "AGENTS.md",
+                "line": 3,
+                "destination": "missing.md",
+                "reason": "missing_file",
+                "suggestion": null
+            },
+            {
+                "file": "general.md",
+                "line": 5,
+                "destination": "#gc2-old-title",
+                "reason": "missing_anchor",
+                "suggestion": "#gc2-directory-creation"
+            },
+            {
+                "file": "testing.md",
+                "line": 5,
+                "destination": "general.md#gc9-gone",
+                "reason": "missing_anchor",
+                "suggestion": null
+            }
+        ])
+    );
+}
+
+/// Test: check_links accepts duplicate heading anchors with suffixes
+/// Precondition: System has two chapters "Notes" and links to #notes and #notes-1
+/// Action: Call handle_check_links
+/// Result: No broken links
+/// Covers Requirement: G.TOOLREQLIXCHECKL.3
+#[test]
+fn test_check_links_duplicate_headings() {
+    let temp_dir = TempDir::new().unwrap();
+    let req_dir = create_requirements_dir(&temp_dir);
+    create_agents_file_in_req_dir(&req_dir, "# Instructions\n");
+    create_category_file_in_req_dir(
+        &req_dir,
+        "general",
+        "# Notes\n\n## G.N.1: One\n\n[a](#notes) [b](#notes-1)\n\n# Notes\n",
+    );
+
+    let json = check_links(&temp_dir);
+
+    assert_eq!(json["data"]["links_checked"], 2);
+    assert!(json["data"]["broken"].as_array().unwrap().is_empty());
+}
+
+// =============================================================================
+// Tests for G.TOOLREQLIXCHECKL.5: Parameter validation
+// =============================================================================
+
+/// Test: check_links validates common parameters
+/// Precondition: System has no constraints violated except an empty operation_description
+/// Action: Call handle_check_links with empty operation_description
+/// Result: Error about operation_description
+/// Covers Requirement: G.TOOLREQLIXCHECKL.5
+#[test]
+fn test_check_links_validation() {
+    let temp_dir = TempDir::new().unwrap();
+    let params = CheckLinksParams {
+        project_root: temp_dir.path().to_string_lossy().to_string(),
+        operation_description: String::new(),
+    };
+
+    let json = parse_response(&RequirementsServer::handle_check_links(params));
+
+    assert!(!json["success"].as_bool().unwrap());
+    assert!(json["error"]
+        .as_str()
+        .unwrap()
+        .contains("operation_description"));
+}
@@ -0,0 +1,253 @@
+// Tests for Tool: reqlix_check_references (G.TOOLREQLIXCH.*)
+// Covers Requirements: G.TOOLREQLIXCH.2, G.TOOLREQLIXCH.3, G.TOOLREQLIXCH.4, G.TOOLREQLIXCH.5
+
+use reqlix::{find_dangling_references, CheckReferencesParams, RequirementsServer, TraceOptions};
+use serde_json::Value;
+use std::fs;
+use std::path::PathBuf;
+use tempfile::TempDir;
+
+use super::common::{
+    create_agents_file_in_req_dir, create_category_file_in_req_dir, create_requirements_dir,
+    git_commit_all, parse_response,
+};
+
+/// Create a project with one category holding the given content
+fn setup_project(temp_dir: &TempDir, general: &str) -> PathBuf {
+    let req_dir = create_requirements_dir(temp_dir);
+    create_agents_file_in_req_dir(&req_dir, "# Instructions\n");
+    create_category_file_in_req_dir(&req_dir, "general", general);
+    req_dir
+}
+
+/// Write src/lib.rs in the temp project
+fn write_source(temp_dir: &TempDir, content: &str) {
+    fs::create_dir_all(temp_dir.path().join("src")).unwrap();
+    fs::write(temp_dir.path().join("src/lib.rs"), content).unwrap();
+}
+
+/// Run reqlix_check_references against a temp project
+fn check_references(temp_dir: &TempDir, include: Option<Vec<&str>>) -> Value {
+    let params = CheckReferencesParams {
+        project_root: temp_dir.path().to_string_lossy().to_string(),
+        operation_description: "test".to_string(),
+        include: include.map(|v| v.into_iter().map(|s| s.to_string()).collect()),
+        exclude: None,
+    };
+    parse_response(&RequirementsServer::handle_check_references(params))
+}
+
+// =============================================================================
+// Tests for G.TOOLREQLIXCH.3: Algorithm
+// =============================================================================
+
+/// Test: references to existing requirements are not reported
+/// Precondition: System has G.S.1 and a source file citing only G.S.1
+/// Action: Call handle_check_references
+/// Result: Function returns success with empty references and files_scanned 1
+/// Covers Requirement: G.TOOLREQLIXCH.3, G.TOOLREQLIXCH.4
+#[test]
+fn test_check_references_valid_not_reported() {
+    let temp_dir = TempDir::new().unwrap();
+    setup_project(
+        &temp_dir,
+        "# Security\n\n## G.S.1: Login\n\nUsers log in.\n",
+    );
+    write_source(&temp_dir, "// G.S.1\n");
+
+    let json = check_references(&temp_dir, None);
+
+    assert!(json["success"].as_bool().unwrap());
+    assert_eq!(json["data"]["files_scanned"], 1);
+    assert!(json["data"]["references"].as_array().unwrap().is_empty());
+}
+
+/// Test: missing references resolve the category prefix and guess from the source line
+/// Precondition: System has no git history; source cites G.S.7 next to the title "Login" and G.S.8 alone
+/// Action: Call handle_check_references
+/// Result: Both are missing in category general; G.S.7 has replacement G.S.1, G.S.8 has none
+/// Covers Requirement: G.TOOLREQLIXCH.3, G.TOOLREQLIXCH.4
+#[test]
+fn test_check_references_missing_with_line_guess() {
+    let temp_dir = TempDir::new().unwrap();
+    setup_project(
+        &temp_dir,
+        "# Security\n\n## G.S.1: Login\n\nUsers log in.\n\n## G.S.2: Login audit\n\nLogins are logged.\n",
+    );
+    write_source(
+        &temp_dir,
+        "fn main() {}\n// Login check (G.S.7)\n// G.S.8\n",
+    );
+
+    let json = check_references(&temp_dir, None);
+
+    let references = json["data"]["references"].as_array().unwrap();
+    assert_eq!(references.len(), 2);
+    assert_eq!(references[0]["index"], "G.S.7");
+    assert_eq!(references[0]["file"], "src/lib.rs");
+    assert_eq!(references[0]["line"], 2);
+    assert_eq!(references[0]["status"], "missing");
+    assert_eq!(references[0]["category"], "general");
+    assert!(references[0]["last_title"].is_null());
+    assert_eq!(references[0]["replacement"]["index"], "G.S.1");
+    assert_eq!(references[0]["replacement"]["chapter"], "Security");
+    assert_eq!(references[1]["index"], "G.S.8");
+    assert!(references[1]["replacement"].is_null());
+}
+
+/// Test: the longest title in the source line wins
+/// Precondition: System has titles "Login" and "Login audit"; source line mentions "login audit"
+/// Action: Call handle_check_references
+/// Result: Replacement is G.S.2
+/// Covers Requirement: G.TOOLREQLIXCH.3
+#[test]
+fn test_check_references_line_guess_prefers_longest_title() {
+    let temp_dir = TempDir::new().unwrap();
+    setup_project(
+        &temp_dir,
+        "# Security\n\n## G.S.1: Login\n\nUsers log in.\n\n## G.S.2: Login audit\n\nLogins are logged.\n",
+    );
+    write_source(&temp_dir, "// Login audit: G.S.9\n");
+
+    let json = check_references(&temp_dir, None);
+
+    assert_eq!(
+        json["data"]["references"][0]["replacement"]["index"],
+        "G.S.2"
+    );
+}
+
+/// Test: references with an unknown category prefix have no category
+/// Precondition: System has only category general; source cites X.S.1
+/// Action: Call handle_check_references
+/// Result: Reference is missing with category null
+/// Covers Requirement: G.TOOLREQLIXCH.3, G.TOOLREQLIXCH.4
+#[test]
+fn test_check_references_unknown_category_prefix() {
+    let temp_dir = TempDir::new().unwrap();
+    setup_project(
+        &temp_dir,
+        "# Security\n\n## G.S.1: Login\n\nUsers log in.\n",
+    );
+    write_source(&temp_dir, "// X.S.1\n");
+
+    let json = check_references(&temp_dir, None);
+
+    let reference = &json["data"]["references"][0];
+    assert_eq!(reference["status"], "missing");
+    assert!(reference["category"].is_null());
+}
+
+/// Test: a renumbered requirement is reported as moved
+/// Precondition: System has committed G.S.1 "Session timeout", then moved it to chapter Sessions as G.SE.1
+/// Action: Call handle_check_references with source citing G.S.1
+/// Result: Reference is moved with last_title "Session timeout" and replacement G.SE.1
+/// Covers Requirement: G.TOOLREQLIXCH.3, G.TOOLREQLIXCH.4
+#[test]
+fn test_check_references_moved() {
+    let temp_dir = TempDir::new().unwrap();
+    setup_project(
+        &temp_dir,
+        "# Security\n\n## G.S.1: Session timeout\n\nSessions expire.\n",
+    );
+    git_commit_all(&temp_dir, "Add requirement");
+    setup_project(
+        &temp_dir,
+        "# Security\n\n# Sessions\n\n## G.SE.1: Session timeout\n\nSessions expire.\n",
+    );
+    git_commit_all(&temp_dir, "Move requirement");
+    write_source(&temp_dir, "// G.S.1\n");
+
+    let json = check_references(&temp_dir, None);
+
+    let reference = &json["data"]["references"][0];
+    assert_eq!(reference["status"], "moved");
+    assert_eq!(reference["last_title"], "Session timeout");
+    assert_eq!(reference["replacement"]["index"], "G.SE.1");
+    assert_eq!(reference["replacement"]["chapter"], "Sessions");
+}
+
+/// Test: a removed requirement is reported as deleted with a similar-title guess
+/// Precondition: System has committed G.S.1 "Password reset" and G.S.2 "Audit log", then deleted both
+///               and added G.S.3 "Password resets"
+/// Action: Call handle_check_references with source citing G.S.1 and G.S.2
+/// Result: Both are deleted; G.S.1 has replacement G.S.3, G.S.2 has none
+/// Covers Requirement: G.TOOLREQLIXCH.3
+#[test]
+fn test_check_references_deleted() {
+    let temp_dir = TempDir::new().unwrap();
+    setup_project(
+        &temp_dir,
+        "# Security\n\n## G.S.1: Password reset\n\nUsers reset passwords.\n\n## G.S.2: Audit log\n\nChanges are logged.\n",
+    );
+    git_commit_all(&temp_dir, "Add requirements");
+    setup_project(
+        &temp_dir,
+        "# Security\n\n## G.S.3: Password resets\n\nUsers reset passwords by email.\n",
+    );
+    git_commit_all(&temp_dir, "Replace requirements");
+    write_source(&temp_dir, "// G.S.1\n// G.S.2\n");
+
+    let json = check_references(&temp_dir, None);
+
+    let references = json["data"]["references"].as_array().unwrap();
+    assert_eq!(references[0]["status"], "deleted");
+    assert_eq!(references[0]["last_title"], "Password reset");
+    assert_eq!(references[0]["replacement"]["index"], "G.S.3");
+    assert_eq!(references[1]["status"], "deleted");
+    assert_eq!(references[1]["last_title"], "Audit log");
+    assert!(references[1]["replacement"].is_null());
+}
+
+/// Test: find_dangling_references library API applies include globs
+/// Precondition: System has dangling references in src/lib.rs and notes.txt
+/// Action: Call find_dangling_references with include "**/*.txt"
+/// Result: Only the reference in notes.txt is reported
+/// Covers Requirement: G.TOOLREQLIXCH.2, G.TOOLREQLIXCH.3
+#[test]
+fn test_find_dangling_references_library_api() {
+    let temp_dir = TempDir::new().unwrap();
+    setup_project(
+        &temp_dir,
+        "# Security\n\n## G.S.1: Login\n\nUsers log in.\n",
+    );
+    write_source(&temp_dir, "// G.S.4\n");
+    fs::write(temp_dir.path().join("notes.txt"), "See G.S.5\n").unwrap();
+
+    let options = TraceOptions {
+        include: vec!["**/*.txt".to_string()],
+        exclude: Vec::new(),
+    };
+    let report = find_dangling_references(&temp_dir.path().to_string_lossy(), &options).unwrap();
+
+    assert_eq!(report.files_scanned, 1);
+    assert_eq!(report.references.len(), 1);
+    assert_eq!(report.references[0].index, "G.S.5");
+    assert_eq!(report.references[0].file, "notes.txt");
+}
+
+// =============================================================================
+// Tests for G.TOOLREQLIXCH.5: Parameter validation
+// =============================================================================
+
+/// Test: check_references validates include patterns
+/// Precondition: System has an invalid include pattern
+/// Action: Call handle_check_references
+/// Result: Function returns error naming include
+/// Covers Requirement: G.TOOLREQLIXCH.5
+#[test]
+fn test_check_references_validation() {
+    let temp_dir = TempDir::new().unwrap();
+    setup_project(
+        &temp_dir,
+        "# Security\n\n## G.S.1: Login\n\nUsers log in.\n",
+    );
+
+    let json = check_references(&temp_dir, Some(vec!["[a"]));
+
+    assert!(!json["success"].as_bool().unwrap());
+    assert!(json["error"]
+        .as_str()
+        .unwrap()
+        .starts_with("Invalid include pattern '[a'"));
+}
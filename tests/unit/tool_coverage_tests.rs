@@ -0,0 +1,273 @@
+// Tests for Tool: reqlix_coverage (G.TOOLREQLIXC.*)
+// Covers Requirements: G.TOOLREQLIXC.2, G.TOOLREQLIXC.3, G.TOOLREQLIXC.4, G.TOOLREQLIXC.5, G.TOOLREQLIXC.6, G.TOOLREQLIXC.7, G.TOOLREQLIXC.8
+
+use reqlix::{coverage_report, CoverageOptions, CoverageParams, RequirementsServer};
+use serde_json::Value;
+use std::fs;
+use tempfile::TempDir;
+
+use super::common::{
+    create_agents_file_in_req_dir, create_category_file_in_req_dir, create_requirements_dir,
+    parse_response,
+};
+
+/// Write a file under the temp dir, creating parent directories
+fn write_file(temp_dir: &TempDir, relative: &str, content: &str) {
+    let path = temp_dir.path().join(relative);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, content).unwrap();
+}
+
+/// Create a project with four security requirements and one logging requirement:
+/// G.S.1 tested, G.S.2 tested via a spec file, G.S.3 code only, G.S.4 and G.L.1 unreferenced
+fn setup_project(temp_dir: &TempDir) {
+    let req_dir = create_requirements_dir(temp_dir);
+    create_agents_file_in_req_dir(&req_dir, "# Instructions\n");
+    create_category_file_in_req_dir(
+        &req_dir,
+        "general",
+        "# Security\n\n## G.S.1: Login\n\nUsers log in.\n\n## G.S.2: Logout\n\nUsers log out.\n\n\
+## G.S.3: Session timeout\n\nSessions expire.\n\n## G.S.4: Password reset\n\nUsers reset passwords.\n\n\
+# Logging\n\n## G.L.1: Request log\n\nRequests are logged.\n",
+    );
+    write_file(temp_dir, "src/auth.rs", "// G.S.1\n// G.S.2\n// G.S.3\n");
+    write_file(temp_dir, "tests/auth.rs", "// Covers Requirement: G.S.1\n");
+    write_file(temp_dir, "web/logout.spec.ts", "// G.S.2\n");
+}
+
+/// Run reqlix_coverage against a temp project
+fn coverage(temp_dir: &TempDir, test_patterns: Option<Vec<&str>>, format: Option<&str>) -> Value {
+    let params = CoverageParams {
+        project_root: temp_dir.path().to_string_lossy().to_string(),
+        operation_description: "test".to_string(),
+        include: None,
+        exclude: None,
+        test_patterns: test_patterns.map(|v| v.into_iter().map(|s| s.to_string()).collect()),
+        format: format.map(|s| s.to_string()),
+    };
+    parse_response(&RequirementsServer::handle_coverage(params))
+}
+
+/// Collect the index field of each entry in a JSON array
+fn indices(value: &Value) -> Vec<&str> {
+    value
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|r| r["index"].as_str().unwrap())
+        .collect()
+}
+
+// =============================================================================
+// Tests for G.TOOLREQLIXC.3: Test files
+// =============================================================================
+
+/// Test: default test patterns recognize tests directories and spec files
+/// Precondition: System has G.S.1 cited in tests/auth.rs and G.S.2 cited in web/logout.spec.ts
+/// Action: Call handle_coverage with default test patterns
+/// Result: G.S.1 and G.S.2 are tested, G.S.3 is listed as untested
+/// Covers Requirement: G.TOOLREQLIXC.3, G.TOOLREQLIXC.4
+#[test]
+fn test_coverage_default_test_patterns() {
+    let temp_dir = TempDir::new().unwrap();
+    setup_project(&temp_dir);
+
+    let json = coverage(&temp_dir, None, None);
+
+    assert!(json["success"].as_bool().unwrap());
+    assert_eq!(json["data"]["total"]["tested"], 2);
+    assert_eq!(indices(&json["data"]["untested"]), vec!["G.S.3"]);
+}
+
+/// Test: custom test patterns replace the defaults
+/// Precondition: System has the sample project
+/// Action: Call handle_coverage with test_patterns ["web/**"]
+/// Result: Only G.S.2 is tested; G.S.1 and G.S.3 are untested
+/// Covers Requirement: G.TOOLREQLIXC.2, G.TOOLREQLIXC.3
+#[test]
+fn test_coverage_custom_test_patterns() {
+    let temp_dir = TempDir::new().unwrap();
+    setup_project(&temp_dir);
+
+    let json = coverage(&temp_dir, Some(vec!["web/**"]), None);
+
+    assert_eq!(json["data"]["total"]["tested"], 1);
+    assert_eq!(indices(&json["data"]["untested"]), vec!["G.S.1", "G.S.3"]);
+}
+
+// =============================================================================
+// Tests for G.TOOLREQLIXC.4: Algorithm
+// =============================================================================
+
+/// Test: coverage lists unreferenced requirements and computes percentages
+/// Precondition: System has five requirements, three referenced and two tested
+/// Action: Call handle_coverage
+/// Result: Totals are 60.0% referenced and 40.0% tested; G.S.4 and G.L.1 are unreferenced
+/// Covers Requirement: G.TOOLREQLIXC.4, G.TOOLREQLIXC.5
+#[test]
+fn test_coverage_totals_and_unreferenced() {
+    let temp_dir = TempDir::new().unwrap();
+    setup_project(&temp_dir);
+
+    let json = coverage(&temp_dir, None, None);
+
+    let data = &json["data"];
+    assert_eq!(data["files_scanned"], 3);
+    assert_eq!(
+        data["total"],
+        serde_json::json!({
+            "requirements": 5,
+            "referenced": 3,
+            "tested": 2,
+            "referenced_percent": 60.0,
+            "tested_percent": 40.0
+        })
+    );
+    assert_eq!(indices(&data["unreferenced"]), vec!["G.S.4", "G.L.1"]);
+    assert_eq!(data["unreferenced"][1]["title"], "Request log");
+    assert_eq!(data["unreferenced"][1]["chapter"], "Logging");
+    assert!(data.get("rendered").is_none());
+}
+
+/// Test: coverage summarizes each category and chapter
+/// Precondition: System has chapters Security (4 requirements) and Logging (1 requirement)
+/// Action: Call handle_coverage
+/// Result: One category row and two chapter rows in file order with their own percentages
+/// Covers Requirement: G.TOOLREQLIXC.4, G.TOOLREQLIXC.5
+#[test]
+fn test_coverage_per_category_and_chapter() {
+    let temp_dir = TempDir::new().unwrap();
+    setup_project(&temp_dir);
+
+    let json = coverage(&temp_dir, None, None);
+
+    let categories = json["data"]["categories"].as_array().unwrap();
+    assert_eq!(categories.len(), 1);
+    assert_eq!(categories[0]["category"], "general");
+    assert_eq!(categories[0]["requirements"], 5);
+
+    let chapters = json["data"]["chapters"].as_array().unwrap();
+    assert_eq!(chapters.len(), 2);
+    assert_eq!(chapters[0]["chapter"], "Security");
+    assert_eq!(chapters[0]["referenced_percent"], 75.0);
+    assert_eq!(chapters[0]["tested_percent"], 50.0);
+    assert_eq!(chapters[1]["chapter"], "Logging");
+    assert_eq!(chapters[1]["referenced_percent"], 0.0);
+}
+
+/// Test: coverage of a project without requirements
+/// Precondition: System has a requirements directory without categories
+/// Action: Call coverage_report
+/// Result: Totals are zero with 0.0 percentages and all lists are empty
+/// Covers Requirement: G.TOOLREQLIXC.4
+#[test]
+fn test_coverage_report_empty_project() {
+    let temp_dir = TempDir::new().unwrap();
+    let req_dir = create_requirements_dir(&temp_dir);
+    create_agents_file_in_req_dir(&req_dir, "# Instructions\n");
+
+    let report = coverage_report(
+        &temp_dir.path().to_string_lossy(),
+        &CoverageOptions::default(),
+    )
+    .unwrap();
+
+    assert_eq!(report.total.requirements, 0);
+    assert_eq!(report.total.referenced_percent, 0.0);
+    assert!(report.categories.is_empty());
+    assert!(report.unreferenced.is_empty());
+}
+
+// =============================================================================
+// Tests for G.TOOLREQLIXC.6 and G.TOOLREQLIXC.7: Renderings
+// =============================================================================
+
+/// Test: markdown rendering contains the summary table and gap lists
+/// Precondition: System has the sample project
+/// Action: Call handle_coverage with format "markdown"
+/// Result: rendered contains the heading, total row, chapter rows and list items
+/// Covers Requirement: G.TOOLREQLIXC.6
+#[test]
+fn test_coverage_markdown() {
+    let temp_dir = TempDir::new().unwrap();
+    setup_project(&temp_dir);
+
+    let json = coverage(&temp_dir, None, Some("markdown"));
+
+    let rendered = json["data"]["rendered"].as_str().unwrap();
+    assert!(rendered.starts_with("# Requirements Coverage\n\nFiles scanned: 3\n"));
+    assert!(rendered.contains("| Total | 5 | 3 (60.0%) | 2 (40.0%) |\n"));
+    assert!(rendered.contains("| general | Security | 4 | 3 (75.0%) | 2 (50.0%) |\n"));
+    assert!(rendered.contains(
+        "## Unreferenced requirements\n\n- G.S.4: Password reset (general / Security)\n"
+    ));
+    assert!(rendered
+        .contains("## Referenced in code only\n\n- G.S.3: Session timeout (general / Security)\n"));
+    assert_eq!(json["data"]["total"]["requirements"], 5);
+}
+
+/// Test: CSV rendering has a stable header and scope order
+/// Precondition: System has the sample project
+/// Action: Call handle_coverage with format "csv"
+/// Result: rendered lists total, category, chapter, unreferenced and untested lines in order
+/// Covers Requirement: G.TOOLREQLIXC.7
+#[test]
+fn test_coverage_csv() {
+    let temp_dir = TempDir::new().unwrap();
+    setup_project(&temp_dir);
+
+    let json = coverage(&temp_dir, None, Some("csv"));
+
+    assert_eq!(
+        json["data"]["rendered"],
+        "scope,category,chapter,requirements,referenced,tested,referenced_percent,tested_percent,index,title\n\
+total,,,5,3,2,60.0,40.0,,\n\
+category,general,,5,3,2,60.0,40.0,,\n\
+chapter,general,Security,4,3,2,75.0,50.0,,\n\
+chapter,general,Logging,1,0,0,0.0,0.0,,\n\
+unreferenced,general,Security,,,,,,G.S.4,Password reset\n\
+unreferenced,general,Logging,,,,,,G.L.1,Request log\n\
+untested,general,Security,,,,,,G.S.3,Session timeout\n"
+    );
+}
+
+/// Test: csv_field quotes commas, quotes and line breaks
+/// Precondition: System has plain and special field values
+/// Action: Call csv_field
+/// Result: Plain values are unchanged; special values are quoted with doubled inner quotes
+/// Covers Requirement: G.TOOLREQLIXC.7
+#[test]
+fn test_csv_field_quoting() {
+    assert_eq!(RequirementsServer::csv_field("Security"), "Security");
+    assert_eq!(RequirementsServer::csv_field("A, B"), "\"A, B\"");
+    assert_eq!(
+        RequirementsServer::csv_field("Say \"hi\""),
+        "\"Say \"\"hi\"\"\""
+    );
+    assert_eq!(RequirementsServer::csv_field("a\nb"), "\"a\nb\"");
+}
+
+// =============================================================================
+// Tests for G.TOOLREQLIXC.8: Parameter validation
+// =============================================================================
+
+/// Test: coverage validates test_patterns before format
+/// Precondition: System has an invalid test pattern and an unknown format
+/// Action: Call handle_coverage with both, then with only the unknown format
+/// Result: First error names test_patterns, second lists the allowed formats
+/// Covers Requirement: G.TOOLREQLIXC.8
+#[test]
+fn test_coverage_validation_order() {
+    let temp_dir = TempDir::new().unwrap();
+    setup_project(&temp_dir);
+
+    let json = coverage(&temp_dir, Some(vec!["[a"]), Some("html"));
+    assert!(!json["success"].as_bool().unwrap());
+    assert!(json["error"]
+        .as_str()
+        .unwrap()
+        .starts_with("Invalid test_patterns pattern '[a'"));
+
+    let json = coverage(&temp_dir, None, Some("html"));
+    assert_eq!(json["error"], "format must be one of: json, markdown, csv");
+}
@@ -1,5 +1,5 @@
 // Tests for Tool: reqlix_delete_requirement (G.TOOLREQLIXD.*)
-// Covers Requirements: G.TOOLREQLIXD.1, G.TOOLREQLIXD.3, G.TOOLREQLIXD.4, G.TOOLREQLIXD.5
+// Covers Requirements: G.TOOLREQLIXD.1, G.TOOLREQLIXD.3, G.TOOLREQLIXD.4, G.TOOLREQLIXD.5, G.TOOLREQLIXD.7
 
 use reqlix::RequirementsServer;
 use tempfile::TempDir;
@@ -38,6 +38,7 @@ Content of second requirement.
         project_root: temp_dir.path().to_string_lossy().to_string(),
         operation_description: "Test delete".to_string(),
         index: reqlix::IndexParam::Single("G.T.1".to_string()),
+        force: None,
     };
     let result = RequirementsServer::handle_delete_requirement(params);
     let parsed: serde_json::Value = serde_json::from_str(&result).unwrap();
@@ -80,6 +81,7 @@ Content.
         project_root: temp_dir.path().to_string_lossy().to_string(),
         operation_description: "Test delete".to_string(),
         index: reqlix::IndexParam::Single("G.T.999".to_string()),
+        force: None,
     };
     let result = RequirementsServer::handle_delete_requirement(params);
     let parsed: serde_json::Value = serde_json::from_str(&result).unwrap();
@@ -99,6 +101,7 @@ fn test_delete_requirement_validation() {
         project_root: "".to_string(),
         operation_description: "Test".to_string(),
         index: reqlix::IndexParam::Single("G.T.1".to_string()),
+        force: None,
     };
     let result = RequirementsServer::handle_delete_requirement(params);
     let parsed: serde_json::Value = serde_json::from_str(&result).unwrap();
@@ -136,6 +139,7 @@ More content.
         project_root: temp_dir.path().to_string_lossy().to_string(),
         operation_description: "Test delete".to_string(),
         index: reqlix::IndexParam::Single("G.F.1".to_string()),
+        force: None,
     };
     let result = RequirementsServer::handle_delete_requirement(params);
     let parsed: serde_json::Value = serde_json::from_str(&result).unwrap();
@@ -176,6 +180,7 @@ Content.
         project_root: temp_dir.path().to_string_lossy().to_string(),
         operation_description: "Test delete".to_string(),
         index: reqlix::IndexParam::Single("G.O.1".to_string()),
+        force: None,
     };
     let result = RequirementsServer::handle_delete_requirement(params);
     let parsed: serde_json::Value = serde_json::from_str(&result).unwrap();
@@ -208,6 +213,7 @@ fn test_batch_delete_requirement_empty_array() {
         project_root: temp_dir.path().to_string_lossy().to_string(),
         operation_description: "Test batch".to_string(),
         index: reqlix::IndexParam::Batch(vec![]),
+        force: None,
     };
     let result = RequirementsServer::handle_delete_requirement(params);
     let parsed: serde_json::Value = serde_json::from_str(&result).unwrap();
@@ -232,6 +238,7 @@ fn test_batch_delete_requirement_single_element() {
         project_root: temp_dir.path().to_string_lossy().to_string(),
         operation_description: "Test batch".to_string(),
         index: reqlix::IndexParam::Batch(vec!["G.C.1".to_string()]),
+        force: None,
     };
     let result = RequirementsServer::handle_delete_requirement(params);
     let parsed: serde_json::Value = serde_json::from_str(&result).unwrap();
@@ -270,6 +277,7 @@ Content three.
         project_root: temp_dir.path().to_string_lossy().to_string(),
         operation_description: "Test batch".to_string(),
         index: reqlix::IndexParam::Batch(vec!["G.C.1".to_string(), "G.C.2".to_string()]),
+        force: None,
     };
     let result = RequirementsServer::handle_delete_requirement(params);
     let parsed: serde_json::Value = serde_json::from_str(&result).unwrap();
@@ -314,6 +322,7 @@ Content two.
             "G.C.999".to_string(), // Does not exist
             "G.C.2".to_string(),
         ]),
+        force: None,
     };
     let result = RequirementsServer::handle_delete_requirement(params);
     let parsed: serde_json::Value = serde_json::from_str(&result).unwrap();
@@ -353,6 +362,7 @@ fn test_batch_delete_requirement_exceeds_limit() {
         project_root: temp_dir.path().to_string_lossy().to_string(),
         operation_description: "Test batch".to_string(),
         index: reqlix::IndexParam::Batch(indices),
+        force: None,
     };
     let result = RequirementsServer::handle_delete_requirement(params);
     let parsed: serde_json::Value = serde_json::from_str(&result).unwrap();
@@ -360,3 +370,108 @@ fn test_batch_delete_requirement_exceeds_limit() {
     assert_eq!(parsed["success"], false);
     assert!(parsed["error"].as_str().unwrap().contains("100"));
 }
+
+// =============================================================================
+// Tests for G.TOOLREQLIXD.7: Linked requirements
+// =============================================================================
+
+const LINKED_CONTENT: &str = "# Chapter\n\n\
+## G.C.1: Target\n\nTarget content.\n\n\
+## G.C.2: Linking by index\n\nSee G.C.1.\n\n\
+## G.C.3: Linking by anchor\n\nSee [target](#gc1-target).\n";
+
+/// Delete requirements from a project with G.C.2 and G.C.3 linking to G.C.1
+fn delete_linked(index: reqlix::IndexParam, force: Option<bool>) -> (TempDir, serde_json::Value) {
+    let temp_dir = TempDir::new().unwrap();
+    let req_dir = create_requirements_dir(&temp_dir);
+    create_agents_file_in_req_dir(&req_dir, "# Instructions\n");
+    create_category_file_in_req_dir(&req_dir, "general", LINKED_CONTENT);
+
+    let params = reqlix::DeleteRequirementParams {
+        project_root: temp_dir.path().to_string_lossy().to_string(),
+        operation_description: "Test delete".to_string(),
+        index,
+        force,
+    };
+    let result = RequirementsServer::handle_delete_requirement(params);
+    (temp_dir, serde_json::from_str(&result).unwrap())
+}
+
+/// Test: delete refuses a requirement that other requirements link to
+/// Precondition: System has G.C.2 linking to G.C.1 by index and G.C.3 by anchor
+/// Action: Call handle_delete_requirement for G.C.1 without force
+/// Result: Error lists both linking indices and the file is unchanged
+/// Covers Requirement: G.TOOLREQLIXD.3, G.TOOLREQLIXD.7
+#[test]
+fn test_delete_requirement_refuses_linked() {
+    let (temp_dir, parsed) = delete_linked(reqlix::IndexParam::Single("G.C.1".to_string()), None);
+
+    assert_eq!(parsed["success"], false);
+    assert_eq!(
+        parsed["error"],
+        "Requirement is referenced by G.C.2, G.C.3; set force to true to delete it anyway"
+    );
+    let file_content = std::fs::read_to_string(
+        temp_dir
+            .path()
+            .join("docs/development/requirements/general.md"),
+    )
+    .unwrap();
+    assert_eq!(file_content, LINKED_CONTENT);
+}
+
+/// Test: forced delete of a linked requirement returns warnings
+/// Precondition: System has G.C.2 and G.C.3 linking to G.C.1
+/// Action: Call handle_delete_requirement for G.C.1 with force true, then delete unlinked G.C.3
+/// Result: G.C.1 is deleted with one warning per linking requirement; G.C.3 result has no warnings field
+/// Covers Requirement: G.TOOLREQLIXD.4, G.TOOLREQLIXD.7
+#[test]
+fn test_delete_requirement_force_warns() {
+    let (_temp_dir, parsed) =
+        delete_linked(reqlix::IndexParam::Single("G.C.1".to_string()), Some(true));
+
+    assert_eq!(parsed["success"], true);
+    assert_eq!(
+        parsed["data"]["warnings"],
+        serde_json::json!([
+            "Requirement was referenced by G.C.2",
+            "Requirement was referenced by G.C.3"
+        ])
+    );
+
+    let (_temp_dir, parsed) = delete_linked(reqlix::IndexParam::Single("G.C.3".to_string()), None);
+    assert_eq!(parsed["success"], true);
+    assert!(parsed["data"].get("warnings").is_none());
+}
+
+/// Test: batch delete ignores links from requirements in the same batch
+/// Precondition: System has G.C.2 and G.C.3 linking to G.C.1
+/// Action: Call handle_delete_requirement with ["G.C.1", "G.C.2"] without force
+/// Result: G.C.1 is refused because of G.C.3 only; G.C.2 is deleted
+/// Covers Requirement: G.TOOLREQLIXD.7
+#[test]
+fn test_batch_delete_requirement_ignores_links_within_batch() {
+    let (_temp_dir, parsed) = delete_linked(
+        reqlix::IndexParam::Batch(vec!["G.C.1".to_string(), "G.C.2".to_string()]),
+        None,
+    );
+
+    let data = parsed["data"].as_array().unwrap();
+    assert_eq!(data[0]["success"], false);
+    assert_eq!(
+        data[0]["error"],
+        "Requirement is referenced by G.C.3; set force to true to delete it anyway"
+    );
+    assert_eq!(data[1]["success"], true);
+
+    let (_temp_dir, parsed) = delete_linked(
+        reqlix::IndexParam::Batch(vec![
+            "G.C.2".to_string(),
+            "G.C.3".to_string(),
+            "G.C.1".to_string(),
+        ]),
+        None,
+    );
+    let data = parsed["data"].as_array().unwrap();
+    assert!(data.iter().all(|r| r["success"] == true));
+}
@@ -0,0 +1,266 @@
+// Tests for Tool: reqlix_export_graph (G.TOOLREQLIXEXPORTG.*)
+// Covers Requirements: G.TOOLREQLIXEXPORTG.3, G.TOOLREQLIXEXPORTG.4, G.TOOLREQLIXEXPORTG.5, G.TOOLREQLIXEXPORTG.6
+
+use reqlix::{ExportGraphParams, RequirementsServer};
+use serde_json::Value;
+use tempfile::TempDir;
+
+use super::common::{
+    create_agents_file_in_req_dir, create_category_file_in_req_dir, create_requirements_dir,
+    parse_response,
+};
+
+/// Create a project with the given category files
+fn create_project(categories: &[(&str, &str)]) -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    let req_dir = create_requirements_dir(&temp_dir);
+    create_agents_file_in_req_dir(&req_dir, "# Instructions\n");
+    for (category, content) in categories {
+        create_category_file_in_req_dir(&req_dir, category, content);
+    }
+    temp_dir
+}
+
+/// Project of the G.TOOLREQLIXEXPORTG.5 examples: G.S.1 links to G.S.2, G.S.2 depends on G.S.1
+fn create_security_project() -> TempDir {
+    create_project(&[(
+        "general",
+        "# Security\n\n## G.S.1: Session timeout\n\nSee G.S.2.\n\n\
+## G.S.2: Password reset\n\nText.\n\n- depends_on: G.S.1\n",
+    )])
+}
+
+/// Chain G.A.1 -> G.A.2 -> G.A.3 -> G.A.4 in general.md, T.U.1 linking to G.A.1 in testing.md
+fn create_chain_project() -> TempDir {
+    create_project(&[
+        (
+            "general",
+            "# Alpha\n\n## G.A.1: One\n\nSee G.A.2.\n\n## G.A.2: Two\n\nSee G.A.3.\n\n\
+## G.A.3: Three\n\nSee G.A.4.\n\n# Beta\n\n## G.A.4: Four\n\nText \"quoted\".\n",
+        ),
+        ("testing", "# Unit\n\n## T.U.1: Tests\n\nCovers G.A.1.\n"),
+    ])
+}
+
+/// Run reqlix_export_graph against a temp project
+fn export(
+    temp_dir: &TempDir,
+    format: &str,
+    category: Option<&str>,
+    index: Option<&str>,
+    radius: Option<usize>,
+) -> Value {
+    let params = ExportGraphParams {
+        project_root: temp_dir.path().to_string_lossy().to_string(),
+        operation_description: "test".to_string(),
+        format: format.to_string(),
+        category: category.map(|c| c.to_string()),
+        index: index.map(|i| i.to_string()),
+        radius,
+    };
+    parse_response(&RequirementsServer::handle_export_graph(params))
+}
+
+/// Node ids of a Mermaid rendering, in order
+fn mermaid_nodes(rendered: &str) -> Vec<String> {
+    rendered
+        .lines()
+        .filter(|l| l.starts_with("      "))
+        .map(|l| l.trim().split('[').next().unwrap().to_string())
+        .collect()
+}
+
+// =============================================================================
+// Tests for G.TOOLREQLIXEXPORTG.5: Renderings
+// =============================================================================
+
+/// Test: DOT rendering matches the example
+/// Precondition: System has G.S.1 linking to G.S.2 and G.S.2 depending on G.S.1
+/// Action: Export the graph as DOT
+/// Result: Nested clusters, a dashed link edge, a labeled relation edge and no link edge for the relation target
+/// Covers Requirement: G.TOOLREQLIXEXPORTG.3, G.TOOLREQLIXEXPORTG.4, G.TOOLREQLIXEXPORTG.5
+#[test]
+fn test_export_graph_dot() {
+    let temp_dir = create_security_project();
+
+    let json = export(&temp_dir, "dot", None, None, None);
+
+    assert!(json["success"].as_bool().unwrap());
+    let data = &json["data"];
+    assert_eq!(data["format"], "dot");
+    assert_eq!(data["nodes"], 2);
+    assert_eq!(data["edges"], 2);
+    assert_eq!(
+        data["rendered"],
+        concat!(
+            "digraph requirements {\n",
+            "  rankdir=LR;\n",
+            "  node [shape=box];\n",
+            "  subgraph cluster_c1 {\n",
+            "    label=\"general\";\n",
+            "    subgraph cluster_c1_1 {\n",
+            "      label=\"Security\";\n",
+            "      \"G.S.1\" [label=\"G.S.1\\nSession timeout\"];\n",
+            "      \"G.S.2\" [label=\"G.S.2\\nPassword reset\"];\n",
+            "    }\n",
+            "  }\n",
+            "  \"G.S.1\" -> \"G.S.2\" [style=dashed];\n",
+            "  \"G.S.2\" -> \"G.S.1\" [label=\"depends_on\"];\n",
+            "}\n"
+        )
+    );
+}
+
+/// Test: Mermaid rendering matches the example
+/// Precondition: System has G.S.1 linking to G.S.2 and G.S.2 depending on G.S.1
+/// Action: Export the graph as Mermaid
+/// Result: Nested subgraphs, a dotted link edge and a labeled relation edge
+/// Covers Requirement: G.TOOLREQLIXEXPORTG.5
+#[test]
+fn test_export_graph_mermaid() {
+    let temp_dir = create_security_project();
+
+    let json = export(&temp_dir, "mermaid", None, None, None);
+
+    assert_eq!(
+        json["data"]["rendered"],
+        concat!(
+            "flowchart LR\n",
+            "  subgraph c1[\"general\"]\n",
+            "    subgraph c1_1[\"Security\"]\n",
+            "      G_S_1[\"G.S.1: Session timeout\"]\n",
+            "      G_S_2[\"G.S.2: Password reset\"]\n",
+            "    end\n",
+            "  end\n",
+            "  G_S_1 -.-> G_S_2\n",
+            "  G_S_2 -->|depends_on| G_S_1\n"
+        )
+    );
+}
+
+/// Test: renderings escape quotes in labels
+/// Precondition: System has a chapter title with double quotes
+/// Action: Export the graph as DOT and as Mermaid
+/// Result: DOT escapes with a backslash, Mermaid writes #quot;
+/// Covers Requirement: G.TOOLREQLIXEXPORTG.5
+#[test]
+fn test_export_graph_escaping() {
+    let temp_dir = create_project(&[("general", "# The \"core\"\n\n## G.C.1: A\\B\n\nText.\n")]);
+
+    let dot = export(&temp_dir, "dot", None, None, None);
+    let mermaid = export(&temp_dir, "mermaid", None, None, None);
+
+    let dot = dot["data"]["rendered"].as_str().unwrap();
+    assert!(dot.contains("label=\"The \\\"core\\\"\";"));
+    assert!(dot.contains("[label=\"G.C.1\\nA\\\\B\"]"));
+    assert!(mermaid["data"]["rendered"]
+        .as_str()
+        .unwrap()
+        .contains("subgraph c1_1[\"The #quot;core#quot;\"]"));
+}
+
+// =============================================================================
+// Tests for G.TOOLREQLIXEXPORTG.3: Algorithm
+// =============================================================================
+
+/// Test: category filter keeps one category and drops edges leaving it
+/// Precondition: System has a chain in general and T.U.1 in testing linking to G.A.1
+/// Action: Export the general category
+/// Result: Four nodes in two chapters, three edges
+/// Covers Requirement: G.TOOLREQLIXEXPORTG.3
+#[test]
+fn test_export_graph_category() {
+    let temp_dir = create_chain_project();
+
+    let json = export(&temp_dir, "mermaid", Some("general"), None, None);
+
+    let data = &json["data"];
+    assert_eq!(data["nodes"], 4);
+    assert_eq!(data["edges"], 3);
+    let rendered = data["rendered"].as_str().unwrap();
+    assert!(rendered.contains("subgraph c1_2[\"Beta\"]"));
+    assert!(!rendered.contains("T_U_1"));
+}
+
+/// Test: neighborhood filter follows links in both directions up to the radius
+/// Precondition: System has the chain T.U.1 -> G.A.1 -> G.A.2 -> G.A.3 -> G.A.4
+/// Action: Export around G.A.2 with the default radius, radius 2 and radius 0
+/// Result: G.A.1-G.A.3; then also G.A.4 and T.U.1; then G.A.2 only
+/// Covers Requirement: G.TOOLREQLIXEXPORTG.3
+#[test]
+fn test_export_graph_neighborhood() {
+    let temp_dir = create_chain_project();
+
+    let json = export(&temp_dir, "mermaid", None, Some("G.A.2"), None);
+    assert_eq!(
+        mermaid_nodes(json["data"]["rendered"].as_str().unwrap()),
+        vec!["G_A_1", "G_A_2", "G_A_3"]
+    );
+    assert_eq!(json["data"]["edges"], 2);
+
+    let json = export(&temp_dir, "mermaid", None, Some("G.A.2"), Some(2));
+    assert_eq!(
+        mermaid_nodes(json["data"]["rendered"].as_str().unwrap()),
+        vec!["G_A_1", "G_A_2", "G_A_3", "G_A_4", "T_U_1"]
+    );
+
+    let json = export(&temp_dir, "mermaid", None, Some("G.A.2"), Some(0));
+    assert_eq!(json["data"]["nodes"], 1);
+    assert_eq!(json["data"]["edges"], 0);
+}
+
+/// Test: category and neighborhood filters combine
+/// Precondition: System has the chain T.U.1 -> G.A.1 -> G.A.2
+/// Action: Export around G.A.1 with radius 1 in category testing
+/// Result: T.U.1 only
+/// Covers Requirement: G.TOOLREQLIXEXPORTG.3
+#[test]
+fn test_export_graph_category_and_neighborhood() {
+    let temp_dir = create_chain_project();
+
+    let json = export(&temp_dir, "mermaid", Some("testing"), Some("G.A.1"), None);
+
+    assert_eq!(
+        mermaid_nodes(json["data"]["rendered"].as_str().unwrap()),
+        vec!["T_U_1"]
+    );
+}
+
+/// Test: unknown category and requirement are errors
+/// Precondition: System has a project without category "security" and requirement G.X.1
+/// Action: Export with category "security", then with index "G.X.1"
+/// Result: "Category not found", then "Requirement not found"
+/// Covers Requirement: G.TOOLREQLIXEXPORTG.3
+#[test]
+fn test_export_graph_not_found() {
+    let temp_dir = create_chain_project();
+
+    let json = export(&temp_dir, "dot", Some("security"), None, None);
+    assert_eq!(json["error"], "Category not found");
+
+    let json = export(&temp_dir, "dot", None, Some("G.X.1"), None);
+    assert_eq!(json["error"], "Requirement not found");
+}
+
+// =============================================================================
+// Tests for G.TOOLREQLIXEXPORTG.6: Parameter validation
+// =============================================================================
+
+/// Test: export validates format, then radius, then radius without index
+/// Precondition: System has a project
+/// Action: Export with format "svg", radius 11, then radius 2 without index
+/// Result: Format error, range error, then "radius requires index"
+/// Covers Requirement: G.TOOLREQLIXEXPORTG.6
+#[test]
+fn test_export_graph_validation_order() {
+    let temp_dir = create_chain_project();
+
+    let json = export(&temp_dir, "svg", None, None, Some(11));
+    assert_eq!(json["error"], "format must be one of: dot, mermaid");
+
+    let json = export(&temp_dir, "dot", None, None, Some(11));
+    assert_eq!(json["error"], "radius must be between 0 and 10");
+
+    let json = export(&temp_dir, "dot", None, None, Some(2));
+    assert_eq!(json["error"], "radius requires index");
+}
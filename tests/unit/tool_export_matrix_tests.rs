@@ -0,0 +1,311 @@
+// Tests for Tool: reqlix_export_matrix (G.TOOLREQLIXE.*)
+// Covers Requirements: G.TOOLREQLIXE.3, G.TOOLREQLIXE.4, G.TOOLREQLIXE.5, G.TOOLREQLIXE.6
+
+use reqlix::{
+    html_escape, render_matrix_csv, render_matrix_html, render_matrix_markdown, ExportMatrixParams,
+    MatrixRow, RequirementsServer, TraceabilityMatrix,
+};
+use serde_json::Value;
+use std::fs;
+use tempfile::TempDir;
+
+use super::common::{
+    create_agents_file_in_req_dir, create_category_file_in_req_dir, create_requirements_dir,
+    parse_response,
+};
+
+/// Create a project with G.R.1 cited in code and tests, G.R.2 in code only and G.R.3 not cited
+fn create_project() -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    let req_dir = create_requirements_dir(&temp_dir);
+    create_agents_file_in_req_dir(&req_dir, "# Instructions\n");
+    create_category_file_in_req_dir(
+        &req_dir,
+        "general",
+        "# Rules\n\n## G.R.1: One\n\nText.\n\n## G.R.2: Two\n\nText.\n\n## G.R.3: Three\n\nText.\n",
+    );
+    fs::create_dir_all(temp_dir.path().join("src")).unwrap();
+    fs::write(
+        temp_dir.path().join("src/lib.rs"),
+        "// G.R.1\nfn a() {}\n// G.R.2\n",
+    )
+    .unwrap();
+    fs::create_dir_all(temp_dir.path().join("tests")).unwrap();
+    fs::write(
+        temp_dir.path().join("tests/a.rs"),
+        "/// Covers Requirement: G.R.1\n",
+    )
+    .unwrap();
+    temp_dir
+}
+
+/// Run reqlix_export_matrix against a temp project
+fn export(temp_dir: &TempDir, format: &str, output: Option<&str>) -> Value {
+    let params = ExportMatrixParams {
+        project_root: temp_dir.path().to_string_lossy().to_string(),
+        operation_description: "test".to_string(),
+        format: format.to_string(),
+        include: None,
+        exclude: None,
+        test_patterns: None,
+        output: output.map(|o| o.to_string()),
+    };
+    parse_response(&RequirementsServer::handle_export_matrix(params))
+}
+
+/// Matrix with one row whose fields need escaping
+fn sample_matrix() -> TraceabilityMatrix {
+    TraceabilityMatrix {
+        verification_imported_at: Some("2026-10-18T09:30:00Z".to_string()),
+        rows: vec![MatrixRow {
+            index: "G.R.1".to_string(),
+            title: "A | \"B\", <C>".to_string(),
+            category: "general".to_string(),
+            chapter: "Rules".to_string(),
+            status: "tested".to_string(),
+            code_references: vec!["src/a.rs:1".to_string(), "src/b.rs:2".to_string()],
+            test_references: vec![],
+            verification: "failing".to_string(),
+        }],
+    }
+}
+
+// =============================================================================
+// Tests for G.TOOLREQLIXE.3: Algorithm
+// =============================================================================
+
+/// Test: export splits references and classifies requirements
+/// Precondition: System has G.R.1 cited in code and tests, G.R.2 in code only, G.R.3 not cited, no verification
+/// Action: Export the matrix as CSV
+/// Result: Rows with code and test references, statuses tested, code_only, unreferenced, verification unknown
+/// Covers Requirement: G.TOOLREQLIXE.3, G.TOOLREQLIXE.4
+#[test]
+fn test_export_matrix_rows() {
+    let temp_dir = create_project();
+
+    let json = export(&temp_dir, "csv", None);
+
+    assert!(json["success"].as_bool().unwrap());
+    let data = &json["data"];
+    assert_eq!(data["format"], "csv");
+    assert!(data["verification_imported_at"].is_null());
+    assert!(data["output"].is_null());
+    assert_eq!(
+        data["rows"][0],
+        serde_json::json!({
+            "index": "G.R.1",
+            "title": "One",
+            "category": "general",
+            "chapter": "Rules",
+            "status": "tested",
+            "code_references": ["src/lib.rs:1"],
+            "test_references": ["tests/a.rs:1"],
+            "verification": "unknown"
+        })
+    );
+    assert_eq!(data["rows"][1]["status"], "code_only");
+    assert_eq!(data["rows"][2]["status"], "unreferenced");
+    assert_eq!(
+        data["rendered"],
+        "index,title,category,chapter,status,code_references,test_references,verification\n\
+G.R.1,One,general,Rules,tested,src/lib.rs:1,tests/a.rs:1,unknown\n\
+G.R.2,Two,general,Rules,code_only,src/lib.rs:3,,unknown\n\
+G.R.3,Three,general,Rules,unreferenced,,,unknown\n"
+    );
+}
+
+/// Test: export reads the verification of the last import
+/// Precondition: System has verification.json with G.R.1 failing
+/// Action: Export the matrix as markdown
+/// Result: G.R.1 failing, other requirements unknown, the import time in the header
+/// Covers Requirement: G.TOOLREQLIXE.3
+#[test]
+fn test_export_matrix_verification() {
+    let temp_dir = create_project();
+    fs::write(
+        temp_dir
+            .path()
+            .join("docs/development/requirements/verification.json"),
+        r#"{"imported_at":"2026-10-18T09:30:00Z","file":"junit.xml","format":"junit","tests_found":1,
+"tests_matched":1,"summary":{"verified":0,"failing":1,"unverified":0},"requirements":[{"index":"G.R.1",
+"title":"One","category":"general","chapter":"Rules","status":"failing","tests":[]}]}"#,
+    )
+    .unwrap();
+
+    let json = export(&temp_dir, "markdown", None);
+
+    let data = &json["data"];
+    assert_eq!(data["verification_imported_at"], "2026-10-18T09:30:00Z");
+    assert_eq!(data["rows"][0]["verification"], "failing");
+    assert_eq!(data["rows"][1]["verification"], "unknown");
+    assert!(data["rendered"]
+        .as_str()
+        .unwrap()
+        .contains("Verification imported at: 2026-10-18T09:30:00Z\n"));
+}
+
+/// Test: export rejects an invalid verification.json
+/// Precondition: System has verification.json that is not JSON
+/// Action: Export the matrix
+/// Result: Error starting with "Invalid verification.json: "
+/// Covers Requirement: G.TOOLREQLIXE.3
+#[test]
+fn test_export_matrix_invalid_verification() {
+    let temp_dir = create_project();
+    fs::write(
+        temp_dir
+            .path()
+            .join("docs/development/requirements/verification.json"),
+        "not json",
+    )
+    .unwrap();
+
+    let json = export(&temp_dir, "csv", None);
+
+    assert!(json["error"]
+        .as_str()
+        .unwrap()
+        .starts_with("Invalid verification.json: "));
+}
+
+/// Test: export writes the rendered matrix to output
+/// Precondition: System has a project without the target directory
+/// Action: Export the matrix as HTML with output "target/matrix.html"
+/// Result: The file is created with the rendered matrix and output is returned
+/// Covers Requirement: G.TOOLREQLIXE.3, G.TOOLREQLIXE.4
+#[test]
+fn test_export_matrix_output_file() {
+    let temp_dir = create_project();
+
+    let json = export(&temp_dir, "html", Some("target/matrix.html"));
+
+    let data = &json["data"];
+    assert_eq!(data["output"], "target/matrix.html");
+    assert_eq!(
+        fs::read_to_string(temp_dir.path().join("target/matrix.html")).unwrap(),
+        data["rendered"].as_str().unwrap()
+    );
+}
+
+// =============================================================================
+// Tests for G.TOOLREQLIXE.5: Renderings
+// =============================================================================
+
+/// Test: CSV rendering quotes fields and joins references
+/// Precondition: System has a row with a comma and quotes in the title and two code references
+/// Action: Call render_matrix_csv
+/// Result: Title quoted with doubled quotes, references joined with "; "
+/// Covers Requirement: G.TOOLREQLIXE.5
+#[test]
+fn test_render_matrix_csv() {
+    assert_eq!(
+        render_matrix_csv(&sample_matrix()).lines().nth(1).unwrap(),
+        "G.R.1,\"A | \"\"B\"\", <C>\",general,Rules,tested,src/a.rs:1; src/b.rs:2,,failing"
+    );
+}
+
+/// Test: markdown rendering escapes pipes and marks empty references
+/// Precondition: System has a row with a pipe in the title and no test references
+/// Action: Call render_matrix_markdown
+/// Result: Heading, verification line, header and a row with escaped pipe, "<br>" and "-"
+/// Covers Requirement: G.TOOLREQLIXE.5
+#[test]
+fn test_render_matrix_markdown() {
+    let rendered = render_matrix_markdown(&sample_matrix());
+
+    assert!(rendered.starts_with(
+        "# Requirements Traceability Matrix\n\nVerification imported at: 2026-10-18T09:30:00Z\n\n\
+| Index | Title | Category | Chapter | Status | Code references | Test references | Verification |\n"
+    ));
+    assert!(rendered.ends_with(
+        "| G.R.1 | A \\| \"B\", <C> | general | Rules | tested | src/a.rs:1<br>src/b.rs:2 | - | failing |\n"
+    ));
+    let mut matrix = sample_matrix();
+    matrix.verification_imported_at = None;
+    assert!(render_matrix_markdown(&matrix).contains("\nVerification: not imported\n"));
+}
+
+/// Test: HTML rendering is a self-contained escaped page
+/// Precondition: System has a row with markup characters in the title
+/// Action: Call render_matrix_html
+/// Result: HTML5 document with inline styles, no scripts or external resources, escaped text and a row class
+/// Covers Requirement: G.TOOLREQLIXE.5
+#[test]
+fn test_render_matrix_html() {
+    let rendered = render_matrix_html(&sample_matrix());
+
+    assert!(rendered.starts_with("<!DOCTYPE html>\n"));
+    assert!(rendered.contains("<meta charset=\"utf-8\">"));
+    assert!(rendered.contains("<title>Requirements Traceability Matrix</title>"));
+    assert!(rendered.contains("<style>"));
+    assert!(!rendered.contains("<script"));
+    assert!(!rendered.contains("http"));
+    assert!(rendered.contains(
+        "<tr class=\"verification-failing\"><td>G.R.1</td><td>A | &quot;B&quot;, &lt;C&gt;</td>"
+    ));
+    assert!(rendered.contains("<td>src/a.rs:1<br>src/b.rs:2</td><td>-</td>"));
+    assert_eq!(html_escape("a&'b'"), "a&amp;&#39;b&#39;");
+}
+
+// =============================================================================
+// Tests for G.TOOLREQLIXE.6: Parameter validation
+// =============================================================================
+
+/// Test: export validates format before output
+/// Precondition: System has a project
+/// Action: Export with format "pdf" and empty output, then format "csv" and empty output
+/// Result: Format error, then "output must not be empty"
+/// Covers Requirement: G.TOOLREQLIXE.6
+#[test]
+fn test_export_matrix_validation_order() {
+    let temp_dir = create_project();
+
+    let json = export(&temp_dir, "pdf", Some(""));
+    assert_eq!(json["error"], "format must be one of: csv, markdown, html");
+
+    let json = export(&temp_dir, "csv", Some(""));
+    assert_eq!(json["error"], "output must not be empty");
+}
+
+/// Test: export rejects output paths outside the project
+/// Precondition: System has a project and a directory outside it
+/// Action: Export with output "../matrix.csv", an absolute path and (on Unix) a path through a symbolic link to the
+///         outside directory
+/// Result: Each is rejected with "output must be a relative path inside the project: {output}" and nothing is written
+/// Covers Requirement: G.TOOLREQLIXE.6, G.P.6
+#[test]
+fn test_export_matrix_rejects_output_outside_project() {
+    let outer = TempDir::new().unwrap();
+    let temp_dir = create_project();
+
+    let absolute = outer
+        .path()
+        .join("matrix.csv")
+        .to_string_lossy()
+        .to_string();
+    for output in [
+        "../matrix.csv",
+        "target/../../matrix.csv",
+        absolute.as_str(),
+    ] {
+        let json = export(&temp_dir, "csv", Some(output));
+        assert_eq!(
+            json["error"],
+            format!(
+                "output must be a relative path inside the project: {}",
+                output
+            )
+        );
+    }
+
+    #[cfg(unix)]
+    {
+        std::os::unix::fs::symlink(outer.path(), temp_dir.path().join("out")).unwrap();
+        let json = export(&temp_dir, "csv", Some("out/new/matrix.csv"));
+        assert_eq!(
+            json["error"],
+            "output must be a relative path inside the project: out/new/matrix.csv"
+        );
+    }
+    assert_eq!(fs::read_dir(outer.path()).unwrap().count(), 0);
+}
@@ -0,0 +1,252 @@
+// Tests for Tool: reqlix_find_duplicates (G.TOOLREQLIXF.*)
+// Covers Requirements: G.TOOLREQLIXF.2, G.TOOLREQLIXF.3, G.TOOLREQLIXF.4, G.TOOLREQLIXF.5
+
+use reqlix::{FindDuplicatesParams, RequirementsServer};
+use serde_json::Value;
+use tempfile::TempDir;
+
+use super::common::{
+    create_agents_file_in_req_dir, create_category_file_in_req_dir, create_requirements_dir,
+    parse_response,
+};
+
+/// Run reqlix_find_duplicates against a temp project
+fn find_duplicates(temp_dir: &TempDir, threshold: Option<f64>) -> Value {
+    let params = FindDuplicatesParams {
+        project_root: temp_dir.path().to_string_lossy().to_string(),
+        operation_description: "test".to_string(),
+        threshold,
+    };
+    parse_response(&RequirementsServer::handle_find_duplicates(params))
+}
+
+// =============================================================================
+// Tests for G.TOOLREQLIXF.3: Detection algorithm
+// =============================================================================
+
+// Tests for tokenize (G.TOOLREQLIXF.3)
+
+/// Test: tokenize splits on non-alphanumeric characters and lowercases
+/// Precondition: System has text with punctuation, mixed case and digits
+/// Action: Call tokenize
+/// Result: Function returns lowercase alphanumeric tokens
+/// Covers Requirement: G.TOOLREQLIXF.3
+#[test]
+fn test_tokenize_splits_and_lowercases() {
+    let tokens = RequirementsServer::tokenize("Use HTTP2, not http/1.1!");
+    assert_eq!(tokens, vec!["use", "http2", "not", "http", "1", "1"]);
+}
+
+// Tests for handle_find_duplicates (G.TOOLREQLIXF.3)
+
+/// Test: find_duplicates reports repeated index
+/// Precondition: System has a chapter with two headings using the same index
+/// Action: Call handle_find_duplicates
+/// Result: duplicate_indices contains the index with both occurrences
+/// Covers Requirement: G.TOOLREQLIXF.3, G.TOOLREQLIXF.4
+#[test]
+fn test_find_duplicates_duplicate_index() {
+    let temp_dir = TempDir::new().unwrap();
+    let req_dir = create_requirements_dir(&temp_dir);
+    create_agents_file_in_req_dir(&req_dir, "# Instructions\n");
+    create_category_file_in_req_dir(
+        &req_dir,
+        "general",
+        "# General\n\n## G.G.1: First\n\nAlpha.\n\n## G.G.2: Second\n\nBeta.\n\n## G.G.2: Third\n\nGamma.\n",
+    );
+
+    let json = find_duplicates(&temp_dir, None);
+
+    assert!(json["success"].as_bool().unwrap());
+    let duplicates = json["data"]["duplicate_indices"].as_array().unwrap();
+    assert_eq!(duplicates.len(), 1);
+    assert_eq!(duplicates[0]["index"], "G.G.2");
+    let occurrences = duplicates[0]["occurrences"].as_array().unwrap();
+    assert_eq!(occurrences.len(), 2);
+    assert_eq!(occurrences[0]["title"], "Second");
+    assert_eq!(occurrences[1]["title"], "Third");
+}
+
+/// Test: find_duplicates ignores headings inside code blocks
+/// Precondition: System has a requirement whose body contains a code block with a requirement-like heading
+/// Action: Call handle_find_duplicates
+/// Result: No duplicate indices or titles are reported
+/// Covers Requirement: G.TOOLREQLIXF.3
+#[test]
+fn test_find_duplicates_ignores_code_blocks() {
+    let temp_dir = TempDir::new().unwrap();
+    let req_dir = create_requirements_dir(&temp_dir);
+    create_agents_file_in_req_dir(&req_dir, "# Instructions\n");
+    create_category_file_in_req_dir(
+        &req_dir,
+        "general",
+        "# General\n\n## G.G.1: Example\n\n```markdown\n## G.G.1: Example\n```\n",
+    );
+
+    let json = find_duplicates(&temp_dir, None);
+
+    assert!(json["data"]["duplicate_indices"]
+        .as_array()
+        .unwrap()
+        .is_empty());
+    assert!(json["data"]["duplicate_titles"]
+        .as_array()
+        .unwrap()
+        .is_empty());
+}
+
+/// Test: find_duplicates reports same title across categories
+/// Precondition: System has two categories, each with a requirement titled "Login"
+/// Action: Call handle_find_duplicates
+/// Result: duplicate_titles contains "Login" with both requirements
+/// Covers Requirement: G.TOOLREQLIXF.3, G.TOOLREQLIXF.4
+#[test]
+fn test_find_duplicates_duplicate_title_across_categories() {
+    let temp_dir = TempDir::new().unwrap();
+    let req_dir = create_requirements_dir(&temp_dir);
+    create_agents_file_in_req_dir(&req_dir, "# Instructions\n");
+    create_category_file_in_req_dir(
+        &req_dir,
+        "general",
+        "# Security\n\n## G.S.1: Login\n\nUsers log in with email.\n",
+    );
+    create_category_file_in_req_dir(
+        &req_dir,
+        "testing",
+        "# Unit Tests\n\n## T.U.1: Login\n\nCover the login form.\n\n## T.U.2: login\n\nLowercase.\n",
+    );
+
+    let json = find_duplicates(&temp_dir, None);
+
+    let titles = json["data"]["duplicate_titles"].as_array().unwrap();
+    assert_eq!(titles.len(), 1);
+    assert_eq!(titles[0]["title"], "Login");
+    let requirements = titles[0]["requirements"].as_array().unwrap();
+    assert_eq!(requirements[0]["index"], "G.S.1");
+    assert_eq!(requirements[1]["index"], "T.U.1");
+    assert_eq!(requirements[1]["chapter"], "Unit Tests");
+}
+
+/// Test: find_duplicates reports near-duplicate texts
+/// Precondition: System has two requirements in different chapters with almost identical text and one unrelated
+/// Action: Call handle_find_duplicates with default threshold
+/// Result: near_duplicates contains exactly the similar pair with similarity >= 0.8
+/// Covers Requirement: G.TOOLREQLIXF.3, G.TOOLREQLIXF.4
+#[test]
+fn test_find_duplicates_near_duplicate_texts() {
+    let temp_dir = TempDir::new().unwrap();
+    let req_dir = create_requirements_dir(&temp_dir);
+    create_agents_file_in_req_dir(&req_dir, "# Instructions\n");
+    create_category_file_in_req_dir(
+        &req_dir,
+        "general",
+        "# Security\n\n## G.S.1: Password storage\n\nPasswords must be hashed with bcrypt before they are stored.\n\n\
+         # Accounts\n\n## G.A.1: Stored passwords\n\nPasswords must be hashed with bcrypt before they are saved.\n\n\
+         ## G.A.2: Email\n\nEvery account has exactly one email address.\n",
+    );
+
+    let json = find_duplicates(&temp_dir, None);
+
+    let pairs = json["data"]["near_duplicates"].as_array().unwrap();
+    assert_eq!(pairs.len(), 1);
+    assert_eq!(pairs[0]["first"]["index"], "G.S.1");
+    assert_eq!(pairs[0]["second"]["index"], "G.A.1");
+    assert!(pairs[0]["similarity"].as_f64().unwrap() >= 0.8);
+}
+
+/// Test: find_duplicates respects threshold
+/// Precondition: System has two requirements sharing a few words
+/// Action: Call handle_find_duplicates with threshold 1.0 and with threshold 0.1
+/// Result: Pair is not reported at 1.0 but is reported at 0.1
+/// Covers Requirement: G.TOOLREQLIXF.2, G.TOOLREQLIXF.3
+#[test]
+fn test_find_duplicates_threshold() {
+    let temp_dir = TempDir::new().unwrap();
+    let req_dir = create_requirements_dir(&temp_dir);
+    create_agents_file_in_req_dir(&req_dir, "# Instructions\n");
+    create_category_file_in_req_dir(
+        &req_dir,
+        "general",
+        "# General\n\n## G.G.1: One\n\nThe server logs every request.\n\n\
+         ## G.G.2: Two\n\nThe server rejects unknown tools.\n",
+    );
+
+    let strict = find_duplicates(&temp_dir, Some(1.0));
+    assert!(strict["data"]["near_duplicates"]
+        .as_array()
+        .unwrap()
+        .is_empty());
+    assert_eq!(strict["data"]["threshold"], 1.0);
+
+    let loose = find_duplicates(&temp_dir, Some(0.1));
+    assert_eq!(
+        loose["data"]["near_duplicates"].as_array().unwrap().len(),
+        1
+    );
+}
+
+/// Test: find_duplicates with no categories
+/// Precondition: System has requirements directory without category files
+/// Action: Call handle_find_duplicates
+/// Result: Function returns success with three empty arrays
+/// Covers Requirement: G.TOOLREQLIXF.4
+#[test]
+fn test_find_duplicates_empty() {
+    let temp_dir = TempDir::new().unwrap();
+    let req_dir = create_requirements_dir(&temp_dir);
+    create_agents_file_in_req_dir(&req_dir, "# Instructions\n");
+
+    let json = find_duplicates(&temp_dir, None);
+
+    assert!(json["success"].as_bool().unwrap());
+    assert_eq!(json["data"]["threshold"], 0.8);
+    assert!(json["data"]["duplicate_indices"]
+        .as_array()
+        .unwrap()
+        .is_empty());
+    assert!(json["data"]["duplicate_titles"]
+        .as_array()
+        .unwrap()
+        .is_empty());
+    assert!(json["data"]["near_duplicates"]
+        .as_array()
+        .unwrap()
+        .is_empty());
+}
+
+// =============================================================================
+// Tests for G.TOOLREQLIXF.5: Parameter validation
+// =============================================================================
+
+/// Test: validate_threshold accepts boundaries and rejects out-of-range values
+/// Precondition: System has threshold values 0.0, 1.0, -0.1, 1.5 and NaN
+/// Action: Call validate_threshold with each value
+/// Result: Boundaries are accepted, other values return error
+/// Covers Requirement: G.TOOLREQLIXF.5
+#[test]
+fn test_validate_threshold_range() {
+    assert!(RequirementsServer::validate_threshold(0.0).is_ok());
+    assert!(RequirementsServer::validate_threshold(1.0).is_ok());
+    assert!(RequirementsServer::validate_threshold(-0.1).is_err());
+    assert!(RequirementsServer::validate_threshold(1.5).is_err());
+    assert!(RequirementsServer::validate_threshold(f64::NAN).is_err());
+}
+
+/// Test: find_duplicates rejects invalid threshold before file system access
+/// Precondition: System has no requirements directory
+/// Action: Call handle_find_duplicates with threshold 2.0
+/// Result: Function returns threshold error and does not create AGENTS.md
+/// Covers Requirement: G.TOOLREQLIXF.5
+#[test]
+fn test_find_duplicates_invalid_threshold() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let json = find_duplicates(&temp_dir, Some(2.0));
+
+    assert!(!json["success"].as_bool().unwrap());
+    assert_eq!(json["error"], "threshold must be between 0.0 and 1.0");
+    assert!(!temp_dir
+        .path()
+        .join("docs/development/requirements/AGENTS.md")
+        .exists());
+}
@@ -1,10 +1,11 @@
 // Tests for Tool: reqlix_get_categories (G.REQLIX_GET_CA.*)
-// Covers Requirements: G.REQLIX_GET_CA.1, G.REQLIX_GET_CA.3
+// Covers Requirements: G.REQLIX_GET_CA.1, G.REQLIX_GET_CA.3, G.C.8
 
-use reqlix::RequirementsServer;
+use reqlix::{GetCategoriesParams, RequirementsServer};
+use std::fs;
 use tempfile::TempDir;
 
-use super::common::{create_agents_file, create_category_file};
+use super::common::{create_agents_file, create_category_file, parse_response};
 
 // =============================================================================
 // Tests for reqlix_get_categories (G.REQLIX_GET_CA.*)
@@ -43,3 +44,24 @@ fn test_get_categories_empty() {
     let categories = RequirementsServer::list_categories(&temp_dir.path().to_path_buf()).unwrap();
     assert_eq!(categories.len(), 0);
 }
+
+/// Test: reqlix_get_categories does not create the requirements tree
+/// Precondition: Project has no AGENTS.md
+/// Action: Call reqlix_get_categories
+/// Result: Error "Requirements directory not found", and the project stays empty
+/// Covers Requirement: G.C.8, G.S.3
+#[test]
+fn test_get_categories_without_requirements() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let response = parse_response(&RequirementsServer::handle_get_categories(
+        GetCategoriesParams {
+            project_root: temp_dir.path().to_string_lossy().to_string(),
+            operation_description: "Listing categories".to_string(),
+        },
+    ));
+
+    assert_eq!(response["success"], false);
+    assert_eq!(response["error"], "Requirements directory not found");
+    assert_eq!(fs::read_dir(temp_dir.path()).unwrap().count(), 0);
+}
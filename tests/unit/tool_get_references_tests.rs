@@ -0,0 +1,125 @@
+// Tests for Tool: reqlix_get_references (G.TOOLREQLIXGETREF.*)
+// Covers Requirements: G.TOOLREQLIXGETREF.2, G.TOOLREQLIXGETREF.3, G.TOOLREQLIXGETREF.4, G.TOOLREQLIXGETREF.5
+
+use reqlix::{GetReferencesParams, RequirementsServer};
+use serde_json::Value;
+use tempfile::TempDir;
+
+use super::common::{
+    create_agents_file_in_req_dir, create_category_file_in_req_dir, create_requirements_dir,
+    parse_response,
+};
+
+/// Create a project where G.R.9 links to G.C.2 and G.C.1 links to G.R.9
+fn setup_project(temp_dir: &TempDir) {
+    let req_dir = create_requirements_dir(temp_dir);
+    create_agents_file_in_req_dir(&req_dir, "# Instructions\n");
+    create_category_file_in_req_dir(
+        &req_dir,
+        "general",
+        "# Configuration\n\n## G.C.1: Location\n\nErrors follow G.R.9.\n\n\
+## G.C.2: Directory creation\n\nDirectories are created.\n\n\
+# Requirements\n\n## G.R.9: File system error handling\n\n\
+Create directories (see [G.C.2](#gc2-directory-creation)).\n",
+    );
+}
+
+/// Run reqlix_get_references against a temp project
+fn get_references(temp_dir: &TempDir, index: &str) -> Value {
+    let params = GetReferencesParams {
+        project_root: temp_dir.path().to_string_lossy().to_string(),
+        operation_description: "test".to_string(),
+        index: index.to_string(),
+    };
+    parse_response(&RequirementsServer::handle_get_references(params))
+}
+
+// =============================================================================
+// Tests for G.TOOLREQLIXGETREF.3: Algorithm
+// =============================================================================
+
+/// Test: get_references returns outgoing links and backlinks
+/// Precondition: System has G.R.9 linking to G.C.2, and G.C.1 linking to G.R.9
+/// Action: Call handle_get_references for G.R.9
+/// Result: Requirement metadata, outgoing [G.C.2] and backlinks [G.C.1]
+/// Covers Requirement: G.TOOLREQLIXGETREF.3, G.TOOLREQLIXGETREF.4
+#[test]
+fn test_get_references_success() {
+    let temp_dir = TempDir::new().unwrap();
+    setup_project(&temp_dir);
+
+    let json = get_references(&temp_dir, "G.R.9");
+
+    assert!(json["success"].as_bool().unwrap());
+    let data = &json["data"];
+    assert_eq!(data["index"], "G.R.9");
+    assert_eq!(data["title"], "File system error handling");
+    assert_eq!(data["category"], "general");
+    assert_eq!(data["chapter"], "Requirements");
+    assert_eq!(
+        data["outgoing"],
+        serde_json::json!([{
+            "index": "G.C.2",
+            "title": "Directory creation",
+            "category": "general",
+            "chapter": "Configuration"
+        }])
+    );
+    assert_eq!(data["backlinks"][0]["index"], "G.C.1");
+    assert_eq!(data["backlinks"].as_array().unwrap().len(), 1);
+}
+
+/// Test: get_references for a requirement without links
+/// Precondition: System has G.C.2 with no outgoing links and a backlink from G.R.9
+/// Action: Call handle_get_references for G.C.2
+/// Result: outgoing is empty, backlinks is [G.R.9]
+/// Covers Requirement: G.TOOLREQLIXGETREF.3
+#[test]
+fn test_get_references_backlinks_only() {
+    let temp_dir = TempDir::new().unwrap();
+    setup_project(&temp_dir);
+
+    let json = get_references(&temp_dir, "G.C.2");
+
+    assert!(json["data"]["outgoing"].as_array().unwrap().is_empty());
+    assert_eq!(json["data"]["backlinks"][0]["index"], "G.R.9");
+}
+
+/// Test: get_references for an unknown index
+/// Precondition: System has no requirement G.C.9
+/// Action: Call handle_get_references for G.C.9
+/// Result: Function returns error "Requirement not found"
+/// Covers Requirement: G.TOOLREQLIXGETREF.3
+#[test]
+fn test_get_references_not_found() {
+    let temp_dir = TempDir::new().unwrap();
+    setup_project(&temp_dir);
+
+    let json = get_references(&temp_dir, "G.C.9");
+
+    assert!(!json["success"].as_bool().unwrap());
+    assert_eq!(json["error"], "Requirement not found");
+}
+
+// =============================================================================
+// Tests for G.TOOLREQLIXGETREF.5: Parameter validation
+// =============================================================================
+
+/// Test: get_references validates index
+/// Precondition: System has empty and oversized index values
+/// Action: Call handle_get_references
+/// Result: Function returns index validation errors
+/// Covers Requirement: G.TOOLREQLIXGETREF.5
+#[test]
+fn test_get_references_validation() {
+    let temp_dir = TempDir::new().unwrap();
+    setup_project(&temp_dir);
+
+    let json = get_references(&temp_dir, "");
+    assert!(!json["success"].as_bool().unwrap());
+    assert!(json["error"].as_str().unwrap().contains("index"));
+
+    let json = get_references(&temp_dir, &"G".repeat(101));
+    assert!(!json["success"].as_bool().unwrap());
+    assert!(json["error"].as_str().unwrap().contains("index"));
+}
@@ -412,13 +412,17 @@ Old two.
                 index: "G.C.2".to_string(),
                 text: "New two".to_string(),
                 title: None,
+                relations: None,
             },
             reqlix::UpdateItem {
                 index: "G.C.1".to_string(),
                 text: "New one".to_string(),
                 title: None,
+                relations: None,
             },
         ]),
+        relations: None,
+        rewrite_links: None,
     };
     let result = RequirementsServer::handle_update_requirement(params);
     let parsed: serde_json::Value = serde_json::from_str(&result).unwrap();
@@ -453,6 +457,7 @@ Content two.
         project_root: temp_dir.path().to_string_lossy().to_string(),
         operation_description: "Test".to_string(),
         index: reqlix::IndexParam::Batch(vec!["G.C.2".to_string(), "G.C.1".to_string()]),
+        force: None,
     };
     let result = RequirementsServer::handle_delete_requirement(params);
     let parsed: serde_json::Value = serde_json::from_str(&result).unwrap();
@@ -550,6 +555,7 @@ fn test_batch_delete_same_index_twice() {
         project_root: temp_dir.path().to_string_lossy().to_string(),
         operation_description: "Test".to_string(),
         index: reqlix::IndexParam::Batch(vec!["G.C.1".to_string(), "G.C.1".to_string()]),
+        force: None,
     };
     let result = RequirementsServer::handle_delete_requirement(params);
     let parsed: serde_json::Value = serde_json::from_str(&result).unwrap();
@@ -586,13 +592,17 @@ fn test_batch_update_title_conflict_in_batch() {
                 index: "G.C.1".to_string(),
                 text: "New content".to_string(),
                 title: Some("Conflict Title".to_string()),
+                relations: None,
             },
             reqlix::UpdateItem {
                 index: "G.C.2".to_string(),
                 text: "New content".to_string(),
                 title: Some("Conflict Title".to_string()), // Will conflict with G.C.1's new title
+                relations: None,
             },
         ]),
+        relations: None,
+        rewrite_links: None,
     };
     let result = RequirementsServer::handle_update_requirement(params);
     let parsed: serde_json::Value = serde_json::from_str(&result).unwrap();
@@ -626,6 +636,7 @@ fn test_batch_delete_partial_chapter() {
         project_root: temp_dir.path().to_string_lossy().to_string(),
         operation_description: "Test".to_string(),
         index: reqlix::IndexParam::Batch(vec!["G.C.1".to_string(), "G.C.3".to_string()]),
+        force: None,
     };
     let result = RequirementsServer::handle_delete_requirement(params);
     let parsed: serde_json::Value = serde_json::from_str(&result).unwrap();
@@ -694,13 +705,17 @@ fn test_batch_update_empty_text() {
                 index: "G.C.1".to_string(),
                 text: "Valid text".to_string(),
                 title: None,
+                relations: None,
             },
             reqlix::UpdateItem {
                 index: "G.C.2".to_string(),
                 text: "".to_string(), // Empty text - invalid
                 title: None,
+                relations: None,
             },
         ]),
+        relations: None,
+        rewrite_links: None,
     };
     let result = RequirementsServer::handle_update_requirement(params);
     let parsed: serde_json::Value = serde_json::from_str(&result).unwrap();
@@ -730,6 +745,7 @@ fn test_batch_delete_nonexistent_category() {
             "G.C.1".to_string(),
             "X.C.1".to_string(), // Non-existent category
         ]),
+        force: None,
     };
     let result = RequirementsServer::handle_delete_requirement(params);
     let parsed: serde_json::Value = serde_json::from_str(&result).unwrap();
@@ -880,13 +896,17 @@ fn test_batch_update_title_too_long() {
                 index: "G.C.1".to_string(),
                 text: "Valid text".to_string(),
                 title: None,
+                relations: None,
             },
             reqlix::UpdateItem {
                 index: "G.C.2".to_string(),
                 text: "Valid text".to_string(),
                 title: Some(long_title),
+                relations: None,
             },
         ]),
+        relations: None,
+        rewrite_links: None,
     };
     let result = RequirementsServer::handle_update_requirement(params);
     let parsed: serde_json::Value = serde_json::from_str(&result).unwrap();
@@ -918,6 +938,7 @@ fn test_batch_delete_multiple_categories() {
         project_root: temp_dir.path().to_string_lossy().to_string(),
         operation_description: "Test".to_string(),
         index: reqlix::IndexParam::Batch(vec!["G.C.1".to_string(), "T.C.1".to_string()]),
+        force: None,
     };
     let result = RequirementsServer::handle_delete_requirement(params);
     let parsed: serde_json::Value = serde_json::from_str(&result).unwrap();
@@ -991,13 +1012,17 @@ fn test_batch_update_changes_file_content() {
                 index: "G.C.1".to_string(),
                 text: "UPDATED ONE".to_string(),
                 title: None,
+                relations: None,
             },
             reqlix::UpdateItem {
                 index: "G.C.2".to_string(),
                 text: "UPDATED TWO".to_string(),
                 title: None,
+                relations: None,
             },
         ]),
+        relations: None,
+        rewrite_links: None,
     };
     let result = RequirementsServer::handle_update_requirement(params);
     let parsed: serde_json::Value = serde_json::from_str(&result).unwrap();
@@ -1028,6 +1053,7 @@ fn test_batch_delete_removes_empty_chapter() {
         project_root: temp_dir.path().to_string_lossy().to_string(),
         operation_description: "Test".to_string(),
         index: reqlix::IndexParam::Batch(vec!["G.CHAPTERONE.1".to_string()]),
+        force: None,
     };
     let result = RequirementsServer::handle_delete_requirement(params);
     let parsed: serde_json::Value = serde_json::from_str(&result).unwrap();
@@ -1097,6 +1123,8 @@ fn test_single_update_response_format() {
         text: Some("New content".to_string()),
         title: None,
         items: None,
+        relations: None,
+        rewrite_links: None,
     };
     let result = RequirementsServer::handle_update_requirement(params);
     let parsed: serde_json::Value = serde_json::from_str(&result).unwrap();
@@ -1124,6 +1152,7 @@ fn test_single_delete_response_format() {
         project_root: temp_dir.path().to_string_lossy().to_string(),
         operation_description: "Test".to_string(),
         index: reqlix::IndexParam::Single("G.C.1".to_string()),
+        force: None,
     };
     let result = RequirementsServer::handle_delete_requirement(params);
     let parsed: serde_json::Value = serde_json::from_str(&result).unwrap();
@@ -1180,13 +1209,17 @@ fn test_batch_update_first_item_error() {
                 index: "G.C.1".to_string(), // Does not exist
                 text: "New".to_string(),
                 title: None,
+                relations: None,
             },
             reqlix::UpdateItem {
                 index: "G.C.2".to_string(),
                 text: "Updated".to_string(),
                 title: None,
+                relations: None,
             },
         ]),
+        relations: None,
+        rewrite_links: None,
     };
     let result = RequirementsServer::handle_update_requirement(params);
     let parsed: serde_json::Value = serde_json::from_str(&result).unwrap();
@@ -0,0 +1,213 @@
+// Tests for Tool: reqlix_impact (G.TOOLREQLIXIM.*)
+// Covers Requirements: G.TOOLREQLIXIM.2, G.TOOLREQLIXIM.3, G.TOOLREQLIXIM.4, G.TOOLREQLIXIM.5
+
+use reqlix::{ImpactParams, ReferenceGraph, RequirementsServer};
+use serde_json::Value;
+use std::fs;
+use tempfile::TempDir;
+
+use super::common::{
+    create_agents_file_in_req_dir, create_category_file_in_req_dir, create_requirements_dir,
+    parse_response,
+};
+
+/// Create a project where G.R.9 depends on G.C.2, T.U.1 verifies G.R.9,
+/// G.C.2 conflicts with G.C.3 and G.C.1 only links to G.C.2
+fn setup_project(temp_dir: &TempDir) {
+    let req_dir = create_requirements_dir(temp_dir);
+    create_agents_file_in_req_dir(&req_dir, "# Instructions\n");
+    create_category_file_in_req_dir(
+        &req_dir,
+        "general",
+        "# Configuration\n\n## G.C.1: Location\n\nSee G.C.2.\n\n\
+## G.C.2: Directory creation\n\nText.\n\n- conflicts_with: G.C.3\n\n\
+## G.C.3: Directory reuse\n\nText.\n\n\
+# Requirements\n\n## G.R.9: File system error handling\n\nText.\n\n- depends_on: G.C.2\n",
+    );
+    create_category_file_in_req_dir(
+        &req_dir,
+        "testing",
+        "# Unit\n\n## T.U.1: Error tests\n\nText.\n\n- verifies: G.R.9\n",
+    );
+    let write = |relative: &str, content: &str| {
+        let path = temp_dir.path().join(relative);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    };
+    write("src/a.rs", "// G.C.2\n// G.C.1\n");
+    write("tests/b.rs", "// T.U.1\n");
+}
+
+/// Run reqlix_impact against a temp project
+fn impact(temp_dir: &TempDir, index: &str, include: Option<Vec<String>>) -> Value {
+    let params = ImpactParams {
+        project_root: temp_dir.path().to_string_lossy().to_string(),
+        operation_description: "test".to_string(),
+        index: index.to_string(),
+        include,
+        exclude: None,
+    };
+    parse_response(&RequirementsServer::handle_impact(params))
+}
+
+/// Affected requirements as "index relation via depth" strings
+fn affected(json: &Value) -> Vec<String> {
+    json["data"]["affected"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|a| {
+            format!(
+                "{} {} {} {}",
+                a["index"], a["relation"], a["via"], a["depth"]
+            )
+        })
+        .map(|s| s.replace('"', ""))
+        .collect()
+}
+
+// =============================================================================
+// Tests for G.TOOLREQLIXIM.3: Algorithm
+// =============================================================================
+
+/// Test: impact walks relations transitively and lists code locations
+/// Precondition: System has G.R.9 depending on G.C.2, T.U.1 verifying G.R.9, G.C.2 conflicting with G.C.3,
+///               a plain link from G.C.1 and source references to G.C.2, G.C.1 and T.U.1
+/// Action: Call handle_impact for G.C.2
+/// Result: G.C.3 and G.R.9 at depth 1, T.U.1 at depth 2, G.C.1 not affected;
+///         locations keep only G.C.2 and T.U.1
+/// Covers Requirement: G.TOOLREQLIXIM.3, G.TOOLREQLIXIM.4
+#[test]
+fn test_impact_transitive() {
+    let temp_dir = TempDir::new().unwrap();
+    setup_project(&temp_dir);
+
+    let json = impact(&temp_dir, "G.C.2", None);
+
+    assert!(json["success"].as_bool().unwrap());
+    let data = &json["data"];
+    assert_eq!(data["index"], "G.C.2");
+    assert_eq!(data["title"], "Directory creation");
+    assert_eq!(data["chapter"], "Configuration");
+    assert_eq!(
+        affected(&json),
+        vec![
+            "G.C.3 conflicts_with G.C.2 1",
+            "G.R.9 depends_on G.C.2 1",
+            "T.U.1 verifies G.R.9 2"
+        ]
+    );
+    assert_eq!(data["affected"][1]["title"], "File system error handling");
+    assert_eq!(data["affected"][1]["category"], "general");
+    assert_eq!(data["files_scanned"], 2);
+    assert_eq!(
+        data["locations"],
+        serde_json::json!([
+            {"index": "G.C.2", "file": "src/a.rs", "line": 1},
+            {"index": "T.U.1", "file": "tests/b.rs", "line": 1}
+        ])
+    );
+}
+
+/// Test: only conflicts_with affects the target of a relation
+/// Precondition: System has G.R.9 depending on G.C.2, and G.C.2 conflicting with G.C.3
+/// Action: Call handle_impact for G.R.9 and for G.C.3
+/// Result: G.R.9 affects T.U.1 only; G.C.3 affects G.C.2, then G.R.9 and T.U.1
+/// Covers Requirement: G.TOOLREQLIXIM.3
+#[test]
+fn test_impact_direction() {
+    let temp_dir = TempDir::new().unwrap();
+    setup_project(&temp_dir);
+
+    let json = impact(&temp_dir, "G.R.9", None);
+    assert_eq!(affected(&json), vec!["T.U.1 verifies G.R.9 1"]);
+
+    let json = impact(&temp_dir, "G.C.3", None);
+    assert_eq!(
+        affected(&json),
+        vec![
+            "G.C.2 conflicts_with G.C.3 1",
+            "G.R.9 depends_on G.C.2 2",
+            "T.U.1 verifies G.R.9 3"
+        ]
+    );
+}
+
+/// Test: impact terminates on relation cycles and never lists the starting requirement
+/// Precondition: System has G.A.1 and G.A.2 depending on each other
+/// Action: Call ReferenceGraph::impact for G.A.1
+/// Result: Only G.A.2 is affected
+/// Covers Requirement: G.TOOLREQLIXIM.3
+#[test]
+fn test_impact_cycle() {
+    let temp_dir = TempDir::new().unwrap();
+    let req_dir = create_requirements_dir(&temp_dir);
+    create_category_file_in_req_dir(
+        &req_dir,
+        "general",
+        "# A\n\n## G.A.1: One\n\n- depends_on: G.A.2\n\n## G.A.2: Two\n\n- depends_on: G.A.1\n",
+    );
+
+    let graph = ReferenceGraph::build(&req_dir).unwrap();
+    let affected = graph.impact("G.A.1");
+
+    assert_eq!(affected.len(), 1);
+    assert_eq!(affected[0].requirement.index, "G.A.2");
+    assert_eq!(affected[0].via, "G.A.1");
+}
+
+/// Test: include globs restrict scanned files
+/// Precondition: System has source references in src and tests
+/// Action: Call handle_impact for G.C.2 with include "src/**"
+/// Result: Only the location in src is returned
+/// Covers Requirement: G.TOOLREQLIXIM.2, G.TOOLREQLIXIM.3
+#[test]
+fn test_impact_include() {
+    let temp_dir = TempDir::new().unwrap();
+    setup_project(&temp_dir);
+
+    let json = impact(&temp_dir, "G.C.2", Some(vec!["src/**".to_string()]));
+
+    assert_eq!(json["data"]["files_scanned"], 1);
+    assert_eq!(json["data"]["locations"].as_array().unwrap().len(), 1);
+}
+
+/// Test: impact of a missing requirement
+/// Precondition: System has no requirement G.C.9
+/// Action: Call handle_impact for G.C.9
+/// Result: Error "Requirement not found"
+/// Covers Requirement: G.TOOLREQLIXIM.3
+#[test]
+fn test_impact_not_found() {
+    let temp_dir = TempDir::new().unwrap();
+    setup_project(&temp_dir);
+
+    let json = impact(&temp_dir, "G.C.9", None);
+
+    assert!(!json["success"].as_bool().unwrap());
+    assert_eq!(json["error"], "Requirement not found");
+}
+
+// =============================================================================
+// Tests for G.TOOLREQLIXIM.5: Parameter validation
+// =============================================================================
+
+/// Test: impact validates index before globs
+/// Precondition: System has a project
+/// Action: Call handle_impact with empty index and invalid include, then valid index and invalid include
+/// Result: First error is about index, second about include
+/// Covers Requirement: G.TOOLREQLIXIM.5
+#[test]
+fn test_impact_validation_order() {
+    let temp_dir = TempDir::new().unwrap();
+    setup_project(&temp_dir);
+
+    let json = impact(&temp_dir, "", Some(vec!["[a".to_string()]));
+    assert_eq!(json["error"], "index is required");
+
+    let json = impact(&temp_dir, "G.C.2", Some(vec!["[a".to_string()]));
+    assert!(json["error"]
+        .as_str()
+        .unwrap()
+        .starts_with("Invalid include pattern '[a'"));
+}
@@ -0,0 +1,392 @@
+// Tests for Tool: reqlix_import_test_results (G.TOOLREQLIXIMPO.*)
+// Covers Requirements: G.TOOLREQLIXIMPO.2, G.TOOLREQLIXIMPO.3, G.TOOLREQLIXIMPO.4, G.TOOLREQLIXIMPO.5
+
+use reqlix::{
+    parse_junit, parse_libtest, ImportTestResultsParams, RequirementsServer, TestOutcome,
+};
+use serde_json::Value;
+use std::fs;
+use tempfile::TempDir;
+
+use super::common::{
+    create_agents_file_in_req_dir, create_category_file_in_req_dir, create_requirements_dir,
+    parse_response,
+};
+
+/// Outcomes as "name: result" strings
+fn outcomes(outcomes: Vec<TestOutcome>) -> Vec<String> {
+    outcomes
+        .into_iter()
+        .map(|o| format!("{}: {}", o.name, o.result))
+        .collect()
+}
+
+/// Create a project with requirements G.R.1-G.R.3 and tests covering G.R.1 and G.R.2
+fn create_project() -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    let req_dir = create_requirements_dir(&temp_dir);
+    create_agents_file_in_req_dir(&req_dir, "# Instructions\n");
+    create_category_file_in_req_dir(
+        &req_dir,
+        "general",
+        "# Rules\n\n## G.R.1: One\n\nText.\n\n## G.R.2: Two\n\nText.\n\n## G.R.3: Three\n\nText.\n",
+    );
+    let tests = temp_dir.path().join("tests");
+    fs::create_dir_all(&tests).unwrap();
+    fs::write(
+        tests.join("a.rs"),
+        "/// Covers Requirement: G.R.1\n#[test]\nfn test_one() {}\n\n\
+/// Covers Requirement: G.R.1, G.R.2\n#[test]\nfn test_two() {}\n\n\
+/// Covers Requirement: G.R.3\n#[test]\nfn test_three() {}\n",
+    )
+    .unwrap();
+    temp_dir
+}
+
+/// Run reqlix_import_test_results against a temp project
+fn import(temp_dir: &TempDir, file: &str, format: Option<&str>) -> Value {
+    let params = ImportTestResultsParams {
+        project_root: temp_dir.path().to_string_lossy().to_string(),
+        operation_description: "test".to_string(),
+        file: file.to_string(),
+        format: format.map(|f| f.to_string()),
+        include: None,
+        exclude: None,
+    };
+    parse_response(&RequirementsServer::handle_import_test_results(params))
+}
+
+// =============================================================================
+// Tests for G.TOOLREQLIXIMPO.3: Algorithm
+// =============================================================================
+
+/// Test: parse_junit reads passed, failed, errored and skipped test cases
+/// Precondition: System has a JUnit report with nested test suites, one test case with a classname
+/// Action: Call parse_junit
+/// Result: Every testcase with its result, in document order; the classname is prepended with `::`
+/// Covers Requirement: G.TOOLREQLIXIMPO.3
+#[test]
+fn test_parse_junit() {
+    let content = r#"<?xml version="1.0" encoding="UTF-8"?>
+<testsuites>
+  <testsuite name="unit">
+    <testcase name="a::test_pass" classname="unit"/>
+    <testcase name="test_fail"><failure message="assertion failed"/></testcase>
+    <testcase name="test_error"><error/></testcase>
+    <testcase name="test_skip"><skipped/></testcase>
+  </testsuite>
+</testsuites>
+"#;
+
+    assert_eq!(
+        outcomes(parse_junit(content).unwrap()),
+        vec![
+            "unit::a::test_pass: passed",
+            "test_fail: failed",
+            "test_error: failed",
+            "test_skip: skipped"
+        ]
+    );
+}
+
+/// Test: parse_junit rejects malformed XML
+/// Precondition: System has an unclosed element
+/// Action: Call parse_junit
+/// Result: Error starting with "Invalid JUnit XML: "
+/// Covers Requirement: G.TOOLREQLIXIMPO.3
+#[test]
+fn test_parse_junit_invalid() {
+    assert!(parse_junit("<testsuite><testcase name=\"a\">")
+        .unwrap_err()
+        .starts_with("Invalid JUnit XML: "));
+}
+
+/// Test: parse_libtest reads test events and ignores other lines
+/// Precondition: System has libtest JSON with suite events, compiler noise and all test events
+/// Action: Call parse_libtest
+/// Result: Finished tests with their results, started events and noise skipped
+/// Covers Requirement: G.TOOLREQLIXIMPO.3
+#[test]
+fn test_parse_libtest() {
+    let content = "   Compiling reqlix v0.1.0\n\
+{ \"type\": \"suite\", \"event\": \"started\", \"test_count\": 4 }\n\
+{ \"type\": \"test\", \"event\": \"started\", \"name\": \"unit::test_a\" }\n\
+{ \"type\": \"test\", \"name\": \"unit::test_a\", \"event\": \"ok\" }\n\
+{ \"type\": \"test\", \"name\": \"test_b\", \"event\": \"failed\", \"stdout\": \"panicked\" }\n\
+{ \"type\": \"test\", \"name\": \"test_c\", \"event\": \"timeout\" }\n\
+{ \"type\": \"test\", \"name\": \"test_d\", \"event\": \"ignored\" }\n\
+not json\n";
+
+    assert_eq!(
+        outcomes(parse_libtest(content)),
+        vec![
+            "unit::test_a: passed",
+            "test_b: failed",
+            "test_c: failed",
+            "test_d: skipped"
+        ]
+    );
+}
+
+/// Test: import classifies requirements and stores verification.json
+/// Precondition: System has G.R.1 covered by two passing tests, G.R.2 by a failing one, G.R.3 by a test without result
+/// Action: Import a JUnit report without format
+/// Result: G.R.1 verified, G.R.2 failing, G.R.3 unverified; the stored file equals the response data
+/// Covers Requirement: G.TOOLREQLIXIMPO.3, G.TOOLREQLIXIMPO.4
+#[test]
+fn test_import_classifies_requirements() {
+    let temp_dir = create_project();
+    fs::write(
+        temp_dir.path().join("junit.xml"),
+        "  <testsuite>\n<testcase name=\"a::test_one\"/>\n<testcase name=\"b::test_two\"/>\n\
+<testcase name=\"c::test_two\"><failure/></testcase>\n<testcase name=\"test_unknown\"/>\n</testsuite>\n",
+    )
+    .unwrap();
+
+    let json = import(&temp_dir, "junit.xml", None);
+
+    assert!(json["success"].as_bool().unwrap());
+    let data = &json["data"];
+    assert_eq!(data["format"], "junit");
+    assert_eq!(data["tests_found"], 4);
+    assert_eq!(data["tests_matched"], 2);
+    assert_eq!(
+        data["summary"],
+        serde_json::json!({"verified": 0, "failing": 2, "unverified": 1})
+    );
+    assert_eq!(data["requirements"][0]["status"], "failing");
+    assert_eq!(data["requirements"][0]["tests"][0]["result"], "passed");
+    assert_eq!(data["requirements"][0]["tests"][1]["result"], "failed");
+    assert_eq!(data["requirements"][2]["status"], "unverified");
+    assert_eq!(data["requirements"][2]["tests"][0]["result"], "missing");
+    let imported_at = data["imported_at"].as_str().unwrap();
+    assert!(regex::Regex::new(r"^\d{4}-\d{2}-\d{2}T\d{2}:\d{2}:\d{2}Z$")
+        .unwrap()
+        .is_match(imported_at));
+
+    let stored: Value = serde_json::from_str(
+        &fs::read_to_string(
+            temp_dir
+                .path()
+                .join("docs/development/requirements/verification.json"),
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(&stored, data);
+}
+
+/// Test: import detects libtest output and marks fully passing requirements verified
+/// Precondition: System has libtest JSON where test_one and test_two pass and test_three is ignored
+/// Action: Import the file without format
+/// Result: Format libtest; G.R.1 and G.R.2 verified, G.R.3 unverified
+/// Covers Requirement: G.TOOLREQLIXIMPO.3
+#[test]
+fn test_import_libtest_verified() {
+    let temp_dir = create_project();
+    fs::write(
+        temp_dir.path().join("results.json"),
+        "{\"type\":\"test\",\"name\":\"unit::test_one\",\"event\":\"ok\"}\n\
+{\"type\":\"test\",\"name\":\"unit::test_two\",\"event\":\"ok\"}\n\
+{\"type\":\"test\",\"name\":\"unit::test_three\",\"event\":\"ignored\"}\n",
+    )
+    .unwrap();
+
+    let json = import(&temp_dir, "results.json", None);
+
+    let data = &json["data"];
+    assert_eq!(data["format"], "libtest");
+    assert_eq!(data["requirements"][0]["status"], "verified");
+    assert_eq!(data["requirements"][1]["status"], "verified");
+    assert_eq!(data["requirements"][2]["status"], "unverified");
+    assert_eq!(
+        data["summary"],
+        serde_json::json!({"verified": 2, "failing": 0, "unverified": 1})
+    );
+}
+
+/// Create a project where tests/a.rs and tests/b.rs both define test_same, covering G.R.1 and G.R.2
+fn create_project_with_same_names() -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    let req_dir = create_requirements_dir(&temp_dir);
+    create_agents_file_in_req_dir(&req_dir, "# Instructions\n");
+    create_category_file_in_req_dir(
+        &req_dir,
+        "general",
+        "# Rules\n\n## G.R.1: One\n\nText.\n\n## G.R.2: Two\n\nText.\n",
+    );
+    let tests = temp_dir.path().join("tests");
+    fs::create_dir_all(&tests).unwrap();
+    for (file, index) in [("a.rs", "G.R.1"), ("b.rs", "G.R.2")] {
+        fs::write(
+            tests.join(file),
+            format!(
+                "/// Covers Requirement: {}\n#[test]\nfn test_same() {{}}\n",
+                index
+            ),
+        )
+        .unwrap();
+    }
+    temp_dir
+}
+
+/// Test: results are matched by module when function names repeat
+/// Precondition: System has test_same in tests/a.rs and tests/b.rs; libtest reports a::test_same passed and
+///               b::test_same failed
+/// Action: Import the file
+/// Result: G.R.1 verified, G.R.2 failing, both tests matched
+/// Covers Requirement: G.TOOLREQLIXIMPO.3
+#[test]
+fn test_import_matches_by_module() {
+    let temp_dir = create_project_with_same_names();
+    fs::write(
+        temp_dir.path().join("results.json"),
+        "{\"type\":\"test\",\"name\":\"a::test_same\",\"event\":\"ok\"}\n\
+{\"type\":\"test\",\"name\":\"b::test_same\",\"event\":\"failed\"}\n",
+    )
+    .unwrap();
+
+    let json = import(&temp_dir, "results.json", None);
+
+    let data = &json["data"];
+    assert_eq!(data["tests_matched"], 2);
+    assert_eq!(data["requirements"][0]["status"], "verified");
+    assert_eq!(data["requirements"][1]["status"], "failing");
+}
+
+/// Test: JUnit classnames select the module
+/// Precondition: System has test_same in tests/a.rs and tests/b.rs; JUnit reports test_same with classnames a and b
+/// Action: Import the file
+/// Result: G.R.1 failing, G.R.2 verified
+/// Covers Requirement: G.TOOLREQLIXIMPO.3
+#[test]
+fn test_import_matches_junit_classname() {
+    let temp_dir = create_project_with_same_names();
+    fs::write(
+        temp_dir.path().join("junit.xml"),
+        "<testsuite>\n<testcase classname=\"a\" name=\"test_same\"><failure/></testcase>\n\
+<testcase classname=\"b\" name=\"test_same\"/>\n</testsuite>\n",
+    )
+    .unwrap();
+
+    let json = import(&temp_dir, "junit.xml", None);
+
+    let data = &json["data"];
+    assert_eq!(data["requirements"][0]["status"], "failing");
+    assert_eq!(data["requirements"][1]["status"], "verified");
+}
+
+/// Test: a bare name does not match test functions that share it
+/// Precondition: System has test_same in tests/a.rs and tests/b.rs; libtest reports test_same without module
+/// Action: Import the file
+/// Result: No test matched, both results missing
+/// Covers Requirement: G.TOOLREQLIXIMPO.3
+#[test]
+fn test_import_ambiguous_name_is_missing() {
+    let temp_dir = create_project_with_same_names();
+    fs::write(
+        temp_dir.path().join("results.json"),
+        "{\"type\":\"test\",\"name\":\"test_same\",\"event\":\"ok\"}\n",
+    )
+    .unwrap();
+
+    let json = import(&temp_dir, "results.json", None);
+
+    let data = &json["data"];
+    assert_eq!(data["tests_matched"], 0);
+    assert_eq!(data["requirements"][0]["tests"][0]["result"], "missing");
+    assert_eq!(data["requirements"][1]["tests"][0]["result"], "missing");
+}
+
+/// Test: explicit format overrides detection
+/// Precondition: System has libtest JSON lines
+/// Action: Import the file with format "junit"
+/// Result: Error starting with "Invalid JUnit XML: "
+/// Covers Requirement: G.TOOLREQLIXIMPO.3
+#[test]
+fn test_import_explicit_format() {
+    let temp_dir = create_project();
+    fs::write(
+        temp_dir.path().join("results.json"),
+        "{\"type\":\"test\",\"name\":\"test_one\",\"event\":\"ok\"}\n",
+    )
+    .unwrap();
+
+    let json = import(&temp_dir, "results.json", Some("junit"));
+
+    assert!(!json["success"].as_bool().unwrap());
+    assert!(json["error"]
+        .as_str()
+        .unwrap()
+        .starts_with("Invalid JUnit XML: "));
+}
+
+// =============================================================================
+// Tests for G.TOOLREQLIXIMPO.5: Parameter validation
+// =============================================================================
+
+/// Test: import validates file before format
+/// Precondition: System has a project
+/// Action: Call handle_import_test_results with empty file and invalid format, then with invalid format only
+/// Result: "file is required", then an error about format
+/// Covers Requirement: G.TOOLREQLIXIMPO.5
+#[test]
+fn test_import_validation_order() {
+    let temp_dir = create_project();
+
+    let json = import(&temp_dir, "", Some("xml"));
+    assert_eq!(json["error"], "file is required");
+
+    let json = import(&temp_dir, "junit.xml", Some("xml"));
+    assert!(json["error"].as_str().unwrap().contains("format"));
+}
+
+/// Test: import rejects results files outside the project
+/// Precondition: System has a project and a results file next to it
+/// Action: Import "../results.json", an absolute path and (on Unix) a symbolic link to the outside file
+/// Result: Each is rejected with "file must be a relative path inside the project: {file}"
+/// Covers Requirement: G.TOOLREQLIXIMPO.5, G.P.6
+#[test]
+fn test_import_rejects_file_outside_project() {
+    let outer = TempDir::new().unwrap();
+    let outside = outer.path().join("results.json");
+    fs::write(
+        &outside,
+        "{\"type\":\"test\",\"name\":\"test_one\",\"event\":\"ok\"}\n",
+    )
+    .unwrap();
+    let temp_dir = create_project();
+
+    let absolute = outside.to_string_lossy().to_string();
+    for file in ["../results.json", absolute.as_str()] {
+        let json = import(&temp_dir, file, None);
+        assert_eq!(
+            json["error"],
+            format!("file must be a relative path inside the project: {}", file)
+        );
+    }
+
+    #[cfg(unix)]
+    {
+        std::os::unix::fs::symlink(&outside, temp_dir.path().join("link.json")).unwrap();
+        let json = import(&temp_dir, "link.json", None);
+        assert_eq!(
+            json["error"],
+            "file must be a relative path inside the project: link.json"
+        );
+    }
+}
+
+/// Test: validate_file enforces the length limit
+/// Precondition: System has file paths of 1000 and 1001 characters
+/// Action: Call validate_file
+/// Result: The first is accepted, the second is rejected
+/// Covers Requirement: G.TOOLREQLIXIMPO.5
+#[test]
+fn test_validate_file_length() {
+    assert!(RequirementsServer::validate_file(&"a".repeat(1000)).is_ok());
+    assert_eq!(
+        RequirementsServer::validate_file(&"a".repeat(1001)).unwrap_err(),
+        "file exceeds maximum length of 1000 characters"
+    );
+}
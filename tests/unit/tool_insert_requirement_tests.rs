@@ -1,12 +1,12 @@
 // Tests for Tool: reqlix_insert_requirement (G.REQLIX_I.*)
-// Covers Requirements: G.REQLIX_I.1, G.REQLIX_I.3, G.REQLIX_I.5
+// Covers Requirements: G.REQLIX_I.1, G.REQLIX_I.2, G.REQLIX_I.3, G.REQLIX_I.5, G.REQLIX_I.7
 
 use reqlix::RequirementsServer;
 use tempfile::TempDir;
 
 use super::common::{
     create_agents_file_in_req_dir, create_category_file, create_category_file_in_req_dir,
-    create_requirements_dir,
+    create_requirements_dir, parse_response,
 };
 
 // =============================================================================
@@ -105,6 +105,8 @@ Content in requirement chapter.
         chapter: "Tool: reqlix_get_requirement".to_string(),
         title: "New Requirement".to_string(),
         text: "New content".to_string(),
+        warn_similar_chapter: None,
+        relations: None,
     };
     let result = RequirementsServer::handle_insert_requirement(params);
     let parsed: serde_json::Value = serde_json::from_str(&result).unwrap();
@@ -183,6 +185,8 @@ More content.
         chapter: "First Chapter".to_string(),
         title: "New Requirement".to_string(),
         text: "New content".to_string(),
+        warn_similar_chapter: None,
+        relations: None,
     };
     let result = RequirementsServer::handle_insert_requirement(params);
     let parsed: serde_json::Value = serde_json::from_str(&result).unwrap();
@@ -197,3 +201,121 @@ More content.
         file_content
     );
 }
+
+// =============================================================================
+// Tests for G.REQLIX_I.7: Similar chapter warning
+// =============================================================================
+
+/// Insert a requirement into a new or existing chapter and return parsed response
+fn insert_into_chapter(
+    temp_dir: &TempDir,
+    category: &str,
+    chapter: &str,
+    warn_similar_chapter: Option<bool>,
+) -> serde_json::Value {
+    let params = reqlix::InsertRequirementParams {
+        project_root: temp_dir.path().to_string_lossy().to_string(),
+        operation_description: "Test insert".to_string(),
+        category: category.to_string(),
+        chapter: chapter.to_string(),
+        title: "New Requirement".to_string(),
+        text: "New content".to_string(),
+        warn_similar_chapter,
+        relations: None,
+    };
+    parse_response(&RequirementsServer::handle_insert_requirement(params))
+}
+
+/// Test: name_similarity normalizes case and separators
+/// Precondition: System has chapter name pairs differing in case, separators, one letter, or entirely
+/// Action: Call name_similarity
+/// Result: Function returns 1.0 for normalized-equal names, 0.9 for one edit in ten characters, low value otherwise
+/// Covers Requirement: G.REQLIX_I.7
+#[test]
+fn test_name_similarity() {
+    assert_eq!(
+        RequirementsServer::name_similarity("Unit Tests", "unit  tests"),
+        1.0
+    );
+    assert_eq!(
+        RequirementsServer::name_similarity("Unit_Tests", "Unit Tests"),
+        1.0
+    );
+    assert!((RequirementsServer::name_similarity("Unit Test", "Unit Tests") - 0.9).abs() < 1e-9);
+    assert!(RequirementsServer::name_similarity("Testing", "Security") < 0.8);
+}
+
+/// Test: insert warns when new chapter is close to existing chapter
+/// Precondition: System has chapter "Unit Tests" in category testing
+/// Action: Insert requirement into new chapter "Unit tests" in category general
+/// Result: Insert succeeds and data contains warning naming the existing chapter and category
+/// Covers Requirement: G.REQLIX_I.5, G.REQLIX_I.7
+#[test]
+fn test_insert_requirement_warns_similar_chapter() {
+    let temp_dir = TempDir::new().unwrap();
+    let req_dir = create_requirements_dir(&temp_dir);
+    create_agents_file_in_req_dir(&req_dir, "# Instructions\n");
+    create_category_file_in_req_dir(
+        &req_dir,
+        "testing",
+        "# Unit Tests\n\n## T.U.1: Parser\n\nTest parser.\n",
+    );
+
+    let json = insert_into_chapter(&temp_dir, "general", "Unit tests", None);
+
+    assert!(json["success"].as_bool().unwrap());
+    assert_eq!(json["data"]["index"], "G.U.1");
+    assert_eq!(
+        json["data"]["warnings"],
+        serde_json::json!([
+            "Chapter \"Unit tests\" is similar to existing chapter \"Unit Tests\" in category \"testing\""
+        ])
+    );
+}
+
+/// Test: insert omits warnings for existing or dissimilar chapters
+/// Precondition: System has chapter "Unit Tests" in category testing
+/// Action: Insert into existing chapter "Unit Tests" and into new chapter "Security"
+/// Result: Neither response contains warnings field
+/// Covers Requirement: G.REQLIX_I.5, G.REQLIX_I.7
+#[test]
+fn test_insert_requirement_no_warning() {
+    let temp_dir = TempDir::new().unwrap();
+    let req_dir = create_requirements_dir(&temp_dir);
+    create_agents_file_in_req_dir(&req_dir, "# Instructions\n");
+    create_category_file_in_req_dir(
+        &req_dir,
+        "testing",
+        "# Unit Tests\n\n## T.U.1: Parser\n\nTest parser.\n",
+    );
+
+    let json = insert_into_chapter(&temp_dir, "testing", "Unit Tests", None);
+    assert!(json["success"].as_bool().unwrap());
+    assert!(json["data"].get("warnings").is_none());
+
+    let json = insert_into_chapter(&temp_dir, "general", "Security", None);
+    assert!(json["success"].as_bool().unwrap());
+    assert!(json["data"].get("warnings").is_none());
+}
+
+/// Test: insert skips similar chapter check when disabled
+/// Precondition: System has chapter "Unit Tests" in category testing
+/// Action: Insert into new chapter "Unit tests" with warn_similar_chapter false
+/// Result: Insert succeeds without warnings
+/// Covers Requirement: G.REQLIX_I.2, G.REQLIX_I.7
+#[test]
+fn test_insert_requirement_similar_chapter_check_disabled() {
+    let temp_dir = TempDir::new().unwrap();
+    let req_dir = create_requirements_dir(&temp_dir);
+    create_agents_file_in_req_dir(&req_dir, "# Instructions\n");
+    create_category_file_in_req_dir(
+        &req_dir,
+        "testing",
+        "# Unit Tests\n\n## T.U.1: Parser\n\nTest parser.\n",
+    );
+
+    let json = insert_into_chapter(&temp_dir, "general", "Unit tests", Some(false));
+
+    assert!(json["success"].as_bool().unwrap());
+    assert!(json["data"].get("warnings").is_none());
+}
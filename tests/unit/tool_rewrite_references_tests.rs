@@ -0,0 +1,225 @@
+// Tests for Tool: reqlix_rewrite_references (G.TOOLREQLIXR.*)
+// Covers Requirements: G.TOOLREQLIXR.3, G.TOOLREQLIXR.4, G.TOOLREQLIXR.5
+
+use reqlix::{rewrite_index_line, IndexMapping, RequirementsServer, RewriteReferencesParams};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fs;
+use tempfile::TempDir;
+
+use super::common::{
+    create_agents_file_in_req_dir, create_category_file_in_req_dir, create_requirements_dir,
+    parse_response,
+};
+
+/// Mappings from (from, to) pairs
+fn mappings(pairs: &[(&str, &str)]) -> Vec<IndexMapping> {
+    pairs
+        .iter()
+        .map(|(from, to)| IndexMapping {
+            from: from.to_string(),
+            to: to.to_string(),
+        })
+        .collect()
+}
+
+/// Create a project citing G.R.1 and G.R.10 in a requirement text and a source file
+fn setup_project(temp_dir: &TempDir) {
+    let req_dir = create_requirements_dir(temp_dir);
+    create_agents_file_in_req_dir(&req_dir, "# Instructions\n");
+    create_category_file_in_req_dir(
+        &req_dir,
+        "general",
+        "# Rules\n\n## G.R.1: First\n\nText.\n\n## G.R.10: Tenth\n\nExtends G.R.1.\n",
+    );
+    let src = temp_dir.path().join("src");
+    fs::create_dir_all(&src).unwrap();
+    fs::write(
+        src.join("lib.rs"),
+        "// G.R.1\r\nfn f() {}\r\n// G.R.10, G.R.1\r\n",
+    )
+    .unwrap();
+    fs::write(src.join("other.rs"), "// nothing\n").unwrap();
+}
+
+/// Run reqlix_rewrite_references against a temp project
+fn rewrite(temp_dir: &TempDir, pairs: &[(&str, &str)], dry_run: Option<bool>) -> Value {
+    let params = RewriteReferencesParams {
+        project_root: temp_dir.path().to_string_lossy().to_string(),
+        operation_description: "test".to_string(),
+        mappings: mappings(pairs),
+        dry_run,
+        include: None,
+        exclude: None,
+    };
+    parse_response(&RequirementsServer::handle_rewrite_references(params))
+}
+
+// =============================================================================
+// Tests for G.TOOLREQLIXR.3: Algorithm
+// =============================================================================
+
+/// Test: rewrite_index_line replaces whole tokens only, all mappings at once
+/// Precondition: System has a mapping that swaps G.R.1 and G.R.2
+/// Action: Call rewrite_index_line on lines with G.R.1, G.R.10, G.R.2 and XG.R.1
+/// Result: G.R.1 and G.R.2 are swapped, G.R.10 and XG.R.1 are untouched
+/// Covers Requirement: G.TOOLREQLIXR.3
+#[test]
+fn test_rewrite_index_line_word_boundaries() {
+    let mapping: HashMap<String, String> = [("G.R.1", "G.R.2"), ("G.R.2", "G.R.1")]
+        .into_iter()
+        .map(|(a, b)| (a.to_string(), b.to_string()))
+        .collect();
+
+    assert_eq!(
+        rewrite_index_line("// G.R.1, G.R.10, G.R.2 and XG.R.1", &mapping),
+        Some(("// G.R.2, G.R.10, G.R.1 and XG.R.1".to_string(), 2))
+    );
+    assert_eq!(rewrite_index_line("// G.R.10", &mapping), None);
+}
+
+/// Test: dry run returns the diff without writing files
+/// Precondition: System cites G.R.1 in a requirement text and a source file with CRLF line endings
+/// Action: Call handle_rewrite_references with G.R.1 -> G.S.1 and dry_run true
+/// Result: Changes and diff list every rewritten line, files are unchanged
+/// Covers Requirement: G.TOOLREQLIXR.3, G.TOOLREQLIXR.4
+#[test]
+fn test_rewrite_references_dry_run() {
+    let temp_dir = TempDir::new().unwrap();
+    setup_project(&temp_dir);
+    let source = temp_dir.path().join("src/lib.rs");
+    let before = fs::read_to_string(&source).unwrap();
+
+    let json = rewrite(&temp_dir, &[("G.R.1", "G.S.1")], Some(true));
+
+    assert!(json["success"].as_bool().unwrap());
+    let data = &json["data"];
+    assert_eq!(data["dry_run"], true);
+    assert_eq!(data["files_changed"], 2);
+    assert_eq!(data["replacements"], 3);
+    assert_eq!(
+        data["changes"][0],
+        serde_json::json!({
+            "file": "docs/development/requirements/general.md",
+            "line": 9,
+            "before": "Extends G.R.1.",
+            "after": "Extends G.S.1."
+        })
+    );
+    assert_eq!(data["changes"][2]["before"], "// G.R.10, G.R.1");
+    assert_eq!(data["changes"][2]["after"], "// G.R.10, G.S.1");
+    assert_eq!(
+        data["diff"],
+        "--- a/docs/development/requirements/general.md\n+++ b/docs/development/requirements/general.md\n\
+@@ -9 +9 @@\n-Extends G.R.1.\n+Extends G.S.1.\n\
+--- a/src/lib.rs\n+++ b/src/lib.rs\n\
+@@ -1 +1 @@\n-// G.R.1\n+// G.S.1\n\
+@@ -3 +3 @@\n-// G.R.10, G.R.1\n+// G.R.10, G.S.1\n"
+    );
+    assert_eq!(fs::read_to_string(&source).unwrap(), before);
+}
+
+/// Test: rewrite writes changed files and keeps headings and line endings
+/// Precondition: System cites G.R.1 in a requirement text and a source file with CRLF line endings
+/// Action: Call handle_rewrite_references with G.R.1 -> G.S.1
+/// Result: References are rewritten, the G.R.1 heading and CRLF endings are kept
+/// Covers Requirement: G.TOOLREQLIXR.3
+#[test]
+fn test_rewrite_references_writes_files() {
+    let temp_dir = TempDir::new().unwrap();
+    setup_project(&temp_dir);
+
+    let json = rewrite(&temp_dir, &[("G.R.1", "G.S.1")], None);
+
+    assert_eq!(json["data"]["dry_run"], false);
+    assert_eq!(
+        fs::read_to_string(temp_dir.path().join("src/lib.rs")).unwrap(),
+        "// G.S.1\r\nfn f() {}\r\n// G.R.10, G.S.1\r\n"
+    );
+    let general = fs::read_to_string(
+        temp_dir
+            .path()
+            .join("docs/development/requirements/general.md"),
+    )
+    .unwrap();
+    assert!(general.contains("## G.R.1: First"));
+    assert!(general.contains("Extends G.S.1."));
+}
+
+/// Test: empty mappings change nothing
+/// Precondition: System has references to G.R.1
+/// Action: Call handle_rewrite_references with no mappings
+/// Result: No changes and an empty diff
+/// Covers Requirement: G.TOOLREQLIXR.5
+#[test]
+fn test_rewrite_references_empty_mappings() {
+    let temp_dir = TempDir::new().unwrap();
+    setup_project(&temp_dir);
+
+    let json = rewrite(&temp_dir, &[], None);
+
+    assert_eq!(json["data"]["files_changed"], 0);
+    assert_eq!(json["data"]["diff"], "");
+}
+
+// =============================================================================
+// Tests for G.TOOLREQLIXR.5: Parameter validation
+// =============================================================================
+
+/// Test: validate_mappings rejects malformed indices, duplicates and oversized arrays
+/// Precondition: System has invalid mapping lists
+/// Action: Call validate_mappings
+/// Result: Function returns the specified errors
+/// Covers Requirement: G.TOOLREQLIXR.5
+#[test]
+fn test_validate_mappings_errors() {
+    assert!(RequirementsServer::validate_mappings(&mappings(&[("G.R.1", "G.S.1")])).is_ok());
+    assert_eq!(
+        RequirementsServer::validate_mappings(&mappings(&[("G.R.1", "")])).unwrap_err(),
+        "index is required"
+    );
+    assert_eq!(
+        RequirementsServer::validate_mappings(&mappings(&[("G.R.1 G.R.2", "G.S.1")])).unwrap_err(),
+        "Invalid index format: G.R.1 G.R.2"
+    );
+    assert_eq!(
+        RequirementsServer::validate_mappings(&mappings(&[("G.R.1", "g.s.1")])).unwrap_err(),
+        "Invalid index format: g.s.1"
+    );
+    assert_eq!(
+        RequirementsServer::validate_mappings(&mappings(&[("G.R.1", "G.S.1"), ("G.R.1", "G.S.2")]))
+            .unwrap_err(),
+        "Duplicate mapping for index: G.R.1"
+    );
+    let many: Vec<(String, String)> = (0..101)
+        .map(|i| (format!("G.R.{}", i), format!("G.S.{}", i)))
+        .collect();
+    let many: Vec<(&str, &str)> = many.iter().map(|(a, b)| (a.as_str(), b.as_str())).collect();
+    assert_eq!(
+        RequirementsServer::validate_mappings(&mappings(&many)).unwrap_err(),
+        "mappings exceeds maximum of 100 items"
+    );
+}
+
+/// Test: handle_rewrite_references validates mappings before globs
+/// Precondition: System has a project
+/// Action: Call handle_rewrite_references with a malformed mapping and an invalid exclude pattern
+/// Result: Error about the mapping, no file is changed
+/// Covers Requirement: G.TOOLREQLIXR.5
+#[test]
+fn test_rewrite_references_validation_order() {
+    let temp_dir = TempDir::new().unwrap();
+    setup_project(&temp_dir);
+    let params = RewriteReferencesParams {
+        project_root: temp_dir.path().to_string_lossy().to_string(),
+        operation_description: "test".to_string(),
+        mappings: mappings(&[("bad", "G.S.1")]),
+        dry_run: None,
+        include: None,
+        exclude: Some(vec!["[a".to_string()]),
+    };
+
+    let json = parse_response(&RequirementsServer::handle_rewrite_references(params));
+
+    assert_eq!(json["error"], "Invalid index format: bad");
+}
@@ -0,0 +1,208 @@
+// Tests for Tool: reqlix_suggest_location (G.TOOLREQLIXSU.*)
+// Covers Requirements: G.TOOLREQLIXSU.2, G.TOOLREQLIXSU.3, G.TOOLREQLIXSU.4, G.TOOLREQLIXSU.5
+
+use reqlix::{RequirementsServer, SuggestLocationParams};
+use serde_json::Value;
+use tempfile::TempDir;
+
+use super::common::{
+    create_agents_file_in_req_dir, create_category_file_in_req_dir, create_requirements_dir,
+    parse_response,
+};
+
+const GENERAL_CONTENT: &str = "# Security\n\n\
+## G.S.1: Password hashing\n\nPasswords must be hashed with bcrypt.\n\n\
+## G.S.2: Session timeout\n\nSessions expire after 30 minutes of inactivity.\n\n\
+# Logging\n\n\
+## G.L.1: Request log\n\nThe server logs every incoming request.\n";
+
+const TESTING_CONTENT: &str = "# Unit Tests\n\n\
+## T.U.1: Parser tests\n\nEvery parser function has unit tests.\n";
+
+/// Run reqlix_suggest_location against a temp project
+fn suggest_location(temp_dir: &TempDir, title: &str, text: &str, limit: Option<usize>) -> Value {
+    let params = SuggestLocationParams {
+        project_root: temp_dir.path().to_string_lossy().to_string(),
+        operation_description: "test".to_string(),
+        title: title.to_string(),
+        text: text.to_string(),
+        limit,
+    };
+    parse_response(&RequirementsServer::handle_suggest_location(params))
+}
+
+/// Create requirements directory with general and testing categories
+fn setup_project(temp_dir: &TempDir) {
+    let req_dir = create_requirements_dir(temp_dir);
+    create_agents_file_in_req_dir(&req_dir, "# Instructions\n");
+    create_category_file_in_req_dir(&req_dir, "general", GENERAL_CONTENT);
+    create_category_file_in_req_dir(&req_dir, "testing", TESTING_CONTENT);
+}
+
+// =============================================================================
+// Tests for G.TOOLREQLIXSU.3: Ranking algorithm
+// =============================================================================
+
+/// Test: suggest_location ranks the matching chapter first
+/// Precondition: System has chapters about security, logging and unit tests
+/// Action: Call handle_suggest_location with a draft about password storage
+/// Result: First location is general/Security; unrelated chapters are ranked lower or omitted
+/// Covers Requirement: G.TOOLREQLIXSU.3, G.TOOLREQLIXSU.4
+#[test]
+fn test_suggest_location_ranks_matching_chapter_first() {
+    let temp_dir = TempDir::new().unwrap();
+    setup_project(&temp_dir);
+
+    let json = suggest_location(
+        &temp_dir,
+        "Password salt",
+        "Passwords must be salted before hashing.",
+        None,
+    );
+
+    assert!(json["success"].as_bool().unwrap());
+    let locations = json["data"]["locations"].as_array().unwrap();
+    assert_eq!(locations[0]["category"], "general");
+    assert_eq!(locations[0]["chapter"], "Security");
+    assert!(locations[0]["similarity"].as_f64().unwrap() > 0.0);
+    for pair in locations.windows(2) {
+        assert!(pair[0]["similarity"].as_f64().unwrap() >= pair[1]["similarity"].as_f64().unwrap());
+    }
+}
+
+/// Test: suggest_location lists similar requirements
+/// Precondition: System has a requirement about password hashing
+/// Action: Call handle_suggest_location with a draft about password storage
+/// Result: First similar requirement is G.S.1 with its title, category and chapter
+/// Covers Requirement: G.TOOLREQLIXSU.3, G.TOOLREQLIXSU.4
+#[test]
+fn test_suggest_location_similar_requirements() {
+    let temp_dir = TempDir::new().unwrap();
+    setup_project(&temp_dir);
+
+    let json = suggest_location(
+        &temp_dir,
+        "Password salt",
+        "Passwords must be salted before hashing.",
+        None,
+    );
+
+    let similar = json["data"]["similar_requirements"].as_array().unwrap();
+    assert_eq!(similar[0]["index"], "G.S.1");
+    assert_eq!(similar[0]["title"], "Password hashing");
+    assert_eq!(similar[0]["category"], "general");
+    assert_eq!(similar[0]["chapter"], "Security");
+}
+
+/// Test: suggest_location matches chapter name
+/// Precondition: System has chapter "Unit Tests" in category testing
+/// Action: Call handle_suggest_location with a draft mentioning unit tests
+/// Result: First location is testing/Unit Tests
+/// Covers Requirement: G.TOOLREQLIXSU.3
+#[test]
+fn test_suggest_location_matches_chapter_name() {
+    let temp_dir = TempDir::new().unwrap();
+    setup_project(&temp_dir);
+
+    let json = suggest_location(
+        &temp_dir,
+        "Cache tests",
+        "The cache module has unit tests.",
+        None,
+    );
+
+    let locations = json["data"]["locations"].as_array().unwrap();
+    assert_eq!(locations[0]["category"], "testing");
+    assert_eq!(locations[0]["chapter"], "Unit Tests");
+}
+
+/// Test: suggest_location respects limit
+/// Precondition: System has three chapters and four requirements sharing the word "the"
+/// Action: Call handle_suggest_location with limit 1
+/// Result: Each list contains at most one entry
+/// Covers Requirement: G.TOOLREQLIXSU.2, G.TOOLREQLIXSU.3
+#[test]
+fn test_suggest_location_limit() {
+    let temp_dir = TempDir::new().unwrap();
+    setup_project(&temp_dir);
+
+    let json = suggest_location(
+        &temp_dir,
+        "Server tests",
+        "The server has unit tests for every request.",
+        Some(1),
+    );
+
+    assert_eq!(json["data"]["locations"].as_array().unwrap().len(), 1);
+    assert_eq!(
+        json["data"]["similar_requirements"]
+            .as_array()
+            .unwrap()
+            .len(),
+        1
+    );
+}
+
+/// Test: suggest_location with unrelated draft or no categories
+/// Precondition: System has requirements sharing no token with the draft, then a project without categories
+/// Action: Call handle_suggest_location
+/// Result: Function returns success with empty arrays
+/// Covers Requirement: G.TOOLREQLIXSU.3, G.TOOLREQLIXSU.4
+#[test]
+fn test_suggest_location_no_matches() {
+    let temp_dir = TempDir::new().unwrap();
+    setup_project(&temp_dir);
+
+    let json = suggest_location(&temp_dir, "Xyzzy", "Plugh.", None);
+    assert!(json["success"].as_bool().unwrap());
+    assert!(json["data"]["locations"].as_array().unwrap().is_empty());
+    assert!(json["data"]["similar_requirements"]
+        .as_array()
+        .unwrap()
+        .is_empty());
+
+    let empty_dir = TempDir::new().unwrap();
+    let req_dir = create_requirements_dir(&empty_dir);
+    create_agents_file_in_req_dir(&req_dir, "# Instructions\n");
+    let json = suggest_location(&empty_dir, "Login", "Users log in.", None);
+    assert!(json["success"].as_bool().unwrap());
+    assert!(json["data"]["locations"].as_array().unwrap().is_empty());
+}
+
+// =============================================================================
+// Tests for G.TOOLREQLIXSU.5: Parameter validation
+// =============================================================================
+
+/// Test: validate_limit accepts 1 to 100
+/// Precondition: System has limit values 0, 1, 100 and 101
+/// Action: Call validate_limit with each value
+/// Result: 1 and 100 are accepted, 0 and 101 return error
+/// Covers Requirement: G.TOOLREQLIXSU.5
+#[test]
+fn test_validate_limit_range() {
+    assert!(RequirementsServer::validate_limit(1).is_ok());
+    assert!(RequirementsServer::validate_limit(100).is_ok());
+    assert_eq!(
+        RequirementsServer::validate_limit(0).unwrap_err(),
+        "limit must be between 1 and 100"
+    );
+    assert!(RequirementsServer::validate_limit(101).is_err());
+}
+
+/// Test: suggest_location validates title before text and limit
+/// Precondition: System has empty title, oversized text and invalid limit
+/// Action: Call handle_suggest_location
+/// Result: Function returns title error
+/// Covers Requirement: G.TOOLREQLIXSU.5
+#[test]
+fn test_suggest_location_validation_order() {
+    let temp_dir = TempDir::new().unwrap();
+    setup_project(&temp_dir);
+
+    let json = suggest_location(&temp_dir, "", &"a".repeat(10001), Some(0));
+    assert!(!json["success"].as_bool().unwrap());
+    assert!(json["error"].as_str().unwrap().contains("title"));
+
+    let json = suggest_location(&temp_dir, "Title", "Text", Some(0));
+    assert_eq!(json["error"], "limit must be between 1 and 100");
+}
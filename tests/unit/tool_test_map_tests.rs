@@ -0,0 +1,151 @@
+// Tests for Tool: reqlix_test_map (G.TOOLREQLIXTE.*)
+// Covers Requirements: G.TOOLREQLIXTE.2, G.TOOLREQLIXTE.3, G.TOOLREQLIXTE.4, G.TOOLREQLIXTE.5
+
+use reqlix::{parse_test_functions, RequirementsServer, TestMapParams};
+use serde_json::Value;
+use std::fs;
+use tempfile::TempDir;
+
+use super::common::{
+    create_agents_file_in_req_dir, create_category_file_in_req_dir, create_requirements_dir,
+    parse_response,
+};
+
+/// Names and requirements of the test functions in a file, as "name: indices" strings
+fn parse(content: &str) -> Vec<String> {
+    parse_test_functions("t.rs", content)
+        .into_iter()
+        .map(|t| format!("{}: {}", t.name, t.requirements.join(", ")))
+        .collect()
+}
+
+/// Run reqlix_test_map against a temp project
+fn test_map(temp_dir: &TempDir, include: Option<Vec<String>>) -> Value {
+    let params = TestMapParams {
+        project_root: temp_dir.path().to_string_lossy().to_string(),
+        operation_description: "test".to_string(),
+        include,
+        exclude: None,
+    };
+    parse_response(&RequirementsServer::handle_test_map(params))
+}
+
+// =============================================================================
+// Tests for G.TOOLREQLIXTE.3: Algorithm
+// =============================================================================
+
+/// Test: parse_test_functions reads the G.TE.2 comment format
+/// Precondition: System has a test with a full doc comment, an async test with two cover lines, a test without
+///               comment, and a non-test function with a cover line
+/// Action: Call parse_test_functions
+/// Result: Three tests with their indices in order of first appearance, the helper is skipped
+/// Covers Requirement: G.TOOLREQLIXTE.3
+#[test]
+fn test_parse_test_functions() {
+    let content = "// Covers Requirements: G.H.1\n\nuse x;\n\n\
+/// Test: empty root\n/// Covers Requirement: G.P.1, G.P.2\n#[test]\nfn test_empty() {}\n\n\
+/// Covers Requirements: G.A.1\n#[tokio::test(flavor = \"multi_thread\")]\n\n\
+// Covers Requirement: G.A.2, G.A.1\npub async fn test_async() {}\n\n\
+#[test]\n#[should_panic]\nfn test_plain() {}\n\n\
+/// Covers Requirement: G.X.1\nfn helper() {}\n";
+
+    assert_eq!(
+        parse(content),
+        vec![
+            "test_empty: G.P.1, G.P.2",
+            "test_async: G.A.1, G.A.2",
+            "test_plain: "
+        ]
+    );
+    assert_eq!(parse_test_functions("t.rs", content)[0].line, 8);
+}
+
+/// Test: cover comments do not leak past other items
+/// Precondition: System has a cover comment followed by a struct, then a test
+/// Action: Call parse_test_functions
+/// Result: The test has no requirements
+/// Covers Requirement: G.TOOLREQLIXTE.3
+#[test]
+fn test_parse_test_functions_resets() {
+    let content = "/// Covers Requirement: G.A.1\nstruct S;\n#[test]\nfn test_a() {}\n";
+
+    assert_eq!(parse(content), vec!["test_a: "]);
+}
+
+/// Test: test map reports tests, unreferenced tests and untested requirements
+/// Precondition: System has requirements G.R.1 and G.R.2, a test covering G.R.1, a test without reference,
+///               and a markdown file that looks like a test
+/// Action: Call handle_test_map
+/// Result: Two tests from the .rs file, one unreferenced test, G.R.2 untested
+/// Covers Requirement: G.TOOLREQLIXTE.3, G.TOOLREQLIXTE.4
+#[test]
+fn test_test_map_gaps() {
+    let temp_dir = TempDir::new().unwrap();
+    let req_dir = create_requirements_dir(&temp_dir);
+    create_agents_file_in_req_dir(&req_dir, "# Instructions\n");
+    create_category_file_in_req_dir(
+        &req_dir,
+        "general",
+        "# Rules\n\n## G.R.1: One\n\nText.\n\n## G.R.2: Two\n\nText.\n",
+    );
+    let tests = temp_dir.path().join("tests");
+    fs::create_dir_all(&tests).unwrap();
+    fs::write(
+        tests.join("a.rs"),
+        "/// Covers Requirement: G.R.1\n#[test]\nfn test_one() {}\n\n#[test]\nfn test_other() {}\n",
+    )
+    .unwrap();
+    fs::write(
+        temp_dir.path().join("notes.md"),
+        "/// Covers Requirement: G.R.2\n#[test]\nfn test_doc() {}\n",
+    )
+    .unwrap();
+
+    let json = test_map(&temp_dir, None);
+
+    assert!(json["success"].as_bool().unwrap());
+    let data = &json["data"];
+    assert_eq!(data["files_scanned"], 1);
+    assert_eq!(
+        data["tests"][0],
+        serde_json::json!({
+            "name": "test_one",
+            "file": "tests/a.rs",
+            "line": 3,
+            "requirements": ["G.R.1"]
+        })
+    );
+    assert_eq!(data["tests"].as_array().unwrap().len(), 2);
+    assert_eq!(data["unreferenced_tests"][0]["name"], "test_other");
+    assert_eq!(
+        data["untested_requirements"],
+        serde_json::json!([{
+            "index": "G.R.2",
+            "title": "Two",
+            "category": "general",
+            "chapter": "Rules"
+        }])
+    );
+}
+
+// =============================================================================
+// Tests for G.TOOLREQLIXTE.5: Parameter validation
+// =============================================================================
+
+/// Test: test map validates include patterns
+/// Precondition: System has a project
+/// Action: Call handle_test_map with include "[a"
+/// Result: Error about the include pattern
+/// Covers Requirement: G.TOOLREQLIXTE.5
+#[test]
+fn test_test_map_invalid_include() {
+    let temp_dir = TempDir::new().unwrap();
+    create_requirements_dir(&temp_dir);
+
+    let json = test_map(&temp_dir, Some(vec!["[a".to_string()]));
+
+    assert!(json["error"]
+        .as_str()
+        .unwrap()
+        .starts_with("Invalid include pattern '[a'"));
+}
@@ -0,0 +1,149 @@
+// Tests for Tool: reqlix_trace (G.TOOLREQLIXT.*)
+// Covers Requirements: G.TOOLREQLIXT.2, G.TOOLREQLIXT.3, G.TOOLREQLIXT.4, G.TOOLREQLIXT.5
+
+use reqlix::{trace_requirements, RequirementsServer, TraceOptions, TraceParams};
+use serde_json::Value;
+use std::fs;
+use tempfile::TempDir;
+
+use super::common::{
+    create_agents_file_in_req_dir, create_category_file_in_req_dir, create_requirements_dir,
+    parse_response,
+};
+
+/// Create a project with one category and two source files
+fn setup_project(temp_dir: &TempDir) {
+    let req_dir = create_requirements_dir(temp_dir);
+    create_agents_file_in_req_dir(&req_dir, "# Instructions\n");
+    create_category_file_in_req_dir(
+        &req_dir,
+        "general",
+        "# Security\n\n## G.S.1: Login\n\nUsers log in.\n\n## G.S.2: Logout\n\nUsers log out.\n",
+    );
+    fs::create_dir_all(temp_dir.path().join("src")).unwrap();
+    fs::write(
+        temp_dir.path().join("src/auth.rs"),
+        "// Login (G.S.1)\nfn login() {}\n\n// G.S.1, G.X.9\nfn check() {}\n",
+    )
+    .unwrap();
+    fs::write(temp_dir.path().join("docs.txt"), "G.S.1\n").unwrap();
+}
+
+/// Run reqlix_trace against a temp project
+fn trace(temp_dir: &TempDir, include: Option<Vec<&str>>, exclude: Option<Vec<&str>>) -> Value {
+    let to_strings = |v: Vec<&str>| v.into_iter().map(|s| s.to_string()).collect();
+    let params = TraceParams {
+        project_root: temp_dir.path().to_string_lossy().to_string(),
+        operation_description: "test".to_string(),
+        include: include.map(to_strings),
+        exclude: exclude.map(to_strings),
+    };
+    parse_response(&RequirementsServer::handle_trace(params))
+}
+
+// =============================================================================
+// Tests for G.TOOLREQLIXT.3: Algorithm
+// =============================================================================
+
+/// Test: trace lists locations for each requirement
+/// Precondition: System has G.S.1 cited twice in src/auth.rs and once in docs.txt, G.S.2 not cited
+/// Action: Call handle_trace without globs
+/// Result: G.S.1 has three locations, G.S.2 has none; unknown index G.X.9 is not reported
+/// Covers Requirement: G.TOOLREQLIXT.3, G.TOOLREQLIXT.4
+#[test]
+fn test_trace_lists_locations() {
+    let temp_dir = TempDir::new().unwrap();
+    setup_project(&temp_dir);
+
+    let json = trace(&temp_dir, None, None);
+
+    assert!(json["success"].as_bool().unwrap());
+    assert_eq!(json["data"]["files_scanned"], 2);
+    let requirements = json["data"]["requirements"].as_array().unwrap();
+    assert_eq!(requirements.len(), 2);
+    assert_eq!(requirements[0]["index"], "G.S.1");
+    assert_eq!(requirements[0]["title"], "Login");
+    assert_eq!(requirements[0]["category"], "general");
+    assert_eq!(requirements[0]["chapter"], "Security");
+    assert_eq!(
+        requirements[0]["locations"],
+        serde_json::json!([
+            {"file": "docs.txt", "line": 1},
+            {"file": "src/auth.rs", "line": 1},
+            {"file": "src/auth.rs", "line": 4}
+        ])
+    );
+    assert_eq!(requirements[1]["index"], "G.S.2");
+    assert!(requirements[1]["locations"].as_array().unwrap().is_empty());
+}
+
+/// Test: trace applies include and exclude globs
+/// Precondition: System has references in src/auth.rs and docs.txt
+/// Action: Call handle_trace with include "**/*.rs", then with exclude "src"
+/// Result: Only src/auth.rs locations, then only docs.txt location are reported
+/// Covers Requirement: G.TOOLREQLIXT.2, G.TOOLREQLIXT.3
+#[test]
+fn test_trace_globs() {
+    let temp_dir = TempDir::new().unwrap();
+    setup_project(&temp_dir);
+
+    let json = trace(&temp_dir, Some(vec!["**/*.rs"]), None);
+    let locations = json["data"]["requirements"][0]["locations"]
+        .as_array()
+        .unwrap();
+    assert_eq!(locations.len(), 2);
+    assert!(locations.iter().all(|l| l["file"] == "src/auth.rs"));
+
+    let json = trace(&temp_dir, None, Some(vec!["src"]));
+    assert_eq!(json["data"]["files_scanned"], 1);
+    assert_eq!(
+        json["data"]["requirements"][0]["locations"],
+        serde_json::json!([{"file": "docs.txt", "line": 1}])
+    );
+}
+
+/// Test: trace_requirements library API returns the same report
+/// Precondition: System has the sample project
+/// Action: Call trace_requirements directly
+/// Result: Report contains both requirements and three locations for G.S.1
+/// Covers Requirement: G.TOOLREQLIXT.3
+#[test]
+fn test_trace_requirements_library_api() {
+    let temp_dir = TempDir::new().unwrap();
+    setup_project(&temp_dir);
+
+    let report =
+        trace_requirements(&temp_dir.path().to_string_lossy(), &TraceOptions::default()).unwrap();
+
+    assert_eq!(report.requirements.len(), 2);
+    assert_eq!(report.requirements[0].locations.len(), 3);
+    assert_eq!(report.requirements[0].locations[1].file, "src/auth.rs");
+}
+
+// =============================================================================
+// Tests for G.TOOLREQLIXT.5: Parameter validation
+// =============================================================================
+
+/// Test: trace validates include before exclude
+/// Precondition: System has invalid include and invalid exclude patterns
+/// Action: Call handle_trace with both, then with only invalid exclude
+/// Result: First error names include, second names exclude
+/// Covers Requirement: G.TOOLREQLIXT.5
+#[test]
+fn test_trace_validation_order() {
+    let temp_dir = TempDir::new().unwrap();
+    setup_project(&temp_dir);
+
+    let json = trace(&temp_dir, Some(vec!["[a"]), Some(vec!["[b"]));
+    assert!(!json["success"].as_bool().unwrap());
+    assert!(json["error"]
+        .as_str()
+        .unwrap()
+        .starts_with("Invalid include pattern '[a'"));
+
+    let json = trace(&temp_dir, None, Some(vec!["[b"]));
+    assert!(json["error"]
+        .as_str()
+        .unwrap()
+        .starts_with("Invalid exclude pattern '[b'"));
+}
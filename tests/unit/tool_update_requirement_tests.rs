@@ -93,6 +93,8 @@ fn test_batch_update_requirement_empty_array() {
         text: None,
         title: None,
         items: Some(vec![]),
+        relations: None,
+        rewrite_links: None,
     };
     let result = RequirementsServer::handle_update_requirement(params);
     let parsed: serde_json::Value = serde_json::from_str(&result).unwrap();
@@ -123,7 +125,10 @@ fn test_batch_update_requirement_single_item() {
             index: "G.C.1".to_string(),
             text: "New content".to_string(),
             title: None,
+            relations: None,
         }]),
+        relations: None,
+        rewrite_links: None,
     };
     let result = RequirementsServer::handle_update_requirement(params);
     let parsed: serde_json::Value = serde_json::from_str(&result).unwrap();
@@ -165,13 +170,17 @@ Old two.
                 index: "G.C.1".to_string(),
                 text: "New one".to_string(),
                 title: None,
+                relations: None,
             },
             reqlix::UpdateItem {
                 index: "G.C.2".to_string(),
                 text: "New two".to_string(),
                 title: Some("Updated Second".to_string()),
+                relations: None,
             },
         ]),
+        relations: None,
+        rewrite_links: None,
     };
     let result = RequirementsServer::handle_update_requirement(params);
     let parsed: serde_json::Value = serde_json::from_str(&result).unwrap();
@@ -209,13 +218,17 @@ fn test_batch_update_requirement_processes_all() {
                 index: "G.C.1".to_string(),
                 text: "New".to_string(),
                 title: None,
+                relations: None,
             },
             reqlix::UpdateItem {
                 index: "G.C.999".to_string(), // Does not exist
                 text: "New".to_string(),
                 title: None,
+                relations: None,
             },
         ]),
+        relations: None,
+        rewrite_links: None,
     };
     let result = RequirementsServer::handle_update_requirement(params);
     let parsed: serde_json::Value = serde_json::from_str(&result).unwrap();
@@ -248,6 +261,7 @@ fn test_batch_update_requirement_exceeds_limit() {
             index: format!("G.C.{}", i),
             text: "New".to_string(),
             title: None,
+            relations: None,
         })
         .collect();
     let params = reqlix::UpdateRequirementParams {
@@ -257,6 +271,8 @@ fn test_batch_update_requirement_exceeds_limit() {
         text: None,
         title: None,
         items: Some(items),
+        relations: None,
+        rewrite_links: None,
     };
     let result = RequirementsServer::handle_update_requirement(params);
     let parsed: serde_json::Value = serde_json::from_str(&result).unwrap();
@@ -284,6 +300,8 @@ fn test_update_requirement_both_index_and_items_error() {
         text: Some("New".to_string()),
         title: None,
         items: Some(vec![]),
+        relations: None,
+        rewrite_links: None,
     };
     let result = RequirementsServer::handle_update_requirement(params);
     let parsed: serde_json::Value = serde_json::from_str(&result).unwrap();
@@ -311,6 +329,8 @@ fn test_update_requirement_neither_index_nor_items_error() {
         text: None,
         title: None,
         items: None,
+        relations: None,
+        rewrite_links: None,
     };
     let result = RequirementsServer::handle_update_requirement(params);
     let parsed: serde_json::Value = serde_json::from_str(&result).unwrap();
@@ -338,6 +358,8 @@ fn test_single_update_requires_text() {
         text: None, // Missing text
         title: None,
         items: None,
+        relations: None,
+        rewrite_links: None,
     };
     let result = RequirementsServer::handle_update_requirement(params);
     let parsed: serde_json::Value = serde_json::from_str(&result).unwrap();
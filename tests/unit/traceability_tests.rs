@@ -0,0 +1,295 @@
+// Tests for Traceability (G.TR.*)
+// Covers Requirements: G.TR.1, G.TR.2, G.TR.3, G.TR.4, G.TR.5, G.TR.6
+
+use reqlix::{
+    requirement_history, scan_references, IndexReference, RequirementsServer, TraceOptions,
+};
+use std::fs;
+use std::path::Path;
+use tempfile::TempDir;
+
+use super::common::{create_requirements_dir, git_commit_all};
+
+/// Write a file under the temp dir, creating parent directories
+fn write_file(temp_dir: &TempDir, relative: &str, content: &str) {
+    let path = temp_dir.path().join(relative);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, content).unwrap();
+}
+
+/// Scan the temp project with the given include and exclude globs, returning file:line:index strings
+fn scan(temp_dir: &TempDir, include: &[&str], exclude: &[&str]) -> Vec<String> {
+    let req_dir = create_requirements_dir(temp_dir);
+    let options = TraceOptions {
+        include: include.iter().map(|s| s.to_string()).collect(),
+        exclude: exclude.iter().map(|s| s.to_string()).collect(),
+    };
+    scan_references(temp_dir.path(), &req_dir, &options)
+        .unwrap()
+        .references
+        .into_iter()
+        .map(|r| format!("{}:{}:{}", r.file, r.line, r.index))
+        .collect()
+}
+
+// =============================================================================
+// Tests for G.TR.1: Index references
+// =============================================================================
+
+/// Test: find_index_references recognizes index tokens in comments and code
+/// Precondition: System has lines with simple, underscored and multi-digit indices
+/// Action: Call find_index_references on each line
+/// Result: Function returns every index token in order of appearance
+/// Covers Requirement: G.TR.1
+#[test]
+fn test_find_index_references_formats() {
+    assert_eq!(
+        RequirementsServer::find_index_references("// G.R.3"),
+        vec!["G.R.3"]
+    );
+    assert_eq!(
+        RequirementsServer::find_index_references("/// Covers Requirement: G.REQLIX_U.7, T.U2.12"),
+        vec!["G.REQLIX_U.7", "T.U2.12"]
+    );
+    assert_eq!(
+        RequirementsServer::find_index_references("check(\"G.G.1\"); // G.G.1"),
+        vec!["G.G.1", "G.G.1"]
+    );
+}
+
+/// Test: find_index_references respects token boundaries
+/// Precondition: System has lines with index-like text glued to letters, digits or underscores, and version strings
+/// Action: Call find_index_references
+/// Result: Function returns no references for glued tokens and lowercase text
+/// Covers Requirement: G.TR.1
+#[test]
+fn test_find_index_references_boundaries() {
+    assert!(RequirementsServer::find_index_references("xG.R.3").is_empty());
+    assert!(RequirementsServer::find_index_references("G.R.3a").is_empty());
+    assert!(RequirementsServer::find_index_references("_G.R.3").is_empty());
+    assert!(RequirementsServer::find_index_references("version 1.2.3, g.r.3").is_empty());
+    assert_eq!(
+        RequirementsServer::find_index_references("(G.R.3)."),
+        vec!["G.R.3"]
+    );
+}
+
+// =============================================================================
+// Tests for G.TR.2: Scanned files
+// =============================================================================
+
+/// Test: scan honors .gitignore without a git repository
+/// Precondition: System has .gitignore listing "target/" and files in src and target
+/// Action: Call scan_references
+/// Result: Only the file in src is reported
+/// Covers Requirement: G.TR.2
+#[test]
+fn test_scan_references_honors_gitignore() {
+    let temp_dir = TempDir::new().unwrap();
+    write_file(&temp_dir, ".gitignore", "target/\n");
+    write_file(&temp_dir, "src/lib.rs", "// G.R.1\n");
+    write_file(&temp_dir, "target/gen.rs", "// G.R.2\n");
+
+    assert_eq!(scan(&temp_dir, &[], &[]), vec!["src/lib.rs:1:G.R.1"]);
+}
+
+/// Test: scan skips hidden files, requirements directory and non-UTF-8 files
+/// Precondition: System has references in a hidden directory, in a category file and in a binary file
+/// Action: Call scan_references
+/// Result: None of them is reported; files_scanned counts only the readable source file
+/// Covers Requirement: G.TR.2
+#[test]
+fn test_scan_references_skips_hidden_requirements_and_binary() {
+    let temp_dir = TempDir::new().unwrap();
+    let req_dir = create_requirements_dir(&temp_dir);
+    fs::write(req_dir.join("general.md"), "## G.G.1: Title\n").unwrap();
+    write_file(&temp_dir, ".hidden/notes.rs", "// G.G.1\n");
+    write_file(&temp_dir, "main.rs", "// G.G.2\n");
+    fs::write(temp_dir.path().join("data.bin"), [0xff, 0xfe, b'G', b'.']).unwrap();
+
+    let result = scan_references(temp_dir.path(), &req_dir, &TraceOptions::default()).unwrap();
+
+    assert_eq!(result.files_scanned, 1);
+    assert_eq!(
+        result.references,
+        vec![IndexReference {
+            index: "G.G.2".to_string(),
+            file: "main.rs".to_string(),
+            line: 1,
+        }]
+    );
+}
+
+/// Test: scan fails when project root is not a directory
+/// Precondition: System has a path that does not exist
+/// Action: Call scan_references
+/// Result: Function returns error naming the path
+/// Covers Requirement: G.TR.5
+#[test]
+fn test_scan_references_missing_root() {
+    let temp_dir = TempDir::new().unwrap();
+    let missing = temp_dir.path().join("missing");
+
+    let result = scan_references(&missing, Path::new("x"), &TraceOptions::default());
+
+    assert!(result
+        .unwrap_err()
+        .contains("Project root is not a directory"));
+}
+
+// =============================================================================
+// Tests for G.TR.3: Include and exclude globs
+// =============================================================================
+
+/// Test: include globs restrict scanned files
+/// Precondition: System has references in .rs and .md files
+/// Action: Call scan_references with include "**/*.rs"
+/// Result: Only .rs files are reported
+/// Covers Requirement: G.TR.3
+#[test]
+fn test_scan_references_include() {
+    let temp_dir = TempDir::new().unwrap();
+    write_file(&temp_dir, "README.md", "See G.R.1\n");
+    write_file(&temp_dir, "src/a.rs", "// G.R.2\n");
+    write_file(&temp_dir, "src/nested/b.rs", "// G.R.3\n");
+
+    assert_eq!(
+        scan(&temp_dir, &["**/*.rs"], &[]),
+        vec!["src/a.rs:1:G.R.2", "src/nested/b.rs:1:G.R.3"]
+    );
+}
+
+/// Test: single star does not cross directories
+/// Precondition: System has .rs files at two directory levels
+/// Action: Call scan_references with include "src/*.rs"
+/// Result: Only the top-level file in src is reported
+/// Covers Requirement: G.TR.3
+#[test]
+fn test_scan_references_star_does_not_cross_separator() {
+    let temp_dir = TempDir::new().unwrap();
+    write_file(&temp_dir, "src/a.rs", "// G.R.2\n");
+    write_file(&temp_dir, "src/nested/b.rs", "// G.R.3\n");
+
+    assert_eq!(
+        scan(&temp_dir, &["src/*.rs"], &[]),
+        vec!["src/a.rs:1:G.R.2"]
+    );
+}
+
+/// Test: exclude globs skip files and directories and win over include
+/// Precondition: System has references in src, vendor and a generated file
+/// Action: Call scan_references with include "**/*.rs" and exclude "vendor", "**/generated.rs"
+/// Result: Only src/a.rs is reported
+/// Covers Requirement: G.TR.3
+#[test]
+fn test_scan_references_exclude() {
+    let temp_dir = TempDir::new().unwrap();
+    write_file(&temp_dir, "src/a.rs", "// G.R.1\n");
+    write_file(&temp_dir, "src/generated.rs", "// G.R.2\n");
+    write_file(&temp_dir, "vendor/lib.rs", "// G.R.3\n");
+
+    assert_eq!(
+        scan(&temp_dir, &["**/*.rs"], &["vendor", "**/generated.rs"]),
+        vec!["src/a.rs:1:G.R.1"]
+    );
+}
+
+/// Test: validate_globs rejects invalid and oversized patterns
+/// Precondition: System has an unclosed character class, a 201-character pattern and 101 patterns
+/// Action: Call validate_globs
+/// Result: Function returns errors naming the parameter
+/// Covers Requirement: G.TR.3
+#[test]
+fn test_validate_globs_errors() {
+    assert!(RequirementsServer::validate_globs("include", &["src/**/*.rs".to_string()]).is_ok());
+
+    let error = RequirementsServer::validate_globs("exclude", &["[a".to_string()]).unwrap_err();
+    assert!(error.starts_with("Invalid exclude pattern '[a'"));
+
+    let error = RequirementsServer::validate_globs("include", &["a".repeat(201)]).unwrap_err();
+    assert_eq!(
+        error,
+        "include pattern exceeds maximum length of 200 characters"
+    );
+
+    let many: Vec<String> = (0..101).map(|i| format!("{}.rs", i)).collect();
+    let error = RequirementsServer::validate_globs("include", &many).unwrap_err();
+    assert_eq!(error, "include exceeds maximum of 100 patterns");
+}
+
+// =============================================================================
+// Tests for G.TR.4: Reference locations
+// =============================================================================
+
+/// Test: references are ordered by file, line and position
+/// Precondition: System has two files, one with two references on one line and one on a later line
+/// Action: Call scan_references
+/// Result: References are in file order, then line order, then position order, with 1-based lines
+/// Covers Requirement: G.TR.4
+#[test]
+fn test_scan_references_order() {
+    let temp_dir = TempDir::new().unwrap();
+    write_file(&temp_dir, "b.rs", "// G.B.1\n");
+    write_file(
+        &temp_dir,
+        "a.rs",
+        "fn main() {}\r\n// G.A.2 and G.A.1\r\n\r\n// G.A.3\r\n",
+    );
+
+    assert_eq!(
+        scan(&temp_dir, &[], &[]),
+        vec![
+            "a.rs:2:G.A.2",
+            "a.rs:2:G.A.1",
+            "a.rs:4:G.A.3",
+            "b.rs:1:G.B.1"
+        ]
+    );
+}
+
+// =============================================================================
+// Tests for G.TR.6: Requirement history
+// =============================================================================
+
+/// Test: requirement history keeps the newest title of each index
+/// Precondition: System has a committed requirement G.R.1 that was renamed in a second commit,
+///               and G.R.2 that was deleted
+/// Action: Call requirement_history
+/// Result: G.R.1 has the newest title, G.R.2 keeps its title from before deletion
+/// Covers Requirement: G.TR.6
+#[test]
+fn test_requirement_history_newest_title() {
+    let temp_dir = TempDir::new().unwrap();
+    let req_dir = create_requirements_dir(&temp_dir);
+    fs::write(
+        req_dir.join("general.md"),
+        "# Rules\n\n## G.R.1: Old title\n\nText.\n\n## G.R.2: Removed\n\nText.\n",
+    )
+    .unwrap();
+    git_commit_all(&temp_dir, "Add requirements");
+    fs::write(
+        req_dir.join("general.md"),
+        "# Rules\n\n## G.R.1: New title\n\nText.\n",
+    )
+    .unwrap();
+    git_commit_all(&temp_dir, "Rename and delete");
+
+    let history = requirement_history(&req_dir);
+
+    assert_eq!(history["G.R.1"], "New title");
+    assert_eq!(history["G.R.2"], "Removed");
+}
+
+/// Test: requirement history is empty outside a git repository
+/// Precondition: System has a requirements directory that is not tracked by git
+/// Action: Call requirement_history
+/// Result: Function returns an empty map without error
+/// Covers Requirement: G.TR.6
+#[test]
+fn test_requirement_history_without_git() {
+    let temp_dir = TempDir::new().unwrap();
+    let req_dir = create_requirements_dir(&temp_dir);
+    fs::write(req_dir.join("general.md"), "## G.R.1: Title\n").unwrap();
+
+    assert!(requirement_history(&req_dir).is_empty());
+}